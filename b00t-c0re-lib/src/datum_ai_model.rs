@@ -15,6 +15,8 @@ pub enum ModelSize {
     /// Small models (<8B parameters) - fast, efficient, local deployment friendly
     #[serde(alias = "sm0l")]
     Small,
+    /// Medium models - optional tier between small and large for finer routing
+    Medium,
     /// Large models (>=8B parameters) - powerful, resource-intensive  
     #[serde(alias = "ch0nky")]
     Large,
@@ -135,6 +137,8 @@ impl ModelProvider {
 /// api_key_env = "ANTHROPIC_API_KEY"
 /// rpm_limit = 60
 /// context_window = 200000
+/// input_price_per_1m = 3.0
+/// output_price_per_1m = 15.0
 /// access_groups = ["beta-models"]
 ///
 /// [ai_model.parameters]
@@ -180,6 +184,12 @@ pub struct AiModelDatum {
     /// Token context window size
     pub context_window: Option<u32>,
 
+    /// Price in USD per 1M prompt tokens
+    pub input_price_per_1m: Option<f64>,
+
+    /// Price in USD per 1M completion tokens
+    pub output_price_per_1m: Option<f64>,
+
     /// Whether model is currently available/enabled
     #[serde(default = "default_true")]
     pub enabled: bool,
@@ -270,6 +280,13 @@ impl AiModelDatum {
         self.capabilities.contains(capability)
     }
 
+    /// Whether `tokens` fit in the context window with `reserve` tokens held back
+    /// (e.g. for the completion); `None` when the datum has no `context_window`
+    pub fn fits_context(&self, tokens: u64, reserve: u64) -> Option<bool> {
+        self.context_window
+            .map(|window| tokens < u64::from(window).saturating_sub(reserve))
+    }
+
     /// Check if model matches size classification
     pub fn is_size(&self, size: &ModelSize) -> bool {
         &self.size == size
@@ -449,6 +466,8 @@ mod tests {
             metadata: HashMap::new(),
             rpm_limit: Some(60),
             context_window: Some(128000),
+            input_price_per_1m: None,
+            output_price_per_1m: None,
             enabled: true,
             access_groups: vec![],
         };
@@ -457,6 +476,16 @@ mod tests {
         assert!(datum.is_size(&ModelSize::Large));
         assert!(datum.is_provider(&ModelProvider::OpenAI));
         assert_eq!(datum.full_litellm_id(), "openai/gpt-4o");
+
+        assert_eq!(datum.fits_context(127_999, 0), Some(true));
+        assert_eq!(datum.fits_context(128_000, 0), Some(false));
+        assert_eq!(datum.fits_context(100_000, 28_000), Some(false));
+        assert_eq!(datum.fits_context(0, 200_000), Some(false));
+        let unknown = AiModelDatum {
+            context_window: None,
+            ..datum
+        };
+        assert_eq!(unknown.fits_context(1, 0), None);
     }
 
     #[test]
@@ -476,6 +505,8 @@ mod tests {
             metadata: HashMap::new(),
             rpm_limit: Some(60),
             context_window: Some(200000),
+            input_price_per_1m: None,
+            output_price_per_1m: None,
             enabled: true,
             access_groups: vec!["beta-models".to_string()],
         };
@@ -516,6 +547,8 @@ mod tests {
             metadata: HashMap::new(),
             rpm_limit: None,
             context_window: Some(4096),
+            input_price_per_1m: None,
+            output_price_per_1m: None,
             enabled: true,
             access_groups: vec![],
         };
@@ -545,6 +578,8 @@ mod tests {
             metadata: HashMap::new(),
             rpm_limit: Some(600),
             context_window: Some(8192),
+            input_price_per_1m: None,
+            output_price_per_1m: None,
             enabled: true,
             access_groups: vec!["public".to_string()],
         };
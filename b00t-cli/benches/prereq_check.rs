@@ -0,0 +1,53 @@
+//! Wall-clock comparison of sequential vs concurrent bootstrap prerequisite checks
+//!
+//! Run with: cargo bench -p b00t-cli --bench prereq_check
+
+use b00t_cli::bootstrap::check_prerequisites;
+use std::time::{Duration, Instant};
+
+/// Common binaries that answer `--version`; missing ones still cost a PATH lookup
+const BINARIES: [&str; 12] = [
+    "git", "cargo", "rustc", "python3", "node", "npm", "curl", "bash", "tar", "make", "ls", "cat",
+];
+const ROUNDS: u32 = 5;
+
+fn average(total: Duration) -> Duration {
+    total / ROUNDS
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("bootstrap.toml");
+    let mut toml = String::from("[bootstrap.required_bins]\n");
+    for (priority, name) in BINARIES.iter().enumerate() {
+        toml.push_str(&format!(
+            "{} = {{ version = \"*\", priority = {} }}\n",
+            name, priority
+        ));
+    }
+    std::fs::write(&config_path, toml)?;
+
+    let mut sequential = Duration::ZERO;
+    let mut concurrent = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        check_prerequisites(&config_path, false).await?;
+        sequential += start.elapsed();
+
+        let start = Instant::now();
+        check_prerequisites(&config_path, true).await?;
+        concurrent += start.elapsed();
+    }
+
+    let (sequential, concurrent) = (average(sequential), average(concurrent));
+    println!(
+        "📊 {} binaries, {} rounds: sequential {:?}, concurrent {:?} ({:.1}x)",
+        BINARIES.len(),
+        ROUNDS,
+        sequential,
+        concurrent,
+        sequential.as_secs_f64() / concurrent.as_secs_f64()
+    );
+    Ok(())
+}
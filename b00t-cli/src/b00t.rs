@@ -4,8 +4,29 @@
 //! Agents/subshells can use `b00t` without relying on bash aliases.
 
 use std::env;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 use std::process::{Command, exit};
 
+/// Search each `$PATH` component for `name`, like a shell resolving a command
+fn find_on_path(name: &str, path_var: &OsStr) -> Option<PathBuf> {
+    env::split_paths(path_var)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Prefer b00t-cli next to this binary, fall back to `$PATH` (mixed installs)
+fn locate_b00t_cli(bin_dir: &Path) -> Option<PathBuf> {
+    let name = format!("b00t-cli{}", env::consts::EXE_SUFFIX);
+
+    let sibling = bin_dir.join(&name);
+    if sibling.is_file() {
+        return Some(sibling);
+    }
+
+    env::var_os("PATH").and_then(|path_var| find_on_path(&name, &path_var))
+}
+
 fn main() {
     // Get the directory where this binary is located
     let current_exe = env::current_exe().expect("Failed to determine current executable path");
@@ -14,8 +35,14 @@ fn main() {
         .parent()
         .expect("Failed to get parent directory");
 
-    // Path to b00t-cli in the same directory
-    let b00t_cli_path = bin_dir.join("b00t-cli");
+    let b00t_cli_path = locate_b00t_cli(bin_dir).unwrap_or_else(|| {
+        eprintln!("Failed to find b00t-cli");
+        eprintln!(
+            "Expected at: {} (or anywhere on $PATH)",
+            bin_dir.join("b00t-cli").display()
+        );
+        exit(1);
+    });
 
     // Collect all arguments (skip argv[0] which is "b00t")
     let args: Vec<String> = env::args().skip(1).collect();
@@ -33,3 +60,24 @@ fn main() {
     // Exit with the same code as b00t-cli
     exit(status.code().unwrap_or(1));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_on_path_checks_each_component() {
+        let empty = tempfile::tempdir().unwrap();
+        let with_cli = tempfile::tempdir().unwrap();
+        std::fs::write(with_cli.path().join("b00t-cli"), "").unwrap();
+
+        let path_var = env::join_paths([empty.path(), with_cli.path()]).unwrap();
+        assert_eq!(
+            find_on_path("b00t-cli", &path_var),
+            Some(with_cli.path().join("b00t-cli"))
+        );
+
+        let path_var = env::join_paths([empty.path()]).unwrap();
+        assert_eq!(find_on_path("b00t-cli", &path_var), None);
+    }
+}
@@ -2,24 +2,31 @@
 //!
 //! Uses stack datums to self-install missing binaries and services
 
-use crate::bootstrap::prereq::PrereqResult;
+use crate::bootstrap::prereq::{BinaryCheck, PrereqResult};
 use anyhow::{Context, Result};
 use std::process::Command;
 
 /// Install missing required binaries based on OS
-pub async fn install_missing_required(prereq: &PrereqResult) -> Result<Vec<String>> {
+/// With `dry_run`, install commands are only logged and the returned list is what *would* be installed
+pub async fn install_missing_required(prereq: &PrereqResult, dry_run: bool) -> Result<Vec<String>> {
     let mut installed = Vec::new();
 
     for binary in prereq.missing_required() {
-        // 🤓 Skip docker install if it was satisfied by podman alternative
-        if binary.name.contains("podman (docker alternative)") {
-            println!("ℹ️  Docker requirement satisfied by podman - skipping docker installation");
+        // 🤓 Skip install when an alternative satisfied the requirement (e.g. podman for docker)
+        if binary.detail.is_some() {
+            println!(
+                "ℹ️  {} satisfied by an alternative - skipping installation",
+                binary.display_name()
+            );
             continue;
         }
 
         println!("🔧 Installing {}...", binary.name);
 
-        match install_binary(&binary.name).await {
+        match install_binary(binary, dry_run).await {
+            Ok(_) if dry_run => {
+                installed.push(binary.name.clone());
+            }
             Ok(_) => {
                 installed.push(binary.name.clone());
                 println!("  ✅ {} installed", binary.name);
@@ -33,37 +40,74 @@ pub async fn install_missing_required(prereq: &PrereqResult) -> Result<Vec<Strin
     Ok(installed)
 }
 
-/// Install a single binary using appropriate package manager
-async fn install_binary(name: &str) -> Result<()> {
+/// Install a single binary, preferring its `install_hint` command over the built-in map
+async fn install_binary(binary: &BinaryCheck, dry_run: bool) -> Result<()> {
+    if let Some(hint) = binary.install_hint.as_deref() {
+        match hint_command(hint) {
+            Some(argv) => {
+                let args: Vec<&str> = argv[1..].iter().map(String::as_str).collect();
+                return run_command(&argv[0], &args, dry_run);
+            }
+            None => println!("  ℹ️  {}", non_executable_hint(hint)),
+        }
+    }
+
+    let name = binary.name.as_str();
     // Detect OS and use appropriate package manager
     if cfg!(target_os = "linux") {
-        install_linux(name).await
+        install_linux(name, dry_run).await
     } else if cfg!(target_os = "macos") {
-        install_macos(name).await
+        install_macos(name, dry_run).await
     } else {
         anyhow::bail!("Unsupported OS for auto-install")
     }
 }
 
-/// Install on Linux using apt/snap/cargo
-async fn install_linux(name: &str) -> Result<()> {
+/// Parse an `install_hint` marked as a command with a `$ ` prefix (`$ brew install foo`)
+///
+/// 🤓 Hints are also plain descriptions ("GitHub CLI for MCP integration", "see
+/// https://..."), so only the explicit prompt marker makes a hint executable.
+fn hint_command(hint: &str) -> Option<Vec<String>> {
+    let argv = shlex::split(hint.trim_start().strip_prefix("$ ")?)?;
+    (!argv.is_empty()).then_some(argv)
+}
+
+/// Explain why an `install_hint` was not run, so a `brew install foo` hint doesn't fall back silently
+fn non_executable_hint(hint: &str) -> String {
+    if hint.trim_start().starts_with("$ ") {
+        format!("install_hint not executable (cannot parse command): {hint}")
+    } else {
+        format!("install_hint not executable (missing `$ ` prefix): {hint}")
+    }
+}
+
+/// Install on Linux using apt/snap/cargo (fallback when no install_hint command)
+async fn install_linux(name: &str, dry_run: bool) -> Result<()> {
+    let user = whoami::username();
+    let is_root = user == "root";
+    let escalate = privilege_escalation(is_root, is_command_available);
+    if escalate.is_none() && !is_root {
+        eprintln!("  ⚠️  Neither sudo nor doas found; running package managers directly");
+    }
+    let privileged = |args: &[&str]| run_privileged(escalate, args, dry_run);
+
     match name {
         "docker" => {
             // Use snap for Docker on Ubuntu/Debian
-            run_command("sudo", &["snap", "install", "docker"])?;
-            run_command("sudo", &["addgroup", "--system", "docker"])?;
-            run_command("sudo", &["usermod", "-aG", "docker", &whoami::username()])?;
+            privileged(&["snap", "install", "docker"])?;
+            privileged(&["addgroup", "--system", "docker"])?;
+            privileged(&["usermod", "-aG", "docker", &user])?;
         }
         "git" => {
-            run_command("sudo", &["apt-get", "update"])?;
-            run_command("sudo", &["apt-get", "install", "-y", "git"])?;
+            privileged(&["apt-get", "update"])?;
+            privileged(&["apt-get", "install", "-y", "git"])?;
         }
         "just" => {
             // Install just via cargo
-            run_command("cargo", &["install", "just"])?;
+            run_command("cargo", &["install", "just"], dry_run)?;
         }
         "fzf" => {
-            run_command("sudo", &["apt-get", "install", "-y", "fzf"])?;
+            privileged(&["apt-get", "install", "-y", "fzf"])?;
         }
         _ => {
             anyhow::bail!("Unknown binary: {}", name);
@@ -72,14 +116,33 @@ async fn install_linux(name: &str) -> Result<()> {
     Ok(())
 }
 
-/// Install on macOS using brew
-async fn install_macos(name: &str) -> Result<()> {
+/// Privilege escalation tool to prefix package manager commands with: sudo, else doas
+///
+/// 🤓 None when already root (rootless containers often run as root without sudo)
+/// or when neither tool exists, in which case the command runs as-is
+fn privilege_escalation(is_root: bool, available: impl Fn(&str) -> bool) -> Option<&'static str> {
+    if is_root {
+        return None;
+    }
+    ["sudo", "doas"].into_iter().find(|tool| available(tool))
+}
+
+/// Run `args` through `escalate` (sudo/doas) when given, otherwise directly
+fn run_privileged(escalate: Option<&str>, args: &[&str], dry_run: bool) -> Result<()> {
+    match escalate {
+        Some(tool) => run_command(tool, args, dry_run),
+        None => run_command(args[0], &args[1..], dry_run),
+    }
+}
+
+/// Install on macOS using brew (fallback when no install_hint command)
+async fn install_macos(name: &str, dry_run: bool) -> Result<()> {
     match name {
         "docker" => {
-            run_command("brew", &["install", "--cask", "docker"])?;
+            run_command("brew", &["install", "--cask", "docker"], dry_run)?;
         }
         "git" | "just" | "fzf" => {
-            run_command("brew", &["install", name])?;
+            run_command("brew", &["install", name], dry_run)?;
         }
         _ => {
             anyhow::bail!("Unknown binary: {}", name);
@@ -89,15 +152,18 @@ async fn install_macos(name: &str) -> Result<()> {
 }
 
 /// Start required services using stack datums
-pub async fn start_services() -> Result<Vec<String>> {
+/// With `dry_run`, the returned list is what *would* be started
+pub async fn start_services(dry_run: bool) -> Result<Vec<String>> {
     let mut started = Vec::new();
 
     // Check if Qdrant is needed and not running
     if should_start_qdrant().await? {
         println!("🚀 Starting Qdrant service...");
-        start_qdrant().await?;
+        start_qdrant(dry_run).await?;
         started.push("qdrant".to_string());
-        println!("  ✅ Qdrant started");
+        if !dry_run {
+            println!("  ✅ Qdrant started");
+        }
     }
 
     Ok(started)
@@ -127,7 +193,7 @@ async fn should_start_qdrant() -> Result<bool> {
 }
 
 /// Start Qdrant using docker or podman
-async fn start_qdrant() -> Result<()> {
+async fn start_qdrant(dry_run: bool) -> Result<()> {
     // 🤓 Use podman if docker not available
     let container_runtime = if is_command_available("docker") {
         "docker"
@@ -156,6 +222,7 @@ async fn start_qdrant() -> Result<()> {
                 "qdrant_storage:/qdrant/storage",
                 "qdrant/qdrant:latest",
             ],
+            dry_run,
         )?;
     }
 
@@ -171,8 +238,13 @@ fn is_command_available(cmd: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// Run a command and check for success
-fn run_command(cmd: &str, args: &[&str]) -> Result<()> {
+/// Run a command and check for success (logged no-op when `dry_run`)
+fn run_command(cmd: &str, args: &[&str], dry_run: bool) -> Result<()> {
+    if dry_run {
+        println!("  📝 [dry-run] would run: {} {}", cmd, args.join(" "));
+        return Ok(());
+    }
+
     let status = Command::new(cmd)
         .args(args)
         .status()
@@ -184,3 +256,54 @@ fn run_command(cmd: &str, args: &[&str]) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_command_parses_shell_words() {
+        assert_eq!(
+            hint_command("$ sh -c 'echo installed'"),
+            Some(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "echo installed".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_hint_command_ignores_descriptions() {
+        assert_eq!(hint_command("GitHub CLI for MCP integration"), None);
+        // First word on PATH, but no `$ ` marker
+        assert_eq!(hint_command("sh -c 'echo installed'"), None);
+        assert_eq!(hint_command("install gh from https://cli.github.com"), None);
+        assert_eq!(hint_command("$ sh -c 'unterminated"), None);
+        assert_eq!(hint_command("$ "), None);
+        assert_eq!(hint_command(""), None);
+    }
+
+    #[test]
+    fn test_non_executable_hint_names_the_reason() {
+        assert_eq!(
+            non_executable_hint("brew install foo"),
+            "install_hint not executable (missing `$ ` prefix): brew install foo"
+        );
+        assert_eq!(
+            non_executable_hint("$ sh -c 'unterminated"),
+            "install_hint not executable (cannot parse command): $ sh -c 'unterminated"
+        );
+    }
+
+    #[test]
+    fn test_privilege_escalation_prefers_sudo_then_doas() {
+        assert_eq!(privilege_escalation(false, |_| true), Some("sudo"));
+        assert_eq!(
+            privilege_escalation(false, |tool| tool == "doas"),
+            Some("doas")
+        );
+        assert_eq!(privilege_escalation(false, |_| false), None);
+        assert_eq!(privilege_escalation(true, |_| true), None);
+    }
+}
@@ -13,6 +13,12 @@ pub mod report;
 pub mod skeleton;
 
 pub use installer::{install_missing_required, start_services};
-pub use prereq::check_prerequisites;
-pub use report::{generate_toon_report, print_toon_report};
-pub use skeleton::create_skeleton;
+pub use prereq::{
+    PrereqDiff, VersionChange, check_prerequisites, diff_prereq_results, find_bootstrap_config,
+    validate_bootstrap_config,
+};
+pub use report::{
+    generate_toon_report, parse_toon, parse_toon_directories_created, print_toon_report,
+    print_toon_report_plain, serialize_to_toon,
+};
+pub use skeleton::{create_skeleton, remove_skeleton};
@@ -4,7 +4,7 @@
 //! with correct versions.
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -24,15 +24,21 @@ pub struct BootstrapSection {
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct BinarySpec {
-    pub version: String, // Format: ">=1.0.0"
+    pub version: String, // Format: ">=1.0.0" or "~=2.30" (compatible release)
     #[serde(default)]
     pub priority: u8,
     #[serde(default)]
     pub install_hint: Option<String>,
+    /// Command + args that print the version (default: `<name> --version`)
+    #[serde(default)]
+    pub version_command: Option<Vec<String>>,
+    /// Binaries that can stand in when this one is missing, tried in order
+    #[serde(default)]
+    pub alternatives: Option<Vec<String>>,
 }
 
 /// Result of prerequisite check for a single binary
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct BinaryCheck {
     pub name: String,
     pub found: bool,
@@ -41,10 +47,24 @@ pub struct BinaryCheck {
     pub meets_requirement: bool,
     pub path: Option<PathBuf>,
     pub install_hint: Option<String>,
+    /// How the binary was satisfied when not directly (e.g. "via podman");
+    /// kept out of `name` so checks of the same binary always compare by name
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+impl BinaryCheck {
+    /// `name`, with `detail` in parentheses when set (e.g. "docker (via podman)")
+    pub fn display_name(&self) -> String {
+        match &self.detail {
+            Some(detail) => format!("{} ({})", self.name, detail),
+            None => self.name.clone(),
+        }
+    }
 }
 
 /// Overall prerequisite check result
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct PrereqResult {
     pub required: Vec<BinaryCheck>,
     pub optional: Vec<BinaryCheck>,
@@ -69,71 +89,320 @@ impl PrereqResult {
     }
 }
 
+/// A binary whose installed version differs between two checks
+#[derive(Debug, PartialEq, Serialize)]
+pub struct VersionChange {
+    pub name: String,
+    pub old_version: String,
+    pub new_version: String,
+}
+
+/// What changed between two prerequisite checks (required and optional binaries alike)
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct PrereqDiff {
+    /// Found now, missing (or not listed) before
+    pub newly_installed: Vec<String>,
+    /// Found before, missing now
+    pub no_longer_found: Vec<String>,
+    pub version_changes: Vec<VersionChange>,
+}
+
+impl PrereqDiff {
+    pub fn is_empty(&self) -> bool {
+        self.newly_installed.is_empty()
+            && self.no_longer_found.is_empty()
+            && self.version_changes.is_empty()
+    }
+}
+
+/// Compare an earlier prerequisite check (e.g. from the last Toon report) with a fresh one
+pub fn diff_prereq_results(old: &PrereqResult, new: &PrereqResult) -> PrereqDiff {
+    let old_bins: HashMap<&str, &BinaryCheck> = old
+        .required
+        .iter()
+        .chain(&old.optional)
+        .map(|bin| (bin.name.as_str(), bin))
+        .collect();
+
+    let mut diff = PrereqDiff::default();
+    for bin in new.required.iter().chain(&new.optional) {
+        let before = old_bins.get(bin.name.as_str());
+        let found_before = before.is_some_and(|b| b.found);
+
+        match (found_before, bin.found) {
+            (false, true) => diff.newly_installed.push(bin.name.clone()),
+            (true, false) => diff.no_longer_found.push(bin.name.clone()),
+            (true, true) => {
+                if let (Some(old_version), Some(new_version)) = (
+                    before.and_then(|b| b.installed_version.as_ref()),
+                    bin.installed_version.as_ref(),
+                ) && old_version != new_version
+                {
+                    diff.version_changes.push(VersionChange {
+                        name: bin.name.clone(),
+                        old_version: old_version.clone(),
+                        new_version: new_version.clone(),
+                    });
+                }
+            }
+            (false, false) => {}
+        }
+    }
+    diff
+}
+
+/// Default bootstrap.toml locations, in search order
+fn bootstrap_config_candidates() -> Vec<PathBuf> {
+    bootstrap_config_candidates_in(
+        &crate::commands::config::default_datum_path(),
+        dirs::home_dir(),
+    )
+}
+
+fn bootstrap_config_candidates_in(datum_path: &str, home: Option<PathBuf>) -> Vec<PathBuf> {
+    let mut candidates = vec![PathBuf::from("_b00t_/bootstrap.toml")];
+    if let Ok(datum_dir) = crate::get_expanded_path(datum_path) {
+        candidates.push(datum_dir.join("bootstrap.toml"));
+    }
+    if let Some(home) = home {
+        candidates.push(home.join(".b00t/bootstrap.toml"));
+    }
+    candidates
+}
+
+/// Locate bootstrap.toml
+/// Search order: `_b00t_/bootstrap.toml` (cwd) → `<datum_path>/bootstrap.toml` (`datum_path` from
+/// ~/.b00t/config.toml, else `~/.dotfiles/_b00t_`) → `~/.b00t/bootstrap.toml`
+pub fn find_bootstrap_config() -> Result<PathBuf> {
+    let candidates = bootstrap_config_candidates();
+
+    if let Some(found) = candidates.iter().find(|path| path.is_file()) {
+        return Ok(found.clone());
+    }
+
+    anyhow::bail!(
+        "Bootstrap config not found. Searched:\n{}",
+        candidates
+            .iter()
+            .map(|path| format!("  - {}", path.display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+}
+
 /// Load bootstrap config from TOML file
-fn load_config(config_path: &Path) -> Result<BootstrapConfig> {
+pub fn load_config(config_path: &Path) -> Result<BootstrapConfig> {
     let content = std::fs::read_to_string(config_path)
         .with_context(|| format!("Failed to read {}", config_path.display()))?;
 
-    toml::from_str(&content).with_context(|| format!("Failed to parse bootstrap.toml"))
+    let value: toml::Value = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", config_path.display()))?;
+    // 🤓 Problems go in the message itself: callers print errors without the context chain
+    validate_bootstrap_config(&value)
+        .map_err(|e| anyhow::anyhow!("Invalid bootstrap config {}:{}", config_path.display(), e))?;
+    value
+        .try_into()
+        .with_context(|| format!("Failed to parse {}", config_path.display()))
 }
 
-/// Check if binary exists in PATH
-fn find_binary(name: &str) -> Option<PathBuf> {
-    Command::new("which")
-        .arg(name)
-        .output()
-        .ok()
-        .and_then(|output| {
-            if output.status.success() {
-                String::from_utf8(output.stdout)
-                    .ok()
-                    .map(|s| PathBuf::from(s.trim()))
+const SECTION_KEYS: [&str; 2] = ["required_bins", "optional_bins"];
+const SPEC_KEYS: [&str; 5] = [
+    "version",
+    "priority",
+    "install_hint",
+    "version_command",
+    "alternatives",
+];
+
+/// Known key that `key` was probably meant to be (`required_binaries` → `required_bins`)
+fn suggest_key<'a>(key: &str, known: &[&'a str]) -> Option<&'a str> {
+    let normalized = key
+        .to_lowercase()
+        .replace('-', "_")
+        .replace("binaries", "bins")
+        .replace("binary", "bin");
+    known
+        .iter()
+        .map(|candidate| (strsim::levenshtein(&normalized, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 3)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn unknown_key_problem(table: &str, key: &str, known: &[&str]) -> String {
+    match suggest_key(key, known) {
+        Some(suggestion) => format!(
+            "unknown key '{}' in {} (did you mean '{}'?)",
+            key, table, suggestion
+        ),
+        None => format!(
+            "unknown key '{}' in {} (expected: {})",
+            key,
+            table,
+            known.join(", ")
+        ),
+    }
+}
+
+/// Check a parsed bootstrap.toml for common mistakes before deserializing it
+///
+/// 🤓 serde's "missing field `bootstrap`" says nothing about *why*; this pre-pass
+/// reports every problem at once with a suggested fix
+pub fn validate_bootstrap_config(value: &toml::Value) -> Result<()> {
+    let mut problems = Vec::new();
+    let root = value
+        .as_table()
+        .context("bootstrap config must be a TOML table")?;
+
+    match root.get("bootstrap") {
+        None => {
+            let misplaced: Vec<&str> = SECTION_KEYS
+                .into_iter()
+                .filter(|key| root.contains_key(*key))
+                .collect();
+            if !misplaced.is_empty() {
+                problems.push(format!(
+                    "{} must be inside the [bootstrap] section (use [bootstrap.{}])",
+                    misplaced.join(", "),
+                    misplaced[0]
+                ));
+            } else if let Some(key) = root
+                .keys()
+                .find(|key| suggest_key(key, &["bootstrap"]).is_some())
+            {
+                problems.push(format!(
+                    "missing [bootstrap] section (found [{}]; rename it to [bootstrap])",
+                    key
+                ));
             } else {
-                None
+                problems.push(
+                    "missing [bootstrap] section (start with [bootstrap.required_bins])"
+                        .to_string(),
+                );
             }
-        })
+        }
+        Some(toml::Value::Table(section)) => {
+            // 🤓 [bootstrap] also holds settings for other stages (directories, services, ...),
+            // so only keys that look like a misspelt binary list are reported
+            for key in section.keys() {
+                if !SECTION_KEYS.contains(&key.as_str())
+                    && suggest_key(key, &SECTION_KEYS).is_some()
+                {
+                    problems.push(unknown_key_problem("[bootstrap]", key, &SECTION_KEYS));
+                }
+            }
+            if !section.contains_key("required_bins")
+                && !section
+                    .keys()
+                    .any(|key| suggest_key(key, &SECTION_KEYS) == Some("required_bins"))
+            {
+                problems.push("missing [bootstrap.required_bins] table".to_string());
+            }
+
+            for list in SECTION_KEYS {
+                match section.get(list) {
+                    None => {}
+                    Some(toml::Value::Table(bins)) => {
+                        for (name, spec) in bins {
+                            check_binary_spec(list, name, spec, &mut problems);
+                        }
+                    }
+                    Some(_) => problems.push(format!(
+                        "bootstrap.{} must be a table of binaries (use [bootstrap.{}])",
+                        list, list
+                    )),
+                }
+            }
+        }
+        Some(_) => problems.push("bootstrap must be a table (use [bootstrap])".to_string()),
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+    anyhow::bail!("\n  - {}", problems.join("\n  - "))
 }
 
-/// Get version of binary by running `<binary> --version`
-fn get_version(name: &str) -> Option<String> {
-    let output = Command::new(name).arg("--version").output().ok()?;
+fn check_binary_spec(list: &str, name: &str, spec: &toml::Value, problems: &mut Vec<String>) {
+    let table = format!("bootstrap.{}.{}", list, name);
+    let Some(spec) = spec.as_table() else {
+        let example = spec.as_str().unwrap_or("*");
+        problems.push(format!(
+            "{} must be a table (use {} = {{ version = \"{}\" }})",
+            table, name, example
+        ));
+        return;
+    };
+
+    for key in spec.keys() {
+        if !SPEC_KEYS.contains(&key.as_str()) {
+            problems.push(unknown_key_problem(&table, key, &SPEC_KEYS));
+        }
+    }
+    match spec.get("version") {
+        Some(toml::Value::String(_)) => {}
+        Some(_) => problems.push(format!(
+            "{}.version must be a string (e.g. \">=1.0.0\" or \"*\")",
+            table
+        )),
+        None => problems.push(format!(
+            "{} is missing version (use version = \"*\" for any version)",
+            table
+        )),
+    }
+}
 
+/// Check if binary exists in PATH
+fn find_binary(name: &str) -> Option<PathBuf> {
+    let output = Command::new("which").arg(name).output().ok()?;
     if !output.status.success() {
         return None;
     }
+    resolve_which_output(&String::from_utf8_lossy(&output.stdout))
+}
 
-    let version_output = String::from_utf8(output.stdout).ok()?;
-    extract_version(&version_output)
+/// Absolute, canonical path from `which` output (first line, whitespace trimmed)
+///
+/// 🤓 Canonicalizing also follows symlinks; the raw path is kept if that fails
+fn resolve_which_output(stdout: &str) -> Option<PathBuf> {
+    let raw = stdout
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    let path = PathBuf::from(raw);
+    Some(std::fs::canonicalize(&path).unwrap_or(path))
 }
 
-/// Check if docker binary is actually podman
-/// 🤓 Checks both symlink target and version output
-fn is_docker_actually_podman() -> bool {
-    // Check if docker is symlinked to podman
-    if let Some(docker_path) = find_binary("docker") {
-        if let Ok(target) = std::fs::read_link(&docker_path) {
-            if target.to_string_lossy().contains("podman") {
-                return true;
-            }
-        }
-        // Also resolve canonical path in case of nested symlinks
-        if let Ok(canonical) = std::fs::canonicalize(&docker_path) {
-            if canonical.to_string_lossy().contains("podman") {
-                return true;
-            }
-        }
+/// Get version of binary by running its `version_command`, or `<binary> --version`
+fn get_version(name: &str, spec: &BinarySpec) -> Option<String> {
+    let output = match spec.version_command.as_deref() {
+        Some([cmd, args @ ..]) => Command::new(cmd).args(args).output().ok()?,
+        _ => Command::new(name).arg("--version").output().ok()?,
+    };
+
+    if !output.status.success() {
+        return None;
     }
 
-    // Fallback: check version output
-    if let Ok(output) = Command::new("docker").arg("--version").output() {
-        if let Ok(version_output) = String::from_utf8(output.stdout) {
-            if version_output.contains("podman") {
-                return true;
-            }
-        }
+    // 🤓 Some tools (java, older python) print their version on stderr
+    extract_version(&String::from_utf8_lossy(&output.stdout))
+        .or_else(|| extract_version(&String::from_utf8_lossy(&output.stderr)))
+}
+
+/// Check if the docker binary at `docker_path` is actually podman
+/// 🤓 `find_binary` already resolved symlinks, so a podman symlink shows in the
+/// path; a wrapper script only shows in the version output
+fn is_docker_actually_podman(docker_path: &Path) -> bool {
+    if docker_path.to_string_lossy().contains("podman") {
+        return true;
     }
 
-    false
+    Command::new(docker_path)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .is_some_and(|version_output| version_output.contains("podman"))
 }
 
 /// Extract semantic version from version output
@@ -141,12 +410,37 @@ fn is_docker_actually_podman() -> bool {
 ///   "git version 2.34.1" -> "2.34.1"
 ///   "docker version 20.10.0, build..." -> "20.10.0"
 ///   "just 1.5.0" -> "1.5.0"
+///   "Python 3.11" -> "3.11.0" (missing patch normalized to 0)
+///   "node 20" -> "20.0.0"
+///   "tool (c) 2024 Acme" -> None (a bare number must look like a version)
 fn extract_version(output: &str) -> Option<String> {
     // Common patterns: "X.Y.Z" or "vX.Y.Z"
     let re = regex::Regex::new(r"v?(\d+\.\d+\.\d+)").ok()?;
-    re.captures(output)
-        .and_then(|cap| cap.get(1))
-        .map(|m| m.as_str().to_string())
+    if let Some(m) = re.captures(output).and_then(|cap| cap.get(1)) {
+        return Some(m.as_str().to_string());
+    }
+
+    // 🤓 Fallbacks so semver can still parse "X.Y" and bare "X"
+    let two_part = regex::Regex::new(r"\bv?(\d+)\.(\d+)\b").ok()?;
+    if let Some(cap) = two_part.captures(output) {
+        return Some(format!("{}.{}.0", &cap[1], &cap[2]));
+    }
+
+    // A bare major version only counts on the first line when it is shaped like
+    // one ("v20", "version 20", "node 20"), never a year or build number
+    let tokens: Vec<&str> = output.lines().next()?.split_whitespace().collect();
+    tokens.iter().enumerate().find_map(|(i, token)| {
+        let token = token.trim_end_matches(',');
+        let (digits, prefixed) = match token.strip_prefix('v') {
+            Some(digits) => (digits, true),
+            None => (token, false),
+        };
+        let after_version = i > 0 && tokens[i - 1].eq_ignore_ascii_case("version");
+        let name_then_number = i == 1 && tokens.len() == 2;
+        let numeric = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+        (numeric && (prefixed || after_version || name_then_number))
+            .then(|| format!("{}.0.0", digits))
+    })
 }
 
 /// Check if installed version meets requirement
@@ -154,6 +448,13 @@ fn extract_version(output: &str) -> Option<String> {
 fn version_meets_requirement(installed: &str, requirement: &str) -> Result<bool> {
     // Parse requirement (e.g., ">=1.0.0")
     let requirement = requirement.trim();
+    if requirement == "*" {
+        return Ok(true);
+    }
+
+    if let Some(release) = requirement.strip_prefix("~=") {
+        return compatible_release(installed, release);
+    }
 
     let (op, required_ver_str) = if requirement.starts_with(">=") {
         (">=", &requirement[2..])
@@ -185,42 +486,121 @@ fn version_meets_requirement(installed: &str, requirement: &str) -> Result<bool>
     })
 }
 
+/// Compatible-release check (`~=X.Y` means `>=X.Y, <(X+1).0`, Python packaging style)
+/// 🤓 The last given component may float: `~=2.30.1` means `>=2.30.1, <2.31.0`
+fn compatible_release(installed: &str, release: &str) -> Result<bool> {
+    let parts = release
+        .trim()
+        .split('.')
+        .map(|part| {
+            part.parse::<u64>()
+                .with_context(|| format!("Failed to parse required version: {}", release))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if parts.len() < 2 || parts.len() > 3 {
+        anyhow::bail!("~= requires X.Y or X.Y.Z, got: {}", release);
+    }
+
+    let lower = semver::Version::new(parts[0], parts[1], parts.get(2).copied().unwrap_or(0));
+    let upper = if parts.len() == 2 {
+        semver::Version::new(parts[0] + 1, 0, 0)
+    } else {
+        semver::Version::new(parts[0], parts[1] + 1, 0)
+    };
+
+    let installed_ver = semver::Version::parse(installed.trim())
+        .with_context(|| format!("Failed to parse installed version: {}", installed))?;
+
+    Ok(installed_ver >= lower && installed_ver < upper)
+}
+
+/// Declared alternatives, defaulting to podman for docker
+fn alternatives_for(name: &str, spec: &BinarySpec) -> Vec<String> {
+    match &spec.alternatives {
+        Some(alternatives) => alternatives.clone(),
+        None if name == "docker" => vec!["podman".to_string()],
+        None => Vec::new(),
+    }
+}
+
+/// Run `check_binary` for every spec on the blocking pool, all at once
+/// when `parallel`, otherwise one after another in priority order
+async fn check_binaries(
+    specs: Vec<(String, BinarySpec)>,
+    parallel: bool,
+) -> Result<Vec<BinaryCheck>> {
+    if !parallel {
+        let mut specs = specs;
+        specs.sort_by_key(|(_, spec)| spec.priority);
+        return tokio::task::spawn_blocking(move || {
+            specs
+                .iter()
+                .map(|(name, spec)| check_binary(name, spec))
+                .collect()
+        })
+        .await
+        .context("Binary check task panicked");
+    }
+
+    let tasks = specs.into_iter().map(|(name, spec)| {
+        tokio::task::spawn_blocking(move || (spec.priority, check_binary(&name, &spec)))
+    });
+
+    let mut checks = futures::future::join_all(tasks)
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .context("Binary check task panicked")?;
+    // 🤓 join_all already yields input order; the stable sort keeps priority order explicit
+    checks.sort_by_key(|(priority, _)| *priority);
+    Ok(checks.into_iter().map(|(_, check)| check).collect())
+}
+
 /// Check a single binary against its specification
-/// 🤓 Supports alternatives (e.g., "docker" can be satisfied by "podman")
-fn check_binary(name: &str, spec: &BinarySpec) -> BinaryCheck {
+/// 🤓 Falls back to `alternatives` in order (e.g., "docker" satisfied by "podman")
+pub fn check_binary(name: &str, spec: &BinarySpec) -> BinaryCheck {
     // Check primary binary first
     let mut path = find_binary(name);
     let mut found = path.is_some();
-    let mut actual_name = name.to_string();
-    let mut using_alternative = false;
+    let mut detail = None;
+    let mut alternative_used = None;
 
     // Check if docker is actually podman (symlink or wrapper)
-    if name == "docker" && found && is_docker_actually_podman() {
-        actual_name = "docker (via podman)".to_string();
-        using_alternative = true;
-    }
-
-    // If not found, check alternatives (docker -> podman)
-    if !found && name == "docker" {
-        if let Some(podman_path) = find_binary("podman") {
-            path = Some(podman_path);
-            found = true;
-            actual_name = "podman (docker alternative)".to_string();
-            using_alternative = true;
+    if name == "docker" && path.as_deref().is_some_and(is_docker_actually_podman) {
+        detail = Some("via podman".to_string());
+        alternative_used = Some(name.to_string());
+    }
+
+    // If not found, use the first alternative that is
+    if !found {
+        for alternative in alternatives_for(name, spec) {
+            if let Some(alternative_path) = find_binary(&alternative) {
+                path = Some(alternative_path);
+                found = true;
+                detail = Some(format!("via {}", alternative));
+                alternative_used = Some(alternative);
+                break;
+            }
         }
     }
 
     let (installed_version, meets_requirement) = if found {
-        // Get version from the actual binary found (podman if docker not found)
-        let binary_for_version = if name == "docker" && find_binary("docker").is_none() {
-            "podman"
-        } else {
-            name
+        // Get version from the actual binary found
+        let version = match &alternative_used {
+            // 🤓 version_command targets the primary, alternatives answer to --version
+            Some(alternative) if alternative != name => get_version(
+                alternative,
+                &BinarySpec {
+                    version_command: None,
+                    ..spec.clone()
+                },
+            ),
+            _ => get_version(name, spec),
         };
 
-        if let Some(version) = get_version(binary_for_version) {
-            // 🤓 If using alternative (podman for docker), assume it meets requirement
-            let meets = if using_alternative {
+        if let Some(version) = version {
+            // 🤓 An alternative has its own versioning, assume it meets requirement
+            let meets = if alternative_used.is_some() {
                 true
             } else {
                 version_meets_requirement(&version, &spec.version).unwrap_or(false)
@@ -235,42 +615,38 @@ fn check_binary(name: &str, spec: &BinarySpec) -> BinaryCheck {
     };
 
     BinaryCheck {
-        name: actual_name,
+        name: name.to_string(),
         found,
         installed_version,
         required_version: spec.version.clone(),
         meets_requirement,
         path,
         install_hint: spec.install_hint.clone(),
+        detail,
     }
 }
 
 /// Check all prerequisites from bootstrap config
-pub fn check_prerequisites(config_path: &Path) -> Result<PrereqResult> {
+///
+/// With `parallel` (what every `bootstrap` subcommand uses unless told
+/// otherwise) each binary is checked concurrently; each check spawns
+/// `--version` subprocesses, so this is much faster than one at a time with
+/// many binaries. Without it the checks run one after another. Results are in
+/// the same priority order either way.
+pub async fn check_prerequisites(config_path: &Path, parallel: bool) -> Result<PrereqResult> {
     let config = load_config(config_path)?;
 
-    let mut required_checks = Vec::new();
-    let mut optional_checks = Vec::new();
-
-    // Check required binaries
-    for (name, spec) in &config.bootstrap.required_bins {
-        required_checks.push(check_binary(name, spec));
-    }
-
-    // Sort by priority (lower number = higher priority)
-    required_checks.sort_by_key(|check| {
-        config
-            .bootstrap
-            .required_bins
-            .get(&check.name)
-            .map(|spec| spec.priority)
-            .unwrap_or(99)
-    });
+    // Check required binaries, sorted by priority (lower number = higher priority)
+    let mut required_specs: Vec<_> = config.bootstrap.required_bins.into_iter().collect();
+    required_specs.sort_by_key(|(_, spec)| spec.priority);
+    let optional_specs: Vec<_> = config.bootstrap.optional_bins.into_iter().collect();
 
-    // Check optional binaries
-    for (name, spec) in &config.bootstrap.optional_bins {
-        optional_checks.push(check_binary(name, spec));
-    }
+    let (required_checks, optional_checks) = futures::future::join(
+        check_binaries(required_specs, parallel),
+        check_binaries(optional_specs, parallel),
+    )
+    .await;
+    let (required_checks, optional_checks) = (required_checks?, optional_checks?);
 
     let all_required_met = required_checks
         .iter()
@@ -287,6 +663,147 @@ pub fn check_prerequisites(config_path: &Path) -> Result<PrereqResult> {
 mod tests {
     use super::*;
 
+    fn config_problems(toml_src: &str) -> String {
+        let value: toml::Value = toml::from_str(toml_src).unwrap();
+        validate_bootstrap_config(&value).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_validate_bootstrap_config_suggests_fixes() {
+        let err = config_problems("[required_bins]\ngit = { version = \"*\" }\n");
+        assert!(err.contains("inside the [bootstrap] section"), "{}", err);
+
+        let err = config_problems("[boostrap.required_bins]\ngit = { version = \"*\" }\n");
+        assert!(err.contains("found [boostrap]"), "{}", err);
+
+        let err = config_problems(
+            "[bootstrap.required_binaries]\ngit = \">=2.0\"\n[bootstrap.optional_bins]\nfzf = { min_version = \"1\" }\n",
+        );
+        assert!(err.contains("did you mean 'required_bins'"), "{}", err);
+        assert!(
+            !err.contains("missing [bootstrap.required_bins]"),
+            "{}",
+            err
+        );
+        assert!(err.contains("unknown key 'min_version'"), "{}", err);
+        assert!(err.contains("fzf is missing version"), "{}", err);
+
+        let err = config_problems("[bootstrap.required_bins]\ngit = \">=2.0\"\n");
+        assert!(err.contains("git = { version = \">=2.0\" }"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_check_prerequisites_parallel_matches_sequential_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("bootstrap.toml");
+        std::fs::write(
+            &config_path,
+            "[bootstrap.required_bins]\n\
+             sh = { version = \"*\", priority = 3 }\n\
+             b00t-missing-bin = { version = \"*\", priority = 1 }\n\
+             ls = { version = \"*\", priority = 2 }\n",
+        )
+        .unwrap();
+
+        let names = |result: &PrereqResult| -> Vec<String> {
+            result.required.iter().map(|c| c.name.clone()).collect()
+        };
+        let sequential = check_prerequisites(&config_path, false).await.unwrap();
+        let parallel = check_prerequisites(&config_path, true).await.unwrap();
+
+        assert_eq!(names(&sequential), ["b00t-missing-bin", "ls", "sh"]);
+        assert_eq!(names(&parallel), names(&sequential));
+        assert!(!parallel.all_required_met);
+    }
+
+    #[test]
+    fn test_resolve_which_output_canonicalizes() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("bin");
+        std::fs::create_dir(&bin).unwrap();
+        std::fs::write(bin.join("tool"), "").unwrap();
+        let canonical = std::fs::canonicalize(bin.join("tool")).unwrap();
+
+        let messy = format!("{}/../bin/tool  \n", bin.display());
+        assert_eq!(resolve_which_output(&messy), Some(canonical));
+        assert_eq!(
+            resolve_which_output("\n/does/not/exist\n"),
+            Some(PathBuf::from("/does/not/exist"))
+        );
+        assert_eq!(resolve_which_output("  \n"), None);
+    }
+
+    #[test]
+    fn test_validate_bootstrap_config_accepts_valid_config() {
+        let value: toml::Value = toml::from_str(
+            "[bootstrap.required_bins]\ngit = { version = \">=2.0\", priority = 1 }\n[bootstrap.optional_bins]\nfzf = { version = \"*\", alternatives = [\"sk\"] }\n",
+        )
+        .unwrap();
+        assert!(validate_bootstrap_config(&value).is_ok());
+    }
+
+    fn bin(name: &str, version: Option<&str>) -> BinaryCheck {
+        BinaryCheck {
+            name: name.to_string(),
+            found: version.is_some(),
+            installed_version: version.map(str::to_string),
+            required_version: "*".to_string(),
+            meets_requirement: version.is_some(),
+            path: None,
+            install_hint: None,
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_prereq_results_ignores_detail() {
+        let old = PrereqResult {
+            required: vec![bin("docker", Some("4.9.0"))],
+            optional: vec![],
+            all_required_met: true,
+        };
+        let mut via_podman = bin("docker", Some("4.9.0"));
+        via_podman.detail = Some("via podman".to_string());
+        let new = PrereqResult {
+            required: vec![via_podman],
+            optional: vec![],
+            all_required_met: true,
+        };
+
+        assert!(diff_prereq_results(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_diff_prereq_results() {
+        let old = PrereqResult {
+            required: vec![bin("git", Some("2.40.0")), bin("just", None)],
+            optional: vec![bin("fzf", Some("0.44.0")), bin("rg", Some("14.0.0"))],
+            all_required_met: false,
+        };
+        let new = PrereqResult {
+            required: vec![bin("git", Some("2.43.0")), bin("just", Some("1.25.0"))],
+            optional: vec![
+                bin("fzf", None),
+                bin("rg", Some("14.0.0")),
+                bin("bat", Some("0.24.0")),
+            ],
+            all_required_met: true,
+        };
+
+        let diff = diff_prereq_results(&old, &new);
+        assert_eq!(diff.newly_installed, vec!["just", "bat"]);
+        assert_eq!(diff.no_longer_found, vec!["fzf"]);
+        assert_eq!(
+            diff.version_changes,
+            vec![VersionChange {
+                name: "git".to_string(),
+                old_version: "2.40.0".to_string(),
+                new_version: "2.43.0".to_string(),
+            }]
+        );
+        assert!(diff_prereq_results(&new, &new).is_empty());
+    }
+
     #[test]
     fn test_extract_version() {
         assert_eq!(
@@ -301,6 +818,115 @@ mod tests {
         assert_eq!(extract_version("v3.2.1"), Some("3.2.1".to_string()));
     }
 
+    #[test]
+    fn test_extract_version_partial() {
+        assert_eq!(extract_version("Python 3.11"), Some("3.11.0".to_string()));
+        assert_eq!(extract_version("rustc 1.82"), Some("1.82.0".to_string()));
+        assert_eq!(extract_version("node 20"), Some("20.0.0".to_string()));
+        assert_eq!(extract_version("v20"), Some("20.0.0".to_string()));
+        assert_eq!(extract_version("no version here"), None);
+        assert_eq!(extract_version("tool version 7"), Some("7.0.0".to_string()));
+        assert_eq!(extract_version("tool (c) 2024 Acme Corp"), None);
+        assert_eq!(extract_version("built 2024-06-01 build 4512 ok"), None);
+        assert_eq!(extract_version("mytool\nCopyright 2024"), None);
+        assert!(version_meets_requirement("0.0.1", "*").unwrap());
+        assert!(version_meets_requirement("3.11.0", ">=3.10.0").unwrap());
+    }
+
+    #[test]
+    fn test_bootstrap_config_search_order() {
+        use crate::commands::config::datum_path_from_config;
+
+        let home = tempfile::tempdir().unwrap();
+        let config = home.path().join(".b00t").join("config.toml");
+        assert_eq!(datum_path_from_config(&config), "~/.dotfiles/_b00t_");
+
+        let dotfiles = home.path().join("dotfiles").join("_b00t_");
+        std::fs::create_dir_all(config.parent().unwrap()).unwrap();
+        std::fs::write(
+            &config,
+            format!("datum_path = \"{}\"\n", dotfiles.display()),
+        )
+        .unwrap();
+
+        let candidates = bootstrap_config_candidates_in(
+            &datum_path_from_config(&config),
+            Some(home.path().to_path_buf()),
+        );
+        assert_eq!(
+            candidates,
+            [
+                PathBuf::from("_b00t_/bootstrap.toml"),
+                dotfiles.join("bootstrap.toml"),
+                home.path().join(".b00t/bootstrap.toml")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_version_uses_version_command() {
+        let spec = BinarySpec {
+            version: ">=1.0.0".to_string(),
+            priority: 0,
+            install_hint: None,
+            version_command: Some(vec!["echo".to_string(), "tool 9.8.7".to_string()]),
+            alternatives: None,
+        };
+        assert_eq!(
+            get_version("definitely-not-installed", &spec),
+            Some("9.8.7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_binary_uses_first_available_alternative() {
+        let spec = BinarySpec {
+            version: ">=1.0.0".to_string(),
+            priority: 0,
+            install_hint: None,
+            version_command: None,
+            alternatives: Some(vec![
+                "b00t-not-installed-either".to_string(),
+                "sh".to_string(),
+            ]),
+        };
+        let check = check_binary("b00t-definitely-not-installed", &spec);
+        assert!(check.found);
+        assert!(check.meets_requirement);
+        assert_eq!(check.name, "b00t-definitely-not-installed");
+        assert_eq!(check.detail.as_deref(), Some("via sh"));
+        assert_eq!(
+            check.display_name(),
+            "b00t-definitely-not-installed (via sh)"
+        );
+
+        let check = check_binary(
+            "b00t-definitely-not-installed",
+            &BinarySpec {
+                alternatives: Some(vec![]),
+                ..spec
+            },
+        );
+        assert!(!check.found);
+        assert_eq!(check.name, "b00t-definitely-not-installed");
+        assert_eq!(check.detail, None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_docker_actually_podman_runs_the_given_binary() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let docker = dir.path().join("docker");
+        std::fs::write(&docker, "#!/bin/sh\necho 'podman version 4.9.3'\n").unwrap();
+        std::fs::set_permissions(&docker, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(is_docker_actually_podman(&docker));
+
+        std::fs::write(&docker, "#!/bin/sh\necho 'Docker version 24.0.7'\n").unwrap();
+        assert!(!is_docker_actually_podman(&docker));
+    }
+
     #[test]
     fn test_version_comparison() {
         assert!(version_meets_requirement("2.34.1", ">=2.30.0").unwrap());
@@ -308,4 +934,26 @@ mod tests {
         assert!(version_meets_requirement("1.0.0", "=1.0.0").unwrap());
         assert!(!version_meets_requirement("1.0.1", "=1.0.0").unwrap());
     }
+
+    #[test]
+    fn test_version_comparison_prerelease() {
+        // 🤓 semver precedence: a pre-release sorts below its release
+        assert!(!version_meets_requirement("1.0.0-beta.1", ">=1.0.0").unwrap());
+        assert!(version_meets_requirement("1.0.0-beta.2", ">=1.0.0-beta.1").unwrap());
+        assert!(version_meets_requirement("1.0.0", ">1.0.0-rc.1").unwrap());
+        assert!(version_meets_requirement("1.0.0-alpha", "<1.0.0-beta").unwrap());
+    }
+
+    #[test]
+    fn test_compatible_release() {
+        assert!(version_meets_requirement("2.34.1", "~=2.30").unwrap());
+        assert!(version_meets_requirement("2.30.0", "~=2.30").unwrap());
+        assert!(!version_meets_requirement("3.0.0", "~=2.30").unwrap());
+        assert!(!version_meets_requirement("2.29.9", "~=2.30").unwrap());
+
+        assert!(version_meets_requirement("2.30.5", "~=2.30.1").unwrap());
+        assert!(!version_meets_requirement("2.31.0", "~=2.30.1").unwrap());
+
+        assert!(version_meets_requirement("2.0.0", "~=2").is_err());
+    }
 }
@@ -5,14 +5,15 @@
 //!
 //! See: https://github.com/toon-format/toon
 
-use crate::bootstrap::prereq::PrereqResult;
+use crate::bootstrap::prereq::{BinaryCheck, PrereqResult};
 use crate::bootstrap::skeleton::SkeletonResult;
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Bootstrap report encompassing all checks
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct BootstrapReport {
     pub timestamp: String,
     pub prereq_result: PrereqResult,
@@ -20,7 +21,14 @@ pub struct BootstrapReport {
 }
 
 /// Generate Toon format report from bootstrap results
-pub fn generate_toon_report(report: &BootstrapReport, output_path: &Path) -> Result<()> {
+///
+/// With `json_sidecar`, the same report is also written as JSON next to the
+/// Toon file (`bootstrap-report.toon` → `bootstrap-report.json`) for CI scripts.
+pub fn generate_toon_report(
+    report: &BootstrapReport,
+    output_path: &Path,
+    json_sidecar: bool,
+) -> Result<()> {
     let toon_content = serialize_to_toon(report)?;
 
     let expanded_path = shellexpand::tilde(output_path.to_str().unwrap());
@@ -35,18 +43,26 @@ pub fn generate_toon_report(report: &BootstrapReport, output_path: &Path) -> Res
     fs::write(final_path, toon_content)
         .with_context(|| format!("Failed to write Toon report to {}", final_path.display()))?;
 
+    if json_sidecar {
+        let json_path = final_path.with_extension("json");
+        let json_content =
+            serde_json::to_string_pretty(report).context("Failed to serialize report to JSON")?;
+        fs::write(&json_path, json_content)
+            .with_context(|| format!("Failed to write JSON report to {}", json_path.display()))?;
+    }
+
     Ok(())
 }
 
 /// Serialize bootstrap report to Toon format
-fn serialize_to_toon(report: &BootstrapReport) -> Result<String> {
+pub fn serialize_to_toon(report: &BootstrapReport) -> Result<String> {
     let mut toon = String::new();
 
     // Meta section
     toon.push_str("# b00t Bootstrap Report\n");
     toon.push_str("# Generated in Toon format (30-60% more token-efficient than JSON)\n\n");
     toon.push_str("[meta]\n");
-    toon.push_str(&format!("timestamp = \"{}\"\n", report.timestamp));
+    toon.push_str(&format!("timestamp = {}\n", toon_string(&report.timestamp)));
     toon.push_str("format = \"toon\"\n");
     toon.push_str(
         "schema = \"https://b00t.promptexecution.com/schemas/bootstrap-report/v0.1.0\"\n\n",
@@ -73,6 +89,11 @@ fn serialize_to_toon(report: &BootstrapReport) -> Result<String> {
     toon.push_str(&format!("missing_required = {}\n", missing_required));
     toon.push_str(&format!("missing_optional = {}\n", missing_optional));
 
+    toon.push_str(&format!(
+        "skeleton = {}\n",
+        report.skeleton_result.is_some()
+    ));
+
     if let Some(ref skeleton) = report.skeleton_result {
         toon.push_str(&format!(
             "directories_created = {}\n",
@@ -94,75 +115,45 @@ fn serialize_to_toon(report: &BootstrapReport) -> Result<String> {
         toon.push_str("directory_errors = 0\n\n");
     }
 
-    // Required binaries table (Toon's tabular format)
+    // Binaries tables (one `[[...]]` entry per binary so the report stays valid TOML)
     if !report.prereq_result.required.is_empty() {
-        toon.push_str("# Required binaries (tabular format - very token-efficient)\n");
-        toon.push_str("[[required_bins]]\n");
-
+        toon.push_str("# Required binaries\n");
         for bin in &report.prereq_result.required {
-            toon.push_str(&format!("name = \"{}\"\n", bin.name));
-            toon.push_str(&format!("found = {}\n", bin.found));
-            toon.push_str(&format!(
-                "required_version = \"{}\"\n",
-                bin.required_version
-            ));
-
-            if let Some(ref installed) = bin.installed_version {
-                toon.push_str(&format!("installed_version = \"{}\"\n", installed));
-            } else {
-                toon.push_str("installed_version = \"not_installed\"\n");
-            }
-
-            toon.push_str(&format!("meets_requirement = {}\n", bin.meets_requirement));
-
-            if let Some(ref path) = bin.path {
-                toon.push_str(&format!("path = \"{}\"\n", path.display()));
-            }
-
-            toon.push_str("\n"); // Separator between table entries
+            push_binary(&mut toon, "required_bins", bin);
         }
     }
 
-    // Optional binaries table
     if !report.prereq_result.optional.is_empty() {
         toon.push_str("# Optional binaries\n");
-        toon.push_str("[[optional_bins]]\n");
-
         for bin in &report.prereq_result.optional {
-            toon.push_str(&format!("name = \"{}\"\n", bin.name));
-            toon.push_str(&format!("found = {}\n", bin.found));
-
-            if bin.found {
-                if let Some(ref installed) = bin.installed_version {
-                    toon.push_str(&format!("installed_version = \"{}\"\n", installed));
-                }
-                toon.push_str(&format!("meets_requirement = {}\n", bin.meets_requirement));
-            }
-
-            if let Some(ref hint) = bin.install_hint {
-                toon.push_str(&format!("install_hint = \"{}\"\n", hint));
-            }
-
-            toon.push_str("\n");
+            push_binary(&mut toon, "optional_bins", bin);
         }
     }
 
-    // Directory creation results
+    // Directory results
     if let Some(ref skeleton) = report.skeleton_result {
         if !skeleton.created.is_empty() {
             toon.push_str("# Directories created during bootstrap\n");
-            toon.push_str("[[directories_created]]\n");
             for dir in &skeleton.created {
-                toon.push_str(&format!("path = \"{}\"\n\n", dir.display()));
+                toon.push_str("[[directories_created]]\n");
+                toon.push_str(&format!("path = {}\n\n", toon_path(dir)));
+            }
+        }
+
+        if !skeleton.already_existed.is_empty() {
+            toon.push_str("# Directories that already existed\n");
+            for dir in &skeleton.already_existed {
+                toon.push_str("[[directories_existed]]\n");
+                toon.push_str(&format!("path = {}\n\n", toon_path(dir)));
             }
         }
 
         if !skeleton.errors.is_empty() {
             toon.push_str("# Directory creation errors\n");
-            toon.push_str("[[directory_errors]]\n");
             for (path, error) in &skeleton.errors {
-                toon.push_str(&format!("path = \"{}\"\n", path.display()));
-                toon.push_str(&format!("error = \"{}\"\n\n", error));
+                toon.push_str("[[directory_errors]]\n");
+                toon.push_str(&format!("path = {}\n", toon_path(path)));
+                toon.push_str(&format!("error = {}\n\n", toon_string(error)));
             }
         }
     }
@@ -170,8 +161,158 @@ fn serialize_to_toon(report: &BootstrapReport) -> Result<String> {
     Ok(toon)
 }
 
+/// Quote a string as a TOML basic string
+fn toon_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn toon_path(path: &Path) -> String {
+    toon_string(&path.to_string_lossy())
+}
+
+/// Append one `[[<table>]]` entry for a binary check (absent fields are omitted)
+fn push_binary(toon: &mut String, table: &str, bin: &BinaryCheck) {
+    toon.push_str(&format!("[[{}]]\n", table));
+    toon.push_str(&format!("name = {}\n", toon_string(&bin.name)));
+    toon.push_str(&format!("found = {}\n", bin.found));
+    toon.push_str(&format!(
+        "required_version = {}\n",
+        toon_string(&bin.required_version)
+    ));
+    if let Some(ref installed) = bin.installed_version {
+        toon.push_str(&format!("installed_version = {}\n", toon_string(installed)));
+    }
+    toon.push_str(&format!("meets_requirement = {}\n", bin.meets_requirement));
+    if let Some(ref path) = bin.path {
+        toon.push_str(&format!("path = {}\n", toon_path(path)));
+    }
+    if let Some(ref hint) = bin.install_hint {
+        toon.push_str(&format!("install_hint = {}\n", toon_string(hint)));
+    }
+    if let Some(ref detail) = bin.detail {
+        toon.push_str(&format!("detail = {}\n", toon_string(detail)));
+    }
+    toon.push('\n'); // Separator between table entries
+}
+
+/// Toon report layout as parsed by the toml crate
+#[derive(Debug, Deserialize)]
+struct ToonDocument {
+    meta: ToonMeta,
+    summary: ToonSummary,
+    #[serde(default)]
+    required_bins: Vec<BinaryCheck>,
+    #[serde(default)]
+    optional_bins: Vec<BinaryCheck>,
+    #[serde(default)]
+    directories_created: Vec<ToonPath>,
+    #[serde(default)]
+    directories_existed: Vec<ToonPath>,
+    #[serde(default)]
+    directory_errors: Vec<ToonDirectoryError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToonMeta {
+    timestamp: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToonSummary {
+    all_required_met: bool,
+    #[serde(default)]
+    skeleton: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToonPath {
+    path: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToonDirectoryError {
+    path: PathBuf,
+    error: String,
+}
+
+/// Reconstruct a `BootstrapReport` from a Toon string written by `generate_toon_report`
+pub fn parse_toon(input: &str) -> Result<BootstrapReport> {
+    let doc: ToonDocument = toml::from_str(input).context("Failed to parse Toon report")?;
+
+    let skeleton_result = doc.summary.skeleton.then(|| SkeletonResult {
+        created: doc
+            .directories_created
+            .into_iter()
+            .map(|d| d.path)
+            .collect(),
+        already_existed: doc
+            .directories_existed
+            .into_iter()
+            .map(|d| d.path)
+            .collect(),
+        errors: doc
+            .directory_errors
+            .into_iter()
+            .map(|e| (e.path, e.error))
+            .collect(),
+    });
+
+    Ok(BootstrapReport {
+        timestamp: doc.meta.timestamp,
+        prereq_result: PrereqResult {
+            required: doc.required_bins,
+            optional: doc.optional_bins,
+            all_required_met: doc.summary.all_required_met,
+        },
+        skeleton_result,
+    })
+}
+
+/// Read the `[[directories_created]]` paths back out of a Toon report
+///
+/// 🤓 Older reports repeat keys under one header (one entry per `path = ...`),
+/// which plain TOML rejects, so sections are tracked line by line and only each
+/// `key = value` line is handed to the toml parser. Works for both layouts.
+pub fn parse_toon_directories_created(input: &str) -> Result<Vec<PathBuf>> {
+    let mut section = "";
+    let mut directories = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            section = line.trim_start_matches('[').trim_end_matches(']');
+            continue;
+        }
+        if section != "directories_created" {
+            continue;
+        }
+
+        let entry: toml::Table = toml::from_str(line)
+            .with_context(|| format!("Invalid Toon line {}: {}", idx + 1, line))?;
+        if let Some(path) = entry.get("path").and_then(|v| v.as_str()) {
+            directories.push(PathBuf::from(path));
+        }
+    }
+
+    Ok(directories)
+}
+
+/// Whether NO_COLOR (https://no-color.org) asks for undecorated output
+fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
 /// Print Toon report to stdout in human-readable format
+/// Falls back to `print_toon_report_plain` when NO_COLOR is set
 pub fn print_toon_report(report: &BootstrapReport) {
+    if no_color_requested() {
+        print_toon_report_plain(report);
+        return;
+    }
+
     println!("🥾 b00t Bootstrap Report");
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("Generated: {}", report.timestamp);
@@ -188,7 +329,7 @@ pub fn print_toon_report(report: &BootstrapReport) {
             "❌"
         };
 
-        print!("  {} {} ", status, bin.name);
+        print!("  {} {} ", status, bin.display_name());
 
         if let Some(ref installed) = bin.installed_version {
             print!("(installed: {}", installed);
@@ -213,7 +354,7 @@ pub fn print_toon_report(report: &BootstrapReport) {
                 "  "
             };
 
-            print!("  {} {} ", status, bin.name);
+            print!("  {} {} ", status, bin.display_name());
 
             if let Some(ref installed) = bin.installed_version {
                 println!("({})", installed);
@@ -257,6 +398,97 @@ pub fn print_toon_report(report: &BootstrapReport) {
     }
 }
 
+/// Print Toon report to stdout as plain ASCII (no emoji/box drawing) for CI logs
+pub fn print_toon_report_plain(report: &BootstrapReport) {
+    println!("b00t Bootstrap Report");
+    println!("=====================");
+    println!("Generated: {}", report.timestamp);
+    println!();
+
+    println!("Required Binaries:");
+    for bin in &report.prereq_result.required {
+        let status = if bin.found && bin.meets_requirement {
+            "[OK]"
+        } else if bin.found {
+            "[WARN]"
+        } else {
+            "[MISSING]"
+        };
+
+        match &bin.installed_version {
+            Some(installed) if bin.meets_requirement => {
+                println!(
+                    "  {} {} (installed: {})",
+                    status,
+                    bin.display_name(),
+                    installed
+                )
+            }
+            Some(installed) => println!(
+                "  {} {} (installed: {}, requires: {})",
+                status,
+                bin.display_name(),
+                installed,
+                bin.required_version
+            ),
+            None => println!(
+                "  {} {} (not installed, requires: {})",
+                status,
+                bin.display_name(),
+                bin.required_version
+            ),
+        }
+    }
+
+    if !report.prereq_result.optional.is_empty() {
+        println!();
+        println!("Optional Binaries:");
+        for bin in &report.prereq_result.optional {
+            let status = if bin.found && bin.meets_requirement {
+                "[OK]"
+            } else {
+                "[--]"
+            };
+
+            match (&bin.installed_version, &bin.install_hint) {
+                (Some(installed), _) => {
+                    println!("  {} {} ({})", status, bin.display_name(), installed)
+                }
+                (None, Some(hint)) => println!("  {} {} - {}", status, bin.display_name(), hint),
+                (None, None) => println!("  {} {} (not installed)", status, bin.display_name()),
+            }
+        }
+    }
+
+    if let Some(ref skeleton) = report.skeleton_result {
+        if !skeleton.created.is_empty() || !skeleton.already_existed.is_empty() {
+            println!();
+            println!("Directories:");
+            for dir in &skeleton.created {
+                println!("  Created: {}", dir.display());
+            }
+            for dir in &skeleton.already_existed {
+                println!("  Exists:  {}", dir.display());
+            }
+        }
+
+        if !skeleton.errors.is_empty() {
+            println!();
+            println!("Errors:");
+            for (path, error) in &skeleton.errors {
+                println!("  {}: {}", path.display(), error);
+            }
+        }
+    }
+
+    println!();
+    if report.prereq_result.all_required_met {
+        println!("OK: All required prerequisites met");
+    } else {
+        println!("FAIL: Some required prerequisites are missing");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +510,108 @@ mod tests {
         assert!(toon.contains("[meta]"));
         assert!(toon.contains("format = \"toon\""));
     }
+
+    #[test]
+    fn test_parse_toon_directories_created() {
+        let report = BootstrapReport {
+            timestamp: "2025-11-09T12:00:00Z".to_string(),
+            prereq_result: PrereqResult {
+                required: vec![],
+                optional: vec![],
+                all_required_met: true,
+            },
+            skeleton_result: Some(SkeletonResult {
+                created: vec![PathBuf::from("/tmp/b00t/a"), PathBuf::from("/tmp/b00t/b")],
+                already_existed: vec![PathBuf::from("/tmp/b00t")],
+                errors: vec![(PathBuf::from("/root/x"), "denied".to_string())],
+            }),
+        };
+
+        let toon = serialize_to_toon(&report).unwrap();
+        assert_eq!(
+            parse_toon_directories_created(&toon).unwrap(),
+            vec![PathBuf::from("/tmp/b00t/a"), PathBuf::from("/tmp/b00t/b")]
+        );
+    }
+
+    #[test]
+    fn test_parse_toon_round_trip() {
+        let report = BootstrapReport {
+            timestamp: "2025-11-09T12:00:00Z".to_string(),
+            prereq_result: PrereqResult {
+                required: vec![
+                    BinaryCheck {
+                        name: "git".to_string(),
+                        found: true,
+                        installed_version: Some("2.43.0".to_string()),
+                        required_version: ">=2.30".to_string(),
+                        meets_requirement: true,
+                        path: Some(PathBuf::from("/usr/bin/git")),
+                        install_hint: None,
+                        detail: None,
+                    },
+                    BinaryCheck {
+                        name: "docker".to_string(),
+                        found: true,
+                        installed_version: Some("4.9.3".to_string()),
+                        required_version: "*".to_string(),
+                        meets_requirement: true,
+                        path: Some(PathBuf::from("/usr/bin/podman")),
+                        install_hint: None,
+                        detail: Some("via podman".to_string()),
+                    },
+                ],
+                optional: vec![BinaryCheck {
+                    name: "fzf".to_string(),
+                    found: false,
+                    installed_version: None,
+                    required_version: "*".to_string(),
+                    meets_requirement: false,
+                    path: None,
+                    install_hint: Some("brew install \"fzf\"".to_string()),
+                    detail: None,
+                }],
+                all_required_met: true,
+            },
+            skeleton_result: Some(SkeletonResult {
+                created: vec![PathBuf::from("/tmp/b00t/a"), PathBuf::from("/tmp/b00t/b")],
+                already_existed: vec![PathBuf::from("/tmp/b00t")],
+                errors: vec![(PathBuf::from("/root/x"), "denied".to_string())],
+            }),
+        };
+
+        let toon = serialize_to_toon(&report).unwrap();
+        assert_eq!(parse_toon(&toon).unwrap(), report);
+
+        let no_skeleton = BootstrapReport {
+            skeleton_result: None,
+            ..report
+        };
+        let toon = serialize_to_toon(&no_skeleton).unwrap();
+        assert_eq!(parse_toon(&toon).unwrap(), no_skeleton);
+    }
+
+    #[test]
+    fn test_json_sidecar_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("bootstrap-report.toon");
+        let report = BootstrapReport {
+            timestamp: "2025-11-09T12:00:00Z".to_string(),
+            prereq_result: PrereqResult {
+                required: vec![],
+                optional: vec![],
+                all_required_met: true,
+            },
+            skeleton_result: None,
+        };
+
+        generate_toon_report(&report, &output, true).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(dir.path().join("bootstrap-report.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["timestamp"], "2025-11-09T12:00:00Z");
+        assert_eq!(json["prereq_result"]["all_required_met"], true);
+    }
 }
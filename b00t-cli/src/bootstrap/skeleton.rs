@@ -3,7 +3,7 @@
 //! Creates directories specified in bootstrap.toml
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -17,11 +17,78 @@ struct BootstrapConfig {
 #[derive(Debug, Deserialize)]
 struct BootstrapDirectories {
     #[serde(default)]
-    directories: HashMap<String, String>,
+    directories: HashMap<String, DirectorySpec>,
+}
+
+/// A `[bootstrap.directories]` entry: a bare path or `{ path = "...", mode = 0o700 }`
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum DirectorySpec {
+    Path(String),
+    Detailed {
+        path: String,
+        /// Permission bits applied to the directory, new or existing (Unix only)
+        #[serde(default)]
+        mode: Option<DirectoryMode>,
+    },
+}
+
+/// Directory permission bits: a TOML octal integer (`0o700`) or an octal string (`"700"`)
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum DirectoryMode {
+    Bits(u32),
+    Octal(String),
+}
+
+impl DirectoryMode {
+    /// Permission bits, rejecting values that are not a plausible directory mode
+    ///
+    /// 🤓 A decimal `mode = 700` is the integer 0o1274; it and most other decimal
+    /// spellings set bits outside 0o777, which is how they get caught here.
+    fn bits(&self) -> Result<u32> {
+        let bits = match self {
+            DirectoryMode::Bits(bits) => *bits,
+            DirectoryMode::Octal(octal) => {
+                let digits = octal.strip_prefix("0o").unwrap_or(octal);
+                u32::from_str_radix(digits, 8).with_context(|| {
+                    format!("Invalid mode \"{}\" (expected octal, e.g. \"700\")", octal)
+                })?
+            }
+        };
+        if bits > 0o777 {
+            anyhow::bail!(
+                "Invalid mode {:o} (write it as octal, e.g. mode = 0o700 or mode = \"700\")",
+                bits
+            );
+        }
+        if bits & 0o500 != 0o500 {
+            anyhow::bail!(
+                "Invalid mode {:o}: the owner needs read and execute permission on a directory",
+                bits
+            );
+        }
+        Ok(bits)
+    }
+}
+
+impl DirectorySpec {
+    fn path(&self) -> &str {
+        match self {
+            DirectorySpec::Path(path) | DirectorySpec::Detailed { path, .. } => path,
+        }
+    }
+
+    fn mode(&self) -> Option<&DirectoryMode> {
+        match self {
+            DirectorySpec::Path(_) => None,
+            DirectorySpec::Detailed { mode, .. } => mode.as_ref(),
+        }
+    }
 }
 
 /// Result of skeleton generation
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct SkeletonResult {
     pub created: Vec<PathBuf>,
     pub already_existed: Vec<PathBuf>,
@@ -35,31 +102,45 @@ impl SkeletonResult {
     }
 
     /// Get total count of directories processed
+    /// A created directory whose mode failed is in both `created` and `errors`, counted once
     pub fn total_count(&self) -> usize {
-        self.created.len() + self.already_existed.len() + self.errors.len()
+        let failed = self
+            .errors
+            .iter()
+            .filter(|(path, _)| !self.created.contains(path))
+            .count();
+        self.created.len() + self.already_existed.len() + failed
     }
 }
 
-/// Expand path with tilde (~) to home directory
-fn expand_path(path: &str) -> PathBuf {
+/// Expand `$VAR` / `${VAR}` from the environment, then a leading tilde (~) to home directory
+/// Errors when a referenced environment variable is not set
+fn expand_path(path: &str) -> Result<PathBuf> {
+    let path = shellexpand::env(path)
+        .map_err(|e| anyhow::anyhow!("Environment variable ${} is not set", e.var_name))?;
+
     if path.starts_with('~') {
         if let Some(home) = dirs::home_dir() {
             let remainder = &path[1..]; // Remove leading ~
             let remainder = remainder.trim_start_matches('/'); // Remove leading /
-            return home.join(remainder);
+            return Ok(home.join(remainder));
         }
     }
-    PathBuf::from(path)
+    Ok(PathBuf::from(path.as_ref()))
 }
 
 /// Create a single directory with proper error handling
-fn create_directory(path: &Path) -> Result<bool> {
+/// Returns Ok(true) when the directory was (or, with `dry_run`, would be) created
+fn create_directory(path: &Path, dry_run: bool) -> Result<bool> {
     if path.exists() {
         if path.is_dir() {
             Ok(false) // Already exists
         } else {
             anyhow::bail!("Path exists but is not a directory: {}", path.display());
         }
+    } else if dry_run {
+        println!("  📝 [dry-run] would create: {}", path.display());
+        Ok(true)
     } else {
         fs::create_dir_all(path)
             .with_context(|| format!("Failed to create directory: {}", path.display()))?;
@@ -67,8 +148,33 @@ fn create_directory(path: &Path) -> Result<bool> {
     }
 }
 
+/// Apply permission bits to a directory (warns and skips on non-Unix platforms)
+fn set_directory_mode(path: &Path, mode: &DirectoryMode) -> Result<()> {
+    let mode = mode
+        .bits()
+        .with_context(|| format!("Not changing permissions of {}", path.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(mode))
+            .with_context(|| format!("Failed to set mode {:o} on {}", mode, path.display()))?;
+    }
+    #[cfg(not(unix))]
+    {
+        eprintln!(
+            "⚠️  Skipping mode {:o} for {}: permissions are only set on Unix",
+            mode,
+            path.display()
+        );
+    }
+
+    Ok(())
+}
+
 /// Create skeleton directory structure from bootstrap config
-pub fn create_skeleton(config_path: &Path) -> Result<SkeletonResult> {
+/// With `dry_run`, nothing is created and `created` lists what would be
+pub fn create_skeleton(config_path: &Path, dry_run: bool) -> Result<SkeletonResult> {
     // Read and parse config
     let content = std::fs::read_to_string(config_path)
         .with_context(|| format!("Failed to read {}", config_path.display()))?;
@@ -81,15 +187,34 @@ pub fn create_skeleton(config_path: &Path) -> Result<SkeletonResult> {
     let mut errors = Vec::new();
 
     // Process each directory
-    for (_name, path_str) in &config.bootstrap.directories {
-        let path = expand_path(path_str);
-
-        match create_directory(&path) {
-            Ok(true) => {
-                created.push(path);
+    for (_name, spec) in &config.bootstrap.directories {
+        // 🤓 An unset $VAR only fails this directory, the rest are still created
+        let path = match expand_path(spec.path()) {
+            Ok(path) => path,
+            Err(e) => {
+                errors.push((PathBuf::from(spec.path()), e.to_string()));
+                continue;
             }
-            Ok(false) => {
-                already_existed.push(path);
+        };
+
+        match create_directory(&path, dry_run) {
+            Ok(created_now) => {
+                // 🤓 Record a new directory before its chmod, so rollback still removes
+                // it when the mode can't be applied
+                if created_now {
+                    created.push(path.clone());
+                }
+                // Existing directories get the mode too, so tightening `mode` in
+                // bootstrap.toml fixes an already-created ~/.b00t/secrets
+                if let Some(mode) = spec.mode().filter(|_| !dry_run)
+                    && let Err(e) = set_directory_mode(&path, mode)
+                {
+                    errors.push((path, format!("{:#}", e)));
+                    continue;
+                }
+                if !created_now {
+                    already_existed.push(path);
+                }
             }
             Err(e) => {
                 errors.push((path, e.to_string()));
@@ -104,20 +229,254 @@ pub fn create_skeleton(config_path: &Path) -> Result<SkeletonResult> {
     })
 }
 
+/// Result of removing previously created skeleton directories
+#[derive(Debug, Default)]
+pub struct RollbackResult {
+    pub removed: Vec<PathBuf>,
+    pub missing: Vec<PathBuf>,
+    /// Left in place because they are not empty (pass `force` to remove)
+    pub skipped_non_empty: Vec<PathBuf>,
+    pub errors: Vec<(PathBuf, String)>,
+}
+
+/// Remove directories created by a bootstrap run, deepest first
+/// Non-empty directories are only removed (recursively) with `force`
+pub fn remove_skeleton(directories: &[PathBuf], force: bool) -> RollbackResult {
+    let mut ordered: Vec<&PathBuf> = directories.iter().collect();
+    ordered.sort_by_key(|path| std::cmp::Reverse(path.components().count()));
+
+    let mut result = RollbackResult::default();
+    for path in ordered {
+        if !path.exists() {
+            result.missing.push(path.clone());
+            continue;
+        }
+        if !path.is_dir() {
+            result
+                .errors
+                .push((path.clone(), "not a directory".to_string()));
+            continue;
+        }
+
+        let is_empty = match fs::read_dir(path) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(e) => {
+                result.errors.push((path.clone(), e.to_string()));
+                continue;
+            }
+        };
+
+        let removal = if is_empty {
+            fs::remove_dir(path)
+        } else if force {
+            fs::remove_dir_all(path)
+        } else {
+            result.skipped_non_empty.push(path.clone());
+            continue;
+        };
+
+        match removal {
+            Ok(()) => result.removed.push(path.clone()),
+            Err(e) => result.errors.push((path.clone(), e.to_string())),
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_expand_path() {
-        let path = expand_path("~/.b00t/config");
+        let path = expand_path("~/.b00t/config").unwrap();
         assert!(path.to_string_lossy().contains(".b00t/config"));
         assert!(!path.to_string_lossy().contains('~'));
     }
 
+    #[test]
+    fn test_create_directory_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("would-exist");
+
+        assert!(create_directory(&target, true).unwrap());
+        assert!(!target.exists());
+
+        assert!(create_directory(&target, false).unwrap());
+        assert!(target.is_dir());
+        assert!(!create_directory(&target, true).unwrap());
+    }
+
+    #[test]
+    fn test_remove_skeleton_keeps_non_empty_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let parent = dir.path().join("b00t");
+        let child = parent.join("cache");
+        let full = dir.path().join("full");
+        fs::create_dir_all(&child).unwrap();
+        fs::create_dir_all(&full).unwrap();
+        fs::write(full.join("keep.txt"), "data").unwrap();
+
+        let dirs = vec![parent.clone(), child.clone(), full.clone()];
+        let result = remove_skeleton(&dirs, false);
+        assert_eq!(result.removed, vec![child.clone(), parent.clone()]);
+        assert_eq!(result.skipped_non_empty, vec![full.clone()]);
+        assert!(full.exists());
+
+        let result = remove_skeleton(&dirs, true);
+        assert_eq!(result.removed, vec![full.clone()]);
+        assert_eq!(result.missing.len(), 2);
+        assert!(!full.exists());
+    }
+
     #[test]
     fn test_expand_path_non_tilde() {
-        let path = expand_path("/tmp/test");
+        let path = expand_path("/tmp/test").unwrap();
         assert_eq!(path, PathBuf::from("/tmp/test"));
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_skeleton_applies_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let keys = dir.path().join("keys");
+        let logs = dir.path().join("logs");
+        let config = dir.path().join("bootstrap.toml");
+        fs::write(
+            &config,
+            format!(
+                "[bootstrap.directories]\nkeys = {{ path = \"{}\", mode = 0o700 }}\nlogs = \"{}\"\n",
+                keys.display(),
+                logs.display()
+            ),
+        )
+        .unwrap();
+
+        let result = create_skeleton(&config, false).unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.created.len(), 2);
+
+        let mode = fs::metadata(&keys).unwrap().permissions().mode() & 0o7777;
+        assert_eq!(mode, 0o700);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_skeleton_applies_mode_to_existing_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let secrets = dir.path().join("secrets");
+        fs::create_dir(&secrets).unwrap();
+        fs::set_permissions(&secrets, fs::Permissions::from_mode(0o755)).unwrap();
+        let config = dir.path().join("bootstrap.toml");
+        fs::write(
+            &config,
+            format!(
+                "[bootstrap.directories]\nsecrets = {{ path = \"{}\", mode = \"700\" }}\n",
+                secrets.display()
+            ),
+        )
+        .unwrap();
+
+        let result = create_skeleton(&config, false).unwrap();
+        assert!(result.is_success(), "{:?}", result.errors);
+        assert_eq!(result.already_existed, vec![secrets.clone()]);
+        let mode = fs::metadata(&secrets).unwrap().permissions().mode() & 0o7777;
+        assert_eq!(mode, 0o700);
+    }
+
+    #[test]
+    fn test_create_skeleton_records_created_directory_when_mode_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let keys = dir.path().join("keys");
+        let config = dir.path().join("bootstrap.toml");
+        // Decimal `mode = 700` is rejected after the directory was created
+        fs::write(
+            &config,
+            format!(
+                "[bootstrap.directories]\nkeys = {{ path = \"{}\", mode = 700 }}\n",
+                keys.display()
+            ),
+        )
+        .unwrap();
+
+        let result = create_skeleton(&config, false).unwrap();
+        assert_eq!(result.created, vec![keys.clone()]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.total_count(), 1);
+
+        let rollback = remove_skeleton(&result.created, false);
+        assert_eq!(rollback.removed, vec![keys.clone()]);
+        assert!(!keys.exists());
+    }
+
+    #[test]
+    fn test_directory_mode_rejects_decimal_and_implausible_modes() {
+        assert_eq!(DirectoryMode::Bits(0o700).bits().unwrap(), 0o700);
+        assert_eq!(
+            DirectoryMode::Octal("0750".to_string()).bits().unwrap(),
+            0o750
+        );
+        assert_eq!(
+            DirectoryMode::Octal("0o700".to_string()).bits().unwrap(),
+            0o700
+        );
+
+        // `mode = 700` written in decimal is 0o1274
+        assert!(DirectoryMode::Bits(700).bits().is_err());
+        assert!(DirectoryMode::Bits(0o7777).bits().is_err());
+        // Owner could not list the directory
+        assert!(DirectoryMode::Bits(0o077).bits().is_err());
+        assert!(DirectoryMode::Octal("rwx".to_string()).bits().is_err());
+        assert!(DirectoryMode::Octal("800".to_string()).bits().is_err());
+    }
+
+    #[test]
+    fn test_expand_path_env_vars() {
+        unsafe {
+            std::env::set_var("B00T_TEST_DATA_HOME", "/tmp/b00t-data");
+            std::env::remove_var("B00T_TEST_UNSET_VAR");
+        }
+
+        assert_eq!(
+            expand_path("$B00T_TEST_DATA_HOME/b00t").unwrap(),
+            PathBuf::from("/tmp/b00t-data/b00t")
+        );
+        assert_eq!(
+            expand_path("${B00T_TEST_DATA_HOME}/cache").unwrap(),
+            PathBuf::from("/tmp/b00t-data/cache")
+        );
+
+        let err = expand_path("$B00T_TEST_UNSET_VAR/b00t").unwrap_err();
+        assert!(err.to_string().contains("B00T_TEST_UNSET_VAR"));
+    }
+
+    #[test]
+    fn test_create_skeleton_reports_unset_env_var() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("bootstrap.toml");
+        fs::write(
+            &config,
+            format!(
+                "[bootstrap.directories]\nok = \"{}\"\nbad = \"$B00T_TEST_MISSING_DIR/x\"\n",
+                dir.path().join("ok").display()
+            ),
+        )
+        .unwrap();
+        unsafe {
+            std::env::remove_var("B00T_TEST_MISSING_DIR");
+        }
+
+        let result = create_skeleton(&config, false).unwrap();
+        assert_eq!(result.created, vec![dir.path().join("ok")]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].0,
+            PathBuf::from("$B00T_TEST_MISSING_DIR/x")
+        );
+    }
 }
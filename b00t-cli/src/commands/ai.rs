@@ -1,5 +1,28 @@
-use anyhow::Result;
+use crate::datum_ai_model::AiModelDatumEntry;
+use crate::model_manager::ModelRecord;
+use crate::{AiConfig, AiHealthCheck, get_expanded_path};
+use anyhow::{Context, Result};
+use b00t_c0re_lib::datum_ai_model::{AiModelDatum, ModelProvider};
 use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Order of `ai list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AiListSort {
+    Name,
+    /// Cheapest input price first, then output price; unpriced models last
+    Cost,
+}
+
+/// Output of `ai embed`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum EmbedFormat {
+    /// JSON array of floats
+    Json,
+    /// Raw float32 little-endian
+    Binary,
+}
 
 #[derive(Parser)]
 pub enum AiCommands {
@@ -12,12 +35,14 @@ pub enum AiCommands {
         file: String,
     },
     #[clap(
-        about = "List available AI provider configurations",
-        long_about = "List available AI provider configurations.\n\nExamples:\n  b00t-cli ai list\n  b00t-cli ai list --json"
+        about = "List AI model datums with context window and pricing",
+        long_about = "List every *.ai_model.toml datum with its provider, size, context window and price per 1M input/output tokens (input_price_per_1m / output_price_per_1m).\n\nExamples:\n  b00t-cli ai list\n  b00t-cli ai list --sort-by cost\n  b00t-cli ai list --json"
     )]
     List {
         #[clap(long, help = "Output in JSON format")]
         json: bool,
+        #[clap(long, value_enum, default_value_t = AiListSort::Name, help = "Sort order")]
+        sort_by: AiListSort,
     },
     #[clap(
         about = "Output AI providers in various formats",
@@ -31,24 +56,695 @@ pub enum AiCommands {
         #[clap(help = "Comma-separated list of AI provider names to output")]
         providers: String,
     },
+    #[clap(
+        about = "Print the litellm model string of the best available model for a capability",
+        long_about = "Pick the best enabled *.ai_model.toml model with a capability whose API key env var is set.\n\nModels are ranked by size (largest first, or --size first). Prints only the litellm model string so scripts can capture it.\n\nExamples:\n  b00t-cli ai select function-calling\n  b00t-cli ai select code --size small\n  MODEL=$(b00t-cli ai select tools)"
+    )]
+    Select {
+        #[clap(
+            help = "Capability (chat, code, tools, vision, embeddings, ... or function-calling, code-generation)"
+        )]
+        capability: String,
+        #[clap(long, value_parser = ["large", "medium", "small"], help = "Preferred model size")]
+        size: Option<String>,
+    },
+    #[clap(
+        about = "Check whether a prompt of <tokens> fits a model's context window",
+        long_about = "Load the *.ai_model.toml datum and compare a token count against its context_window.\n\nExits 0 when tokens < context_window - reserve, 1 otherwise (or when the datum has no context_window).\n\nExamples:\n  b00t-cli ai context-budget claude-3-5-sonnet 150000\n  b00t-cli ai context-budget gpt-4o-mini 120000 --reserve 4096\n  b00t-cli ai context-budget kimi-k2 $TOKENS && echo fits"
+    )]
+    ContextBudget {
+        #[clap(help = "AI model datum name")]
+        model: String,
+        #[clap(help = "Tokens already in the prompt/context")]
+        tokens: u64,
+        #[clap(
+            long,
+            default_value_t = 0,
+            help = "Tokens to keep free (e.g. for the completion)"
+        )]
+        reserve: u64,
+    },
+    #[clap(
+        about = "Test a new API key against the provider and save it to a .env file",
+        long_about = "Test a new API key against the provider's [health] endpoint (from its .ai.toml datum) and, if accepted, write KEY=value to a .env file.\n\nThe key is read from stdin when --key is not given (keeps it out of shell history).\n\nExamples:\n  b00t-cli ai rotate-key openai --key sk-...\n  pass show openrouter | b00t-cli ai rotate-key openrouter --env-file ~/.b00t/.env"
+    )]
+    RotateKey {
+        #[clap(help = "AI provider name (e.g., openai, anthropic)")]
+        provider: String,
+        #[clap(long, help = "New API key (default: read from stdin)")]
+        key: Option<String>,
+        #[clap(long, default_value = ".env", help = ".env file to update")]
+        env_file: PathBuf,
+    },
+    #[clap(
+        about = "Compare latency and token usage of models on the same prompt",
+        long_about = "Send the same prompt to each *.ai_model.toml model and compare time-to-first-token, total time and tokens.\n\nRequests go to the model's OpenAI-compatible api_base (or the provider's public endpoint) with its litellm_model id. Models whose api_key_env is not set are skipped. Point api_base at a litellm proxy for providers without an OpenAI-compatible API.\n\nExamples:\n  b00t-cli ai benchmark gpt-4o-mini kimi-k2 --prompt \"Explain RAII in one paragraph\"\n  b00t-cli ai benchmark gpt-4o-mini claude-3-5-sonnet qwen-2.5-72b --prompt hi --max-tokens 64"
+    )]
+    Benchmark {
+        #[clap(required = true, num_args = 2.., help = "AI model datum names to compare")]
+        models: Vec<String>,
+        #[clap(long, help = "Prompt sent to every model")]
+        prompt: String,
+        #[clap(long, default_value_t = 256, help = "Completion token limit per model")]
+        max_tokens: u32,
+    },
+    #[clap(
+        about = "Print the embedding vector of a text",
+        long_about = "Embed a text with an *.ai_model.toml model that has the embeddings capability: --model, or the best available one whose API key env var is set.\n\nThe request goes to the model's OpenAI-compatible api_base (or the provider's public endpoint) at /embeddings, authenticated with api_key_env.\n\n--format json prints a JSON array of floats; --format binary writes raw float32 little-endian (4 bytes per dimension), e.g. for piping into Qdrant tooling.\n\nExamples:\n  b00t-cli ai embed \"hello world\"\n  b00t-cli ai embed \"hello world\" --model text-embedding-3-small\n  b00t-cli ai embed \"$(cat notes.md)\" --format binary > notes.f32"
+    )]
+    Embed {
+        #[clap(help = "Text to embed")]
+        text: String,
+        #[clap(
+            long,
+            help = "AI model datum name (default: best model with embeddings)"
+        )]
+        model: Option<String>,
+        #[clap(long, value_enum, default_value_t = EmbedFormat::Json, help = "Output format")]
+        format: EmbedFormat,
+    },
 }
 
 impl AiCommands {
-    pub fn execute(&self, _path: &str) -> Result<()> {
+    pub fn execute(&self, path: &str) -> Result<()> {
         match self {
             AiCommands::Add { .. } => {
                 println!("🤖 AI add functionality coming soon...");
                 Ok(())
             }
-            AiCommands::List { .. } => {
-                println!("📋 AI list functionality coming soon...");
-                Ok(())
-            }
+            AiCommands::List { json, sort_by } => list(path, *json, *sort_by),
             AiCommands::Output { .. } => {
                 println!("📤 AI output functionality coming soon...");
                 Ok(())
             }
+            AiCommands::Select { capability, size } => select(path, capability, size.as_deref()),
+            AiCommands::ContextBudget {
+                model,
+                tokens,
+                reserve,
+            } => context_budget(path, model, *tokens, *reserve),
+            AiCommands::RotateKey { .. } => {
+                anyhow::bail!("rotate-key is async; use execute_async")
+            }
+            AiCommands::Benchmark { .. } => {
+                anyhow::bail!("benchmark is async; use execute_async")
+            }
+            AiCommands::Embed { .. } => {
+                anyhow::bail!("embed is async; use execute_async")
+            }
+        }
+    }
+
+    /// Like `execute`, but also runs commands that call provider APIs
+    pub async fn execute_async(&self, path: &str) -> Result<()> {
+        match self {
+            AiCommands::RotateKey {
+                provider,
+                key,
+                env_file,
+            } => rotate_key(path, provider, key.clone(), env_file).await,
+            AiCommands::Benchmark {
+                models,
+                prompt,
+                max_tokens,
+            } => benchmark(path, models, prompt, *max_tokens).await,
+            AiCommands::Embed {
+                text,
+                model,
+                format,
+            } => embed(path, text, model.as_deref(), *format).await,
+            _ => self.execute(path),
+        }
+    }
+}
+
+async fn rotate_key(
+    path: &str,
+    provider: &str,
+    key: Option<String>,
+    env_file: &Path,
+) -> Result<()> {
+    let datum_path = get_expanded_path(path)?.join(format!("{}.ai.toml", provider));
+    let content = std::fs::read_to_string(&datum_path).with_context(|| {
+        format!(
+            "AI provider '{}' not found at {}",
+            provider,
+            datum_path.display()
+        )
+    })?;
+    let config: AiConfig = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", datum_path.display()))?;
+
+    let env_var = config.api_key_env().with_context(|| {
+        format!(
+            "{} has no API key env var (set [health] api_key_env or an [env] *_API_KEY entry)",
+            datum_path.display()
+        )
+    })?;
+    let health = config.health.as_ref().with_context(|| {
+        format!(
+            "{} has no [health] url to test the key against",
+            datum_path.display()
+        )
+    })?;
+
+    let key = match key {
+        Some(key) => key,
+        None => {
+            eprintln!("🔑 Enter new {} (then Enter):", env_var);
+            let mut line = String::new();
+            std::io::stdin()
+                .read_line(&mut line)
+                .context("Failed to read key from stdin")?;
+            line
+        }
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        anyhow::bail!("No API key given");
+    }
+
+    println!("🩺 Testing {} against {}...", env_var, health.url);
+    check_api_key(health, key).await?;
+    println!("✅ Key accepted by {}", provider);
+
+    let env_file = PathBuf::from(shellexpand::tilde(&env_file.to_string_lossy()).to_string());
+    upsert_env_file(&env_file, &env_var, key)?;
+    println!("💾 Wrote {} to {}", env_var, env_file.display());
+
+    Ok(())
+}
+
+/// GET the health endpoint with `key`; any non-2xx status is an error
+async fn check_api_key(health: &AiHealthCheck, key: &str) -> Result<()> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(&health.url);
+    request = match &health.header {
+        Some(header) => request.header(header.as_str(), key),
+        None => request.bearer_auth(key),
+    };
+    for (name, value) in &health.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {}", health.url))?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("❌ Key rejected by {}: HTTP {}", health.url, status);
+    }
+    Ok(())
+}
+
+/// Set `KEY=value` in a .env file, replacing an existing `KEY=` line and keeping the rest
+fn upsert_env_file(env_file: &Path, key: &str, value: &str) -> Result<()> {
+    let existing = match std::fs::read_to_string(env_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read {}", env_file.display()));
+        }
+    };
+
+    let entry = format!("{}={}", key, value);
+    let mut replaced = false;
+    let mut lines: Vec<String> = existing
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let export = if trimmed.starts_with("export ") {
+                "export "
+            } else {
+                ""
+            };
+            let assigns_key = trimmed
+                .trim_start_matches("export ")
+                .split_once('=')
+                .is_some_and(|(name, _)| name.trim() == key);
+            if assigns_key && !replaced {
+                replaced = true;
+                format!("{}{}", export, entry)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    if !replaced {
+        lines.push(entry);
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    write_private(env_file, &content)
+        .with_context(|| format!("Failed to write {}", env_file.display()))
+}
+
+/// Replace `path` with `content` through a temp file that is never readable by other users
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = path.with_file_name(format!(".{}.tmp", file_name));
+    match std::fs::remove_file(&tmp) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // 🤓 The file holds secrets: create it 0600 instead of chmod-ing after the write
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let result = options
+        .open(&tmp)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .and_then(|_| std::fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Timings and token usage of one `ai benchmark` run
+#[derive(Debug, Clone, PartialEq)]
+struct BenchmarkResult {
+    model: String,
+    time_to_first_token: Option<Duration>,
+    total: Duration,
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+}
+
+/// Token counts and text from one server-sent event of a streaming chat completion
+#[derive(Debug, Default, PartialEq)]
+struct StreamEvent {
+    content: String,
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+}
+
+/// Public OpenAI-compatible endpoint for providers that have one
+fn default_api_base(provider: &ModelProvider) -> Option<&'static str> {
+    match provider {
+        ModelProvider::OpenAI => Some("https://api.openai.com/v1"),
+        ModelProvider::Anthropic => Some("https://api.anthropic.com/v1"),
+        ModelProvider::GoogleAI => Some("https://generativelanguage.googleapis.com/v1beta/openai"),
+        ModelProvider::Groq => Some("https://api.groq.com/openai/v1"),
+        ModelProvider::XAI => Some("https://api.x.ai/v1"),
+        ModelProvider::OpenRouter => Some("https://openrouter.ai/api/v1"),
+        ModelProvider::FireworksAI => Some("https://api.fireworks.ai/inference/v1"),
+        ModelProvider::Ollama => Some("http://localhost:11434/v1"),
+        _ => None,
+    }
+}
+
+/// Model id the provider's own API expects: the litellm model without its provider prefix
+fn provider_model_id(model: &AiModelDatum) -> &str {
+    model
+        .litellm_model
+        .strip_prefix(model.provider.litellm_prefix())
+        .filter(|id| !id.is_empty())
+        .unwrap_or(&model.litellm_model)
+}
+
+/// Parse one `data: {...}` line of an OpenAI-style completion stream
+///
+/// 🤓 Returns None for comments, keep-alives and the final `[DONE]` marker
+fn parse_stream_line(line: &str) -> Option<StreamEvent> {
+    let data = line.trim().strip_prefix("data:")?.trim();
+    if data.is_empty() || data == "[DONE]" {
+        return None;
+    }
+    let value: serde_json::Value = serde_json::from_str(data).ok()?;
+    let content = value["choices"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|choice| choice["delta"]["content"].as_str())
+        .collect();
+    let usage = &value["usage"];
+    Some(StreamEvent {
+        content,
+        prompt_tokens: usage["prompt_tokens"].as_u64(),
+        completion_tokens: usage["completion_tokens"].as_u64(),
+    })
+}
+
+async fn benchmark(path: &str, models: &[String], prompt: &str, max_tokens: u32) -> Result<()> {
+    let client = reqwest::Client::new();
+    let mut results = Vec::new();
+
+    for name in models {
+        let entry = AiModelDatumEntry::from_config(name, path)?;
+        let model = &entry.model;
+
+        let key = match &model.api_key_env {
+            Some(var) => match std::env::var(var) {
+                Ok(key) if !key.is_empty() => Some(key),
+                _ => {
+                    eprintln!("⚠️  Skipping {}: {} is not set", name, var);
+                    continue;
+                }
+            },
+            None => None,
+        };
+        let Some(api_base) = model
+            .api_base
+            .as_deref()
+            .or_else(|| default_api_base(&model.provider))
+        else {
+            eprintln!(
+                "⚠️  Skipping {}: no api_base for provider '{}' (point it at a litellm proxy)",
+                name,
+                model.provider.litellm_prefix().trim_end_matches('/')
+            );
+            continue;
+        };
+
+        println!("⏱️  {} ({})...", name, model.litellm_model);
+        match time_completion(&client, api_base, key.as_deref(), model, prompt, max_tokens).await {
+            Ok(mut result) => {
+                result.model = name.clone();
+                results.push(result);
+            }
+            Err(e) => eprintln!("❌ {}: {:#}", name, e),
+        }
+    }
+
+    if results.is_empty() {
+        anyhow::bail!("No model could be benchmarked");
+    }
+    print!("{}", benchmark_table(&results));
+    Ok(())
+}
+
+/// Stream one chat completion and time the first content token and the whole response
+async fn time_completion(
+    client: &reqwest::Client,
+    api_base: &str,
+    key: Option<&str>,
+    model: &AiModelDatum,
+    prompt: &str,
+    max_tokens: u32,
+) -> Result<BenchmarkResult> {
+    let url = format!("{}/chat/completions", api_base.trim_end_matches('/'));
+    let body = serde_json::json!({
+        "model": provider_model_id(model),
+        "messages": [{ "role": "user", "content": prompt }],
+        "max_tokens": max_tokens,
+        "stream": true,
+        "stream_options": { "include_usage": true },
+    });
+
+    let mut request = client.post(&url).json(&body);
+    if let Some(key) = key {
+        request = request.bearer_auth(key);
+    }
+
+    let start = Instant::now();
+    let mut response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {}", url))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        anyhow::bail!("HTTP {} from {}: {}", status, url, text.trim());
+    }
+
+    let mut result = BenchmarkResult {
+        model: model.litellm_model.clone(),
+        time_to_first_token: None,
+        total: Duration::ZERO,
+        prompt_tokens: None,
+        completion_tokens: None,
+    };
+    let mut content_chunks = 0;
+    let mut buffer = String::new();
+    while let Some(chunk) = response.chunk().await? {
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(newline) = buffer.find('\n') {
+            let line: String = buffer.drain(..=newline).collect();
+            let Some(event) = parse_stream_line(&line) else {
+                continue;
+            };
+            if !event.content.is_empty() {
+                content_chunks += 1;
+                result
+                    .time_to_first_token
+                    .get_or_insert_with(|| start.elapsed());
+            }
+            result.prompt_tokens = event.prompt_tokens.or(result.prompt_tokens);
+            result.completion_tokens = event.completion_tokens.or(result.completion_tokens);
+        }
+    }
+    result.total = start.elapsed();
+
+    // 🤓 Servers that ignore include_usage still send roughly one token per chunk
+    if result.completion_tokens.is_none() && content_chunks > 0 {
+        result.completion_tokens = Some(content_chunks);
+    }
+    Ok(result)
+}
+
+fn benchmark_table(results: &[BenchmarkResult]) -> String {
+    let name_width = results
+        .iter()
+        .map(|r| r.model.len())
+        .chain(["MODEL".len()])
+        .max()
+        .unwrap_or(0);
+    let fmt_secs = |d: Option<Duration>| {
+        d.map(|d| format!("{:.2}s", d.as_secs_f64()))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let fmt_count = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string());
+
+    let mut table = format!(
+        "\n📊 {:name_width$}  {:>8}  {:>8}  {:>6}  {:>6}  {:>7}\n",
+        "MODEL", "TTFT", "TOTAL", "IN", "OUT", "TOK/S"
+    );
+    for r in results {
+        let rate = r
+            .completion_tokens
+            .filter(|_| !r.total.is_zero())
+            .map(|n| format!("{:.1}", n as f64 / r.total.as_secs_f64()))
+            .unwrap_or_else(|| "-".to_string());
+        table.push_str(&format!(
+            "   {:name_width$}  {:>8}  {:>8}  {:>6}  {:>6}  {:>7}\n",
+            r.model,
+            fmt_secs(r.time_to_first_token),
+            fmt_secs(Some(r.total)),
+            fmt_count(r.prompt_tokens),
+            fmt_count(r.completion_tokens),
+            rate
+        ));
+    }
+    table
+}
+
+/// Sort `ai list` rows in place
+fn sort_models(models: &mut [ModelRecord], sort_by: AiListSort) {
+    match sort_by {
+        AiListSort::Name => models.sort_by(|a, b| a.name.cmp(&b.name)),
+        AiListSort::Cost => models.sort_by(|a, b| {
+            let price = |p: Option<f64>| p.unwrap_or(f64::INFINITY);
+            price(a.input_price_per_1m)
+                .total_cmp(&price(b.input_price_per_1m))
+                .then_with(|| price(a.output_price_per_1m).total_cmp(&price(b.output_price_per_1m)))
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+    }
+}
+
+fn list(path: &str, json: bool, sort_by: AiListSort) -> Result<()> {
+    let mut models = crate::model_manager::list_models(path, None)?;
+    sort_models(&mut models, sort_by);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&models)?);
+        return Ok(());
+    }
+    if models.is_empty() {
+        println!("ℹ️  No AI model datums found in {}", path);
+        return Ok(());
+    }
+
+    let name_width = models
+        .iter()
+        .map(|m| m.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let fmt_price = |p: Option<f64>| {
+        p.map(|p| format!("${:.2}", p))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    println!(
+        "🤖 {:name_width$}  {:12}  {:6}  {:>8}  {:>9}  {:>9}",
+        "NAME", "PROVIDER", "SIZE", "CONTEXT", "IN/1M", "OUT/1M"
+    );
+    for model in &models {
+        println!(
+            "   {:name_width$}  {:12}  {:6}  {:>8}  {:>9}  {:>9}",
+            model.name,
+            model.provider,
+            model.size,
+            model
+                .context_window
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            fmt_price(model.input_price_per_1m),
+            fmt_price(model.output_price_per_1m)
+        );
+    }
+    Ok(())
+}
+
+fn context_budget(path: &str, name: &str, tokens: u64, reserve: u64) -> Result<()> {
+    let entry = AiModelDatumEntry::from_config(name, path)?;
+    let window = entry
+        .model
+        .context_window
+        .with_context(|| format!("AI model datum '{}' has no context_window", name))?;
+
+    let available = u64::from(window).saturating_sub(reserve);
+    if entry.model.fits_context(tokens, reserve) == Some(true) {
+        println!(
+            "✅ {} tokens fit {} ({} to spare; window {}, {} reserved)",
+            tokens,
+            name,
+            available - tokens,
+            window,
+            reserve
+        );
+        Ok(())
+    } else {
+        // 🤓 Exit code is the answer; the message is for humans
+        eprintln!(
+            "❌ {} tokens exceed the {}-token budget of {} (window {}, {} reserved)",
+            tokens, available, name, window, reserve
+        );
+        std::process::exit(1);
+    }
+}
+
+/// The vector of an OpenAI-style `/embeddings` response (`data[0].embedding`)
+fn parse_embedding(response: &serde_json::Value) -> Result<Vec<f32>> {
+    response["data"][0]["embedding"]
+        .as_array()
+        .context("Response has no data[0].embedding")?
+        .iter()
+        .map(|value| {
+            value
+                .as_f64()
+                .map(|value| value as f32)
+                .context("Embedding contains a non-number")
+        })
+        .collect()
+}
+
+/// `--format binary` output: each dimension as float32 little-endian
+fn embedding_bytes(embedding: &[f32]) -> Vec<u8> {
+    embedding
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+async fn embed(path: &str, text: &str, model: Option<&str>, format: EmbedFormat) -> Result<()> {
+    use b00t_c0re_lib::datum_ai_model::ModelCapability;
+    use std::io::Write;
+
+    let entry = match model {
+        Some(name) => {
+            let entry = AiModelDatumEntry::from_config(name, path)?;
+            if !entry.model.has_capability(&ModelCapability::Embeddings) {
+                anyhow::bail!("AI model datum '{}' has no embeddings capability", name);
+            }
+            entry
         }
+        None => crate::model_manager::select_model_for_capability(
+            path,
+            &ModelCapability::Embeddings,
+            None,
+        )?
+        .context(
+            "No available model with the embeddings capability (check *.ai_model.toml and API key env vars)",
+        )?,
+    };
+    let model = &entry.model;
+
+    let key = match &model.api_key_env {
+        Some(var) => Some(
+            std::env::var(var)
+                .ok()
+                .filter(|key| !key.is_empty())
+                .with_context(|| format!("{} is not set for {}", var, entry.datum.name))?,
+        ),
+        None => None,
+    };
+    let api_base = model
+        .api_base
+        .as_deref()
+        .or_else(|| default_api_base(&model.provider))
+        .with_context(|| {
+            format!(
+                "No api_base for provider '{}' (point it at a litellm proxy)",
+                model.provider.litellm_prefix().trim_end_matches('/')
+            )
+        })?;
+
+    let url = format!("{}/embeddings", api_base.trim_end_matches('/'));
+    let body = serde_json::json!({
+        "model": provider_model_id(model),
+        "input": text,
+    });
+    let mut request = reqwest::Client::new().post(&url).json(&body);
+    if let Some(key) = key {
+        request = request.bearer_auth(key);
+    }
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach {}", url))?;
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        anyhow::bail!("HTTP {} from {}: {}", status, url, text.trim());
+    }
+    let embedding = parse_embedding(&response.json().await?)
+        .with_context(|| format!("Unexpected response from {}", url))?;
+
+    match format {
+        EmbedFormat::Json => println!("{}", serde_json::to_string(&embedding)?),
+        EmbedFormat::Binary => std::io::stdout().write_all(&embedding_bytes(&embedding))?,
+    }
+    Ok(())
+}
+
+fn select(path: &str, capability: &str, size: Option<&str>) -> Result<()> {
+    use b00t_c0re_lib::datum_ai_model::ModelSize;
+
+    let capability = crate::model_manager::parse_capability(capability)?;
+    let size = size
+        .map(|s| serde_json::from_value::<ModelSize>(serde_json::Value::String(s.to_string())))
+        .transpose()?;
+
+    match crate::model_manager::select_model_for_capability(path, &capability, size.as_ref())? {
+        Some(entry) => {
+            println!("{}", entry.model.litellm_model);
+            Ok(())
+        }
+        None => anyhow::bail!(
+            "No available model with capability '{:?}' (check *.ai_model.toml and API key env vars)",
+            capability
+        ),
     }
 }
 
@@ -56,6 +752,29 @@ impl AiCommands {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_embedding_and_binary_output() {
+        let response = serde_json::json!({
+            "object": "list",
+            "data": [{ "object": "embedding", "index": 0, "embedding": [0.5, -1.0, 2.25] }],
+            "model": "text-embedding-3-small"
+        });
+        let embedding = parse_embedding(&response).unwrap();
+        assert_eq!(embedding, vec![0.5, -1.0, 2.25]);
+        assert_eq!(
+            embedding_bytes(&embedding),
+            [
+                0.5f32.to_le_bytes(),
+                (-1.0f32).to_le_bytes(),
+                2.25f32.to_le_bytes()
+            ]
+            .concat()
+        );
+
+        assert!(parse_embedding(&serde_json::json!({ "data": [] })).is_err());
+        assert!(parse_embedding(&serde_json::json!({ "data": [{ "embedding": ["x"] }] })).is_err());
+    }
+
     #[test]
     fn test_ai_commands_exist() {
         let add_cmd = AiCommands::Add {
@@ -64,4 +783,138 @@ mod tests {
 
         assert!(add_cmd.execute("test").is_ok());
     }
+
+    #[test]
+    fn test_upsert_env_file_replaces_and_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let env_file = dir.path().join(".env");
+        std::fs::write(&env_file, "# keys\nexport OPENAI_API_KEY=old\nOTHER=1\n").unwrap();
+
+        upsert_env_file(&env_file, "OPENAI_API_KEY", "sk-new").unwrap();
+        upsert_env_file(&env_file, "ANTHROPIC_API_KEY", "sk-ant").unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&env_file).unwrap(),
+            "# keys\nexport OPENAI_API_KEY=sk-new\nOTHER=1\nANTHROPIC_API_KEY=sk-ant\n"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&env_file).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_api_key_env_from_health_or_env() {
+        let config: AiConfig = toml::from_str(
+            r#"
+[b00t]
+name = "openai"
+type = "ai"
+hint = "OpenAI"
+
+[env]
+OPENAI_API_BASE = "https://api.openai.com/v1"
+OPENAI_API_KEY = "${OPENAI_API_KEY}"
+
+[health]
+url = "https://api.openai.com/v1/models"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.api_key_env().as_deref(), Some("OPENAI_API_KEY"));
+
+        let mut config = config;
+        config.health.as_mut().unwrap().api_key_env = Some("MY_KEY".to_string());
+        assert_eq!(config.api_key_env().as_deref(), Some("MY_KEY"));
+    }
+
+    #[test]
+    fn test_parse_stream_line() {
+        let event =
+            parse_stream_line(r#"data: {"choices":[{"index":0,"delta":{"content":"Hel"}}]}"#)
+                .unwrap();
+        assert_eq!(event.content, "Hel");
+        assert_eq!(event.completion_tokens, None);
+
+        let usage = parse_stream_line(
+            r#"data: {"choices":[],"usage":{"prompt_tokens":12,"completion_tokens":34}}"#,
+        )
+        .unwrap();
+        assert_eq!(usage.content, "");
+        assert_eq!(usage.prompt_tokens, Some(12));
+        assert_eq!(usage.completion_tokens, Some(34));
+
+        assert_eq!(parse_stream_line("data: [DONE]"), None);
+        assert_eq!(parse_stream_line(": keep-alive"), None);
+    }
+
+    #[test]
+    fn test_provider_model_id_strips_litellm_prefix() {
+        let mut model: AiModelDatum = toml::from_str(
+            r#"
+provider = "openrouter"
+size = "large"
+litellm_model = "openrouter/moonshot/kimi-k2"
+"#,
+        )
+        .unwrap();
+        assert_eq!(provider_model_id(&model), "moonshot/kimi-k2");
+
+        model.provider = ModelProvider::LiteLLM;
+        assert_eq!(provider_model_id(&model), "openrouter/moonshot/kimi-k2");
+    }
+
+    #[test]
+    fn test_benchmark_table() {
+        let table = benchmark_table(&[BenchmarkResult {
+            model: "gpt-4o-mini".to_string(),
+            time_to_first_token: Some(Duration::from_millis(250)),
+            total: Duration::from_secs(2),
+            prompt_tokens: Some(10),
+            completion_tokens: Some(100),
+        }]);
+        assert!(table.contains("TTFT"));
+        assert!(table.contains("gpt-4o-mini"));
+        assert!(table.contains("0.25s"));
+        assert!(table.contains("50.0"));
+    }
+
+    #[test]
+    fn test_sort_models_by_cost_puts_unpriced_last() {
+        let record = |name: &str, input: Option<f64>, output: Option<f64>| ModelRecord {
+            name: name.to_string(),
+            hint: String::new(),
+            provider: "openrouter".to_string(),
+            size: "large".to_string(),
+            capabilities: vec![],
+            repo: None,
+            cache_dir: None,
+            container_path: None,
+            dtype: None,
+            rpm_limit: None,
+            context_window: None,
+            input_price_per_1m: input,
+            output_price_per_1m: output,
+            installed: false,
+            active: false,
+            aliases: vec![],
+        };
+        let mut models = vec![
+            record("local", None, None),
+            record("qwen", Some(0.35), Some(0.40)),
+            record("kimi", Some(0.30), Some(0.90)),
+            record("mini", Some(0.30), Some(0.60)),
+        ];
+
+        sort_models(&mut models, AiListSort::Cost);
+        let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["mini", "kimi", "qwen", "local"]);
+
+        sort_models(&mut models, AiListSort::Name);
+        assert_eq!(models[0].name, "kimi");
+    }
 }
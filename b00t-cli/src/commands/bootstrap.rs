@@ -6,19 +6,42 @@
 //! - Generate Toon format report
 
 use crate::bootstrap::report::BootstrapReport;
+use crate::bootstrap::skeleton::SkeletonResult;
 use crate::bootstrap::{
-    check_prerequisites, create_skeleton, generate_toon_report, install_missing_required,
-    print_toon_report, start_services,
+    PrereqDiff, check_prerequisites, create_skeleton, diff_prereq_results, find_bootstrap_config,
+    generate_toon_report, install_missing_required, parse_toon, parse_toon_directories_created,
+    print_toon_report, print_toon_report_plain, remove_skeleton, serialize_to_toon, start_services,
 };
 use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::Parser;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Output of `bootstrap check`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CheckOutputFormat {
+    /// Emoji report for terminals
+    Human,
+    /// `PrereqResult` as JSON (for CI)
+    Json,
+    /// Toon report (same layout as bootstrap-report.toon)
+    Toon,
+}
 
 #[derive(Debug, Parser, Clone)]
 pub enum BootstrapCommands {
     /// Run full bootstrap (check prereqs + auto-install + start services + create skeleton + report)
-    #[clap(alias = "init")]
+    ///
+    /// Exit codes:
+    ///   0  success (or --dry-run)
+    ///   1  required binaries still missing (auto-install failed or --skip-install)
+    ///   2  directory skeleton could not be created
+    ///   3  services (Qdrant, ...) failed to start
+    ///   4  bootstrap.toml not found
+    ///
+    /// The report is still written when stages 1-3 fail; the first failing stage
+    /// in that order sets the exit code. Other errors exit with 1.
+    #[clap(alias = "init", verbatim_doc_comment)]
     Run {
         /// Skip directory creation
         #[clap(long)]
@@ -39,13 +62,79 @@ pub enum BootstrapCommands {
         /// Print report to stdout instead of file
         #[clap(long)]
         print: bool,
+
+        /// Don't write the bootstrap-report.json sidecar next to the Toon report
+        #[clap(long)]
+        no_json_sidecar: bool,
+
+        /// Plain ASCII output without emoji (also enabled by NO_COLOR)
+        #[clap(long)]
+        no_color: bool,
+
+        /// Show what would be installed, started and created without changing anything
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Path to bootstrap.toml (default: search _b00t_/, the datum path, ~/.b00t/)
+        #[clap(long)]
+        config: Option<PathBuf>,
+
+        /// Check prerequisites concurrently (default, or bare `--parallel`); `--parallel false` checks them one at a time
+        #[clap(long, default_value_t = true, num_args = 0..=1, default_missing_value = "true", action = clap::ArgAction::Set)]
+        parallel: bool,
     },
 
     /// Check prerequisites only
-    Check,
+    Check {
+        /// Plain ASCII output without emoji (also enabled by NO_COLOR)
+        #[clap(long)]
+        no_color: bool,
+        /// Output format (json/toon print only the machine-readable result to stdout)
+        #[clap(long, value_enum, default_value_t = CheckOutputFormat::Human)]
+        output_format: CheckOutputFormat,
+        /// Path to bootstrap.toml (default: search _b00t_/, the datum path, ~/.b00t/)
+        #[clap(long)]
+        config: Option<PathBuf>,
+        /// Install missing required binaries after the check, then re-check
+        #[clap(long)]
+        fix: bool,
+        /// Check prerequisites concurrently (default, or bare `--parallel`); `--parallel false` checks them one at a time
+        #[clap(long, default_value_t = true, num_args = 0..=1, default_missing_value = "true", action = clap::ArgAction::Set)]
+        parallel: bool,
+    },
 
     /// Create directory skeleton only
-    Skeleton,
+    Skeleton {
+        /// Path to bootstrap.toml (default: search _b00t_/, the datum path, ~/.b00t/)
+        #[clap(long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Remove the directories a previous `run` created (listed in its Toon report)
+    Rollback {
+        /// Toon report from the run to undo (default: ~/.b00t/bootstrap-report.toon)
+        #[clap(short, long)]
+        report: Option<PathBuf>,
+
+        /// Also remove directories that are no longer empty
+        #[clap(long)]
+        force: bool,
+    },
+
+    /// Show what changed since the last recorded bootstrap (new binaries, upgrades, new directories)
+    Diff {
+        /// Toon report to compare against (default: ~/.b00t/bootstrap-report.toon)
+        #[clap(long)]
+        against: Option<PathBuf>,
+
+        /// Path to bootstrap.toml (default: search _b00t_/, the datum path, ~/.b00t/)
+        #[clap(long)]
+        config: Option<PathBuf>,
+
+        /// Check prerequisites concurrently (default, or bare `--parallel`); `--parallel false` checks them one at a time
+        #[clap(long, default_value_t = true, num_args = 0..=1, default_missing_value = "true", action = clap::ArgAction::Set)]
+        parallel: bool,
+    },
 }
 
 /// Handle bootstrap commands
@@ -57,72 +146,206 @@ pub async fn handle_bootstrap_command(cmd: BootstrapCommands) -> Result<()> {
             skip_services,
             output,
             print,
-        } => run_bootstrap(skip_dirs, skip_install, skip_services, output, print).await,
-        BootstrapCommands::Check => check_only().await,
-        BootstrapCommands::Skeleton => skeleton_only().await,
+            no_json_sidecar,
+            no_color,
+            dry_run,
+            config,
+            parallel,
+        } => {
+            let config_path = match resolve_config_path(config) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("❌ {}", e);
+                    std::process::exit(EXIT_CONFIG_NOT_FOUND);
+                }
+            };
+            let options = RunOptions {
+                skip_dirs,
+                skip_install,
+                skip_services,
+                output,
+                print_only: print,
+                json_sidecar: !no_json_sidecar,
+                no_color,
+                dry_run,
+                parallel,
+            };
+            run_bootstrap(&config_path, options).await
+        }
+        BootstrapCommands::Check {
+            no_color,
+            output_format,
+            config,
+            fix,
+            parallel,
+        } => {
+            check_only(
+                &resolve_config_path(config)?,
+                no_color,
+                output_format,
+                fix,
+                parallel,
+            )
+            .await
+        }
+        BootstrapCommands::Skeleton { config } => {
+            skeleton_only(&resolve_config_path(config)?).await
+        }
+        BootstrapCommands::Rollback { report, force } => rollback(report, force).await,
+        BootstrapCommands::Diff {
+            against,
+            config,
+            parallel,
+        } => diff(against, &resolve_config_path(config)?, parallel).await,
+    }
+}
+
+/// Explicit `--config` wins, otherwise search the default locations
+fn resolve_config_path(config: Option<PathBuf>) -> Result<PathBuf> {
+    match config {
+        Some(path) => {
+            let path = PathBuf::from(shellexpand::tilde(&path.to_string_lossy()).to_string());
+            if !path.is_file() {
+                anyhow::bail!("Bootstrap config not found: {}", path.display());
+            }
+            Ok(path)
+        }
+        None => find_bootstrap_config(),
+    }
+}
+
+/// `bootstrap run` exit codes (listed in its --help)
+const EXIT_MISSING_PREREQS: i32 = 1;
+const EXIT_SKELETON_FAILED: i32 = 2;
+const EXIT_SERVICES_FAILED: i32 = 3;
+const EXIT_CONFIG_NOT_FOUND: i32 = 4;
+
+/// Exit code for a finished `bootstrap run`, earliest failing stage first
+fn run_exit_code(prereqs_met: bool, skeleton_ok: bool, services_ok: bool) -> i32 {
+    if !prereqs_met {
+        EXIT_MISSING_PREREQS
+    } else if !skeleton_ok {
+        EXIT_SKELETON_FAILED
+    } else if !services_ok {
+        EXIT_SERVICES_FAILED
+    } else {
+        0
     }
 }
 
-async fn run_bootstrap(
+/// Flags for `bootstrap run`
+struct RunOptions {
     skip_dirs: bool,
     skip_install: bool,
     skip_services: bool,
     output: Option<PathBuf>,
     print_only: bool,
-) -> Result<()> {
+    json_sidecar: bool,
+    no_color: bool,
+    dry_run: bool,
+    parallel: bool,
+}
+
+/// `--no-color` forces plain output; otherwise print_toon_report honours NO_COLOR
+fn print_report(report: &BootstrapReport, no_color: bool) {
+    if no_color {
+        print_toon_report_plain(report);
+    } else {
+        print_toon_report(report);
+    }
+}
+
+async fn run_bootstrap(config_path: &Path, options: RunOptions) -> Result<()> {
+    let RunOptions {
+        skip_dirs,
+        skip_install,
+        skip_services,
+        output,
+        print_only,
+        json_sidecar,
+        no_color,
+        dry_run,
+        parallel,
+    } = options;
+
     println!("🥾 b00t bootstrap - Phase 0: Foundation (Self-Installing)");
+    if dry_run {
+        println!("📝 Dry run - nothing will be installed, started or created");
+    }
     println!();
 
-    // Locate bootstrap.toml
-    let config_path = PathBuf::from("_b00t_/bootstrap.toml");
-    if !config_path.exists() {
-        anyhow::bail!(
-            "Bootstrap config not found: {}\nRun from dotfiles root directory",
-            config_path.display()
-        );
-    }
+    println!("⚙️  Using config: {}", config_path.display());
 
     // Check prerequisites
     println!("📋 Checking prerequisites...");
-    let mut prereq_result =
-        check_prerequisites(&config_path).context("Failed to check prerequisites")?;
+    let mut prereq_result = check_prerequisites(config_path, parallel)
+        .await
+        .context("Failed to check prerequisites")?;
 
     // Auto-install missing binaries (unless skipped)
     if !skip_install && !prereq_result.all_required_met {
         println!();
         println!("🔧 Auto-installing missing dependencies...");
-        let installed = install_missing_required(&prereq_result)
+        let installed = install_missing_required(&prereq_result, dry_run)
             .await
             .context("Failed to auto-install dependencies")?;
 
-        if !installed.is_empty() {
+        if dry_run {
+            if !installed.is_empty() {
+                println!("📝 Would install: {}", installed.join(", "));
+            }
+        } else if !installed.is_empty() {
             println!("✅ Installed: {}", installed.join(", "));
 
             // Re-check prerequisites after installation
-            prereq_result = check_prerequisites(&config_path)?;
+            prereq_result = check_prerequisites(config_path, parallel).await?;
         }
     }
 
     // Start services (unless skipped)
+    let mut services_ok = true;
     if !skip_services {
         println!();
         println!("🚀 Starting services...");
-        let started = start_services().await.context("Failed to start services")?;
-
-        if !started.is_empty() {
-            println!("✅ Started: {}", started.join(", "));
-        } else {
-            println!("ℹ️  All services already running");
+        // 🤓 Keep going so the skeleton and report still happen; the exit code records it
+        match start_services(dry_run).await {
+            Ok(started) if dry_run => {
+                if !started.is_empty() {
+                    println!("📝 Would start: {}", started.join(", "));
+                }
+            }
+            Ok(started) if !started.is_empty() => {
+                println!("✅ Started: {}", started.join(", "));
+            }
+            Ok(_) => println!("ℹ️  All services already running"),
+            Err(e) => {
+                eprintln!("❌ Failed to start services: {:#}", e);
+                services_ok = false;
+            }
         }
     }
 
     // Create skeleton (unless skipped)
+    let mut skeleton_ok = true;
     let skeleton_result = if skip_dirs {
         println!("⏭️  Skipping directory creation");
         None
     } else {
         println!("📁 Creating directory skeleton...");
-        Some(create_skeleton(&config_path).context("Failed to create directory skeleton")?)
+        match create_skeleton(config_path, dry_run) {
+            Ok(skeleton) => {
+                if dry_run && !skeleton.created.is_empty() {
+                    println!("📝 Would create {} directories", skeleton.created.len());
+                }
+                skeleton_ok = skeleton.is_success();
+                Some(skeleton)
+            }
+            Err(e) => {
+                eprintln!("❌ Failed to create directory skeleton: {:#}", e);
+                skeleton_ok = false;
+                None
+            }
+        }
     };
 
     // Generate report
@@ -132,23 +355,52 @@ async fn run_bootstrap(
         skeleton_result,
     };
 
-    if print_only {
-        // Print to stdout
-        print_toon_report(&report);
+    if print_only || dry_run {
+        // Print to stdout (dry runs never write the report)
+        print_report(&report, no_color);
     } else {
         // Write to file
         let output_path = output.unwrap_or_else(|| PathBuf::from("~/.b00t/bootstrap-report.toon"));
 
-        generate_toon_report(&report, &output_path).context("Failed to generate Toon report")?;
+        generate_toon_report(&report, &output_path, json_sidecar)
+            .context("Failed to generate Toon report")?;
 
         println!();
-        print_toon_report(&report);
+        print_report(&report, no_color);
         println!();
         println!("📄 Report written to: {}", output_path.display());
+        if json_sidecar {
+            println!(
+                "📄 JSON sidecar written to: {}",
+                output_path.with_extension("json").display()
+            );
+        }
     }
 
-    if !report.prereq_result.all_required_met {
-        anyhow::bail!("⚠️  Bootstrap incomplete - required prerequisites missing");
+    if dry_run {
+        println!();
+        println!("📝 Dry run complete - no changes made");
+        return Ok(());
+    }
+
+    match run_exit_code(
+        report.prereq_result.all_required_met,
+        skeleton_ok,
+        services_ok,
+    ) {
+        0 => {}
+        EXIT_MISSING_PREREQS => {
+            eprintln!("⚠️  Bootstrap incomplete - required prerequisites missing");
+            std::process::exit(EXIT_MISSING_PREREQS);
+        }
+        EXIT_SKELETON_FAILED => {
+            eprintln!("⚠️  Bootstrap incomplete - directory skeleton has errors");
+            std::process::exit(EXIT_SKELETON_FAILED);
+        }
+        code => {
+            eprintln!("⚠️  Bootstrap incomplete - services failed to start");
+            std::process::exit(code);
+        }
     }
 
     println!();
@@ -157,14 +409,17 @@ async fn run_bootstrap(
     Ok(())
 }
 
-async fn check_only() -> Result<()> {
-    let config_path = PathBuf::from("_b00t_/bootstrap.toml");
-    if !config_path.exists() {
-        anyhow::bail!("Bootstrap config not found: {}", config_path.display());
+async fn check_only(
+    config_path: &Path,
+    no_color: bool,
+    output_format: CheckOutputFormat,
+    fix: bool,
+    parallel: bool,
+) -> Result<()> {
+    if output_format == CheckOutputFormat::Human {
+        println!("📋 Checking prerequisites...");
     }
-
-    println!("📋 Checking prerequisites...");
-    let prereq_result = check_prerequisites(&config_path)?;
+    let prereq_result = check_prerequisites(config_path, parallel).await?;
 
     let report = BootstrapReport {
         timestamp: Utc::now().to_rfc3339(),
@@ -172,7 +427,31 @@ async fn check_only() -> Result<()> {
         skeleton_result: None,
     };
 
-    print_toon_report(&report);
+    match render_check_output(&report, output_format)? {
+        Some(output) => println!("{}", output),
+        None => print_report(&report, no_color),
+    }
+
+    if fix && !report.prereq_result.all_required_met {
+        eprintln!("🔧 Installing missing required binaries...");
+        let installed = install_missing_required(&report.prereq_result, false)
+            .await
+            .context("Failed to auto-install dependencies")?;
+        if installed.is_empty() {
+            eprintln!("⚠️  Nothing could be installed");
+        } else {
+            eprintln!("✅ Installed: {}", installed.join(", "));
+            if check_prerequisites(config_path, parallel)
+                .await?
+                .all_required_met
+            {
+                eprintln!("✅ All required prerequisites are now met");
+                return Ok(());
+            }
+        }
+        eprintln!("❌ Some required prerequisites are still missing");
+        std::process::exit(1);
+    }
 
     if !report.prereq_result.all_required_met {
         std::process::exit(1);
@@ -181,14 +460,23 @@ async fn check_only() -> Result<()> {
     Ok(())
 }
 
-async fn skeleton_only() -> Result<()> {
-    let config_path = PathBuf::from("_b00t_/bootstrap.toml");
-    if !config_path.exists() {
-        anyhow::bail!("Bootstrap config not found: {}", config_path.display());
+/// Machine-readable `bootstrap check` output (None for the human report)
+fn render_check_output(
+    report: &BootstrapReport,
+    output_format: CheckOutputFormat,
+) -> Result<Option<String>> {
+    match output_format {
+        CheckOutputFormat::Human => Ok(None),
+        CheckOutputFormat::Json => serde_json::to_string_pretty(&report.prereq_result)
+            .map(Some)
+            .context("Failed to serialize prerequisite result"),
+        CheckOutputFormat::Toon => serialize_to_toon(report).map(Some),
     }
+}
 
+async fn skeleton_only(config_path: &Path) -> Result<()> {
     println!("📁 Creating directory skeleton...");
-    let skeleton_result = create_skeleton(&config_path)?;
+    let skeleton_result = create_skeleton(config_path, false)?;
 
     if skeleton_result.is_success() {
         println!("✅ Created {} directories", skeleton_result.created.len());
@@ -210,3 +498,270 @@ async fn skeleton_only() -> Result<()> {
 
     Ok(())
 }
+
+async fn rollback(report: Option<PathBuf>, force: bool) -> Result<()> {
+    let report_path = report.unwrap_or_else(|| PathBuf::from("~/.b00t/bootstrap-report.toon"));
+    let report_path = PathBuf::from(shellexpand::tilde(&report_path.to_string_lossy()).to_string());
+
+    let content = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("Failed to read report {}", report_path.display()))?;
+    let directories = parse_toon_directories_created(&content)
+        .with_context(|| format!("Failed to parse report {}", report_path.display()))?;
+
+    if directories.is_empty() {
+        println!(
+            "ℹ️  No created directories recorded in {}",
+            report_path.display()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "↩️  Rolling back {} directories from {}",
+        directories.len(),
+        report_path.display()
+    );
+    let result = remove_skeleton(&directories, force);
+
+    for dir in &result.removed {
+        println!("  🗑️  Removed: {}", dir.display());
+    }
+    for dir in &result.missing {
+        println!("  ℹ️  Already gone: {}", dir.display());
+    }
+    for dir in &result.skipped_non_empty {
+        println!("  ⚠️  Not empty, kept: {}", dir.display());
+    }
+    for (path, error) in &result.errors {
+        eprintln!("  ❌ {}: {}", path.display(), error);
+    }
+
+    if !result.skipped_non_empty.is_empty() {
+        println!();
+        println!("💡 Re-run with --force to remove non-empty directories");
+    }
+
+    if !result.errors.is_empty() {
+        anyhow::bail!("{} directories could not be removed", result.errors.len());
+    }
+
+    Ok(())
+}
+
+/// Skeleton directories in `new` that the earlier run never saw
+fn new_directories(old: Option<&SkeletonResult>, new: &SkeletonResult) -> Vec<PathBuf> {
+    let seen: std::collections::HashSet<&PathBuf> = old
+        .map(|skeleton| {
+            skeleton
+                .created
+                .iter()
+                .chain(&skeleton.already_existed)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    new.created
+        .iter()
+        .chain(&new.already_existed)
+        .filter(|dir| !seen.contains(dir))
+        .cloned()
+        .collect()
+}
+
+fn print_prereq_diff(diff: &PrereqDiff) {
+    for name in &diff.newly_installed {
+        println!("  ✨ Installed: {}", name);
+    }
+    for change in &diff.version_changes {
+        println!(
+            "  ⬆️  {}: {} → {}",
+            change.name, change.old_version, change.new_version
+        );
+    }
+    for name in &diff.no_longer_found {
+        println!("  ❌ No longer found: {}", name);
+    }
+}
+
+async fn diff(against: Option<PathBuf>, config_path: &Path, parallel: bool) -> Result<()> {
+    let report_path = against.unwrap_or_else(|| PathBuf::from("~/.b00t/bootstrap-report.toon"));
+    let report_path = PathBuf::from(shellexpand::tilde(&report_path.to_string_lossy()).to_string());
+
+    let content = std::fs::read_to_string(&report_path)
+        .with_context(|| format!("Failed to read report {}", report_path.display()))?;
+    let previous = parse_toon(&content)
+        .with_context(|| format!("Failed to parse report {}", report_path.display()))?;
+
+    println!(
+        "🔍 Comparing {} ({}) with a fresh check",
+        report_path.display(),
+        previous.timestamp
+    );
+
+    let prereq_result = check_prerequisites(config_path, parallel).await?;
+    let prereq_diff = diff_prereq_results(&previous.prereq_result, &prereq_result);
+    // 🤓 Dry run: lists would-be-created directories without touching the filesystem
+    let skeleton = create_skeleton(config_path, true)?;
+    let directories = new_directories(previous.skeleton_result.as_ref(), &skeleton);
+
+    if prereq_diff.is_empty() && directories.is_empty() {
+        println!("✅ Nothing changed since the last bootstrap");
+        return Ok(());
+    }
+
+    if !prereq_diff.is_empty() {
+        println!();
+        println!("📦 Binaries:");
+        print_prereq_diff(&prereq_diff);
+    }
+
+    if !directories.is_empty() {
+        println!();
+        println!("📁 New directories:");
+        for dir in &directories {
+            let state = if skeleton.created.contains(dir) {
+                "will be created"
+            } else {
+                "exists"
+            };
+            println!("  ✨ {} ({})", dir.display(), state);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::prereq::{BinaryCheck, PrereqResult};
+
+    #[test]
+    fn test_run_and_check_accept_config() {
+        #[derive(Parser)]
+        struct Cli {
+            #[clap(subcommand)]
+            command: BootstrapCommands,
+        }
+
+        let run = Cli::try_parse_from(["b00t", "run", "--config", "/tmp/alt/bootstrap.toml"])
+            .unwrap()
+            .command;
+        assert!(matches!(
+            run,
+            BootstrapCommands::Run { config: Some(ref path), .. }
+                if path == Path::new("/tmp/alt/bootstrap.toml")
+        ));
+        let check = Cli::try_parse_from(["b00t", "check", "--config", "alt.toml"])
+            .unwrap()
+            .command;
+        assert!(matches!(
+            check,
+            BootstrapCommands::Check { config: Some(ref path), .. } if path == Path::new("alt.toml")
+        ));
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("bootstrap.toml");
+        std::fs::write(&config, "").unwrap();
+        assert_eq!(resolve_config_path(Some(config.clone())).unwrap(), config);
+        assert!(resolve_config_path(Some(dir.path().join("missing.toml"))).is_err());
+    }
+
+    #[test]
+    fn test_parallel_checks_default_on_for_every_subcommand() {
+        #[derive(Parser)]
+        struct Cli {
+            #[clap(subcommand)]
+            command: BootstrapCommands,
+        }
+        let parallel = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            BootstrapCommands::Run { parallel, .. }
+            | BootstrapCommands::Check { parallel, .. }
+            | BootstrapCommands::Diff { parallel, .. } => parallel,
+            other => panic!("unexpected {:?}", other),
+        };
+
+        assert!(parallel(&["b00t", "run"]));
+        assert!(parallel(&["b00t", "check"]));
+        assert!(parallel(&["b00t", "diff"]));
+        assert!(parallel(&["b00t", "run", "--parallel"]));
+        assert!(!parallel(&["b00t", "run", "--parallel", "false"]));
+        assert!(!parallel(&["b00t", "check", "--parallel=false"]));
+    }
+
+    #[test]
+    fn test_run_exit_code_prefers_earliest_stage() {
+        assert_eq!(run_exit_code(true, true, true), 0);
+        assert_eq!(run_exit_code(false, false, false), EXIT_MISSING_PREREQS);
+        assert_eq!(run_exit_code(true, false, false), EXIT_SKELETON_FAILED);
+        assert_eq!(run_exit_code(true, true, false), EXIT_SERVICES_FAILED);
+    }
+
+    fn report() -> BootstrapReport {
+        BootstrapReport {
+            timestamp: "2025-11-09T12:00:00Z".to_string(),
+            prereq_result: PrereqResult {
+                required: vec![BinaryCheck {
+                    name: "git".to_string(),
+                    found: true,
+                    installed_version: Some("2.43.0".to_string()),
+                    required_version: ">=2.0".to_string(),
+                    meets_requirement: true,
+                    path: Some(PathBuf::from("/usr/bin/git")),
+                    install_hint: None,
+                    detail: None,
+                }],
+                optional: vec![],
+                all_required_met: true,
+            },
+            skeleton_result: None,
+        }
+    }
+
+    #[test]
+    fn test_new_directories() {
+        let old = SkeletonResult {
+            created: vec![PathBuf::from("/b00t/a")],
+            already_existed: vec![PathBuf::from("/b00t")],
+            errors: vec![],
+        };
+        let new = SkeletonResult {
+            created: vec![PathBuf::from("/b00t/c")],
+            already_existed: vec![
+                PathBuf::from("/b00t"),
+                PathBuf::from("/b00t/a"),
+                PathBuf::from("/b00t/b"),
+            ],
+            errors: vec![],
+        };
+
+        assert_eq!(
+            new_directories(Some(&old), &new),
+            vec![PathBuf::from("/b00t/c"), PathBuf::from("/b00t/b")]
+        );
+        assert_eq!(new_directories(None, &new).len(), 4);
+    }
+
+    #[test]
+    fn test_render_check_output_formats() {
+        let report = report();
+
+        assert_eq!(
+            render_check_output(&report, CheckOutputFormat::Human).unwrap(),
+            None
+        );
+
+        let json = render_check_output(&report, CheckOutputFormat::Json)
+            .unwrap()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["all_required_met"], true);
+        assert_eq!(value["required"][0]["name"], "git");
+        assert_eq!(value["required"][0]["installed_version"], "2.43.0");
+
+        let toon = render_check_output(&report, CheckOutputFormat::Toon)
+            .unwrap()
+            .unwrap();
+        assert!(toon.contains("[[required_bins]]"));
+    }
+}
@@ -217,8 +217,8 @@ fn load_all_datums(path: &str) -> Result<HashMap<String, BootDatum>> {
                             let datum_type = datum
                                 .datum_type
                                 .as_ref()
-                                .map(|t| format!("{:?}", t).to_lowercase())
-                                .unwrap_or_else(|| "unknown".to_string());
+                                .map(|t| t.type_slug())
+                                .unwrap_or("unknown");
                             let key = format!("{}.{}", datum.name, datum_type);
                             datums.insert(key, datum);
                         }
@@ -0,0 +1,207 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Datum directory used when neither `--path`/`_B00T_Path` nor `datum_path` is set
+pub const DEFAULT_DATUM_PATH: &str = "~/.dotfiles/_b00t_";
+
+/// Keys accepted by `b00t config get/set`
+pub const CONFIG_KEYS: [&str; 3] = ["datum_path", "default_container_runtime", "log_level"];
+
+const CONTAINER_RUNTIMES: [&str; 2] = ["docker", "podman"];
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+#[derive(Parser, Debug)]
+pub enum ConfigCommands {
+    #[clap(
+        about = "Print a b00t setting from ~/.b00t/config.toml",
+        long_about = "Print a b00t setting from ~/.b00t/config.toml.\n\nKeys: datum_path, default_container_runtime, log_level\n\nExamples:\n  b00t-cli config get datum_path"
+    )]
+    Get {
+        #[clap(help = "Setting name")]
+        key: String,
+    },
+    #[clap(
+        about = "Change a b00t setting in ~/.b00t/config.toml",
+        long_about = "Change a b00t setting in ~/.b00t/config.toml.\n\nKeys:\n  datum_path                 default datum directory (instead of ~/.dotfiles/_b00t_)\n  default_container_runtime  docker or podman\n  log_level                  error, warn, info, debug or trace\n\nExamples:\n  b00t-cli config set datum_path ~/work/dotfiles/_b00t_\n  b00t-cli config set default_container_runtime podman"
+    )]
+    Set {
+        #[clap(help = "Setting name")]
+        key: String,
+        #[clap(help = "New value")]
+        value: String,
+    },
+}
+
+/// b00t's own settings (`~/.b00t/config.toml`)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CliConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datum_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_container_runtime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+}
+
+impl CliConfig {
+    /// Location of the config file
+    pub fn path() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Could not determine home directory")?;
+        Ok(home.join(".b00t").join("config.toml"))
+    }
+
+    /// Load the user's config; a missing file means all defaults
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::path()?)
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<&str>> {
+        let value = match key {
+            "datum_path" => &self.datum_path,
+            "default_container_runtime" => &self.default_container_runtime,
+            "log_level" => &self.log_level,
+            _ => anyhow::bail!(unknown_key(key)),
+        };
+        Ok(value.as_deref())
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        let allowed: &[&str] = match key {
+            "default_container_runtime" => &CONTAINER_RUNTIMES,
+            "log_level" => &LOG_LEVELS,
+            _ => &[],
+        };
+        if !allowed.is_empty() && !allowed.contains(&value) {
+            anyhow::bail!(
+                "Invalid {} '{}' (expected one of: {})",
+                key,
+                value,
+                allowed.join(", ")
+            );
+        }
+
+        let slot = match key {
+            "datum_path" => &mut self.datum_path,
+            "default_container_runtime" => &mut self.default_container_runtime,
+            "log_level" => &mut self.log_level,
+            _ => anyhow::bail!(unknown_key(key)),
+        };
+        *slot = Some(value.to_string());
+        Ok(())
+    }
+}
+
+fn unknown_key(key: &str) -> String {
+    format!(
+        "Unknown config key '{}' (expected one of: {})",
+        key,
+        CONFIG_KEYS.join(", ")
+    )
+}
+
+/// Datum directory to use when `--path`/`_B00T_Path` are not given:
+/// `datum_path` from `~/.b00t/config.toml`, else `~/.dotfiles/_b00t_`
+pub fn default_datum_path() -> String {
+    match CliConfig::path() {
+        Ok(path) => datum_path_from_config(&path),
+        Err(_) => DEFAULT_DATUM_PATH.to_string(),
+    }
+}
+
+/// `datum_path` from the config file at `path`, else `~/.dotfiles/_b00t_`
+pub fn datum_path_from_config(path: &Path) -> String {
+    match CliConfig::load_from(path) {
+        Ok(config) => config.datum_path,
+        Err(e) => {
+            tracing::warn!(error = format!("{:#}", e), "Ignoring b00t config");
+            None
+        }
+    }
+    .unwrap_or_else(|| DEFAULT_DATUM_PATH.to_string())
+}
+
+impl ConfigCommands {
+    pub fn execute(&self) -> Result<()> {
+        let path = CliConfig::path()?;
+        let mut config = CliConfig::load_from(&path)?;
+
+        match self {
+            ConfigCommands::Get { key } => {
+                match config.get(key)? {
+                    Some(value) => println!("{}", value),
+                    None if key == "datum_path" => {
+                        println!("{}", DEFAULT_DATUM_PATH);
+                        eprintln!("ℹ️  datum_path not set, showing the default");
+                    }
+                    None => eprintln!("ℹ️  {} not set", key),
+                }
+                Ok(())
+            }
+            ConfigCommands::Set { key, value } => {
+                config.set(key, value)?;
+                config.save_to(&path)?;
+                println!("✅ {} = {} ({})", key, value, path.display());
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_set_get_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".b00t").join("config.toml");
+
+        let mut config = CliConfig::load_from(&path).unwrap();
+        assert_eq!(config, CliConfig::default());
+
+        config.set("datum_path", "~/work/_b00t_").unwrap();
+        config.set("default_container_runtime", "podman").unwrap();
+        config.save_to(&path).unwrap();
+
+        let loaded = CliConfig::load_from(&path).unwrap();
+        assert_eq!(loaded.get("datum_path").unwrap(), Some("~/work/_b00t_"));
+        assert_eq!(
+            loaded.get("default_container_runtime").unwrap(),
+            Some("podman")
+        );
+        assert_eq!(loaded.get("log_level").unwrap(), None);
+    }
+
+    #[test]
+    fn test_config_rejects_bad_keys_and_values() {
+        let mut config = CliConfig::default();
+        assert!(config.get("colour").is_err());
+        assert!(config.set("colour", "red").is_err());
+
+        let err = config.set("log_level", "loud").unwrap_err().to_string();
+        assert!(err.contains("error, warn, info, debug, trace"));
+        assert_eq!(config.log_level, None);
+
+        config.set("log_level", "debug").unwrap();
+        assert_eq!(config.get("log_level").unwrap(), Some("debug"));
+    }
+}
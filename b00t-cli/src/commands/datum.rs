@@ -1,6 +1,21 @@
-use crate::datum_utils;
-use anyhow::Result;
+use crate::datum_ai_model::{AiModelConfig, StrictAiModelConfig};
+use crate::{
+    AiConfig, BootDatum, DatumType, StrictAiConfig, StrictBootDatum, StrictUnifiedConfig,
+    UnifiedConfig, datum_utils, get_expanded_path,
+};
+use anyhow::{Context, Result};
 use clap::Parser;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Output of `datum export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Toml,
+    Json,
+    Yaml,
+}
 
 #[derive(Parser, Debug)]
 pub enum DatumCommands {
@@ -9,14 +24,1137 @@ pub enum DatumCommands {
         #[clap(help = "Datum name to show (e.g., just, rust, docker)")]
         name: String,
     },
+    #[clap(
+        about = "Validate datum TOML files against their schema",
+        long_about = "Validate every <name>.<type>.toml datum in a directory (or a single file).\n\nEach file is parsed with the schema for its type suffix (.mcp.toml, .docker.toml, .ai.toml, ...), and references to other datums (stack members, depends_on) must resolve. Unknown and deprecated keys are reported as warnings.\n\nExits with code 1 if any file fails.\n\nExamples:\n  b00t datum validate\n  b00t datum validate ~/.dotfiles/_b00t_\n  b00t datum validate _b00t_/qdrant.docker.toml"
+    )]
+    Validate {
+        #[clap(help = "Directory or file to validate (default: --path)")]
+        path: Option<String>,
+    },
+    #[clap(
+        about = "Report unknown, deprecated and missing recommended datum keys",
+        long_about = "Lint every <name>.<type>.toml datum in a directory (or a single file).\n\nUnknown keys (not in the schema for the type suffix) and deprecated keys are reported as warnings; recommended keys the datum does not set (e.g. version for a cli datum) are reported as hints.\n\nExits 0 unless a file fails to parse, or --strict is given and there are warnings.\n\nExamples:\n  b00t datum lint\n  b00t datum lint ~/.dotfiles/_b00t_\n  b00t datum lint --strict _b00t_/qdrant.docker.toml"
+    )]
+    Lint {
+        #[clap(help = "Directory or file to lint (default: --path)")]
+        path: Option<String>,
+        #[clap(long, help = "Exit with code 1 when there are warnings")]
+        strict: bool,
+    },
+    #[clap(
+        about = "List datums grouped by type",
+        long_about = "List every <name>.<type>.toml datum in a directory, grouped by type.\n\nExamples:\n  b00t datum list\n  b00t datum list --type mcp\n  b00t datum list --type docker,cli --json ~/.dotfiles/_b00t_"
+    )]
+    List {
+        #[clap(
+            long = "type",
+            value_delimiter = ',',
+            help = "Only these types (comma-separated, case-insensitive)"
+        )]
+        types: Vec<String>,
+        #[clap(long, help = "Output a JSON array of {name, type, file}")]
+        json: bool,
+        #[clap(help = "Datum directory (default: --path)")]
+        path: Option<String>,
+    },
+    #[clap(
+        about = "Show which datum fields changed between two git revisions",
+        long_about = "Parse a datum file at two git revisions of the datum directory and list the fields that changed (image, capabilities, context_window, ...) instead of a raw text diff.\n\nWith no refs, compares HEAD with the working tree; with one ref, compares that ref with the working tree.\n\nExamples:\n  b00t datum diff qdrant\n  b00t datum diff claude-3-5-sonnet HEAD~3\n  b00t datum diff qdrant.docker v0.7.0 HEAD"
+    )]
+    Diff {
+        #[clap(help = "Datum name, optionally with type (e.g., qdrant or qdrant.docker)")]
+        name: String,
+        #[clap(help = "Old revision (default: HEAD)")]
+        ref1: Option<String>,
+        #[clap(help = "New revision (default: working tree)")]
+        ref2: Option<String>,
+    },
+    #[clap(
+        about = "Search datum files for text (case-insensitive)",
+        long_about = "Scan every <name>.<type>.toml datum for a string (case-insensitive) and print file:line:content for each matching line, like grep.\n\nWith --field, only lines assigning that key (e.g. image = ...) are searched.\n\nExamples:\n  b00t datum search qdrant\n  b00t datum search 6333 --field docker_args\n  b00t datum search anthropic --field provider ~/.dotfiles/_b00t_"
+    )]
+    Search {
+        #[clap(help = "Text to look for")]
+        query: String,
+        #[clap(long, help = "Only search lines that set this TOML key")]
+        field: Option<String>,
+        #[clap(help = "Datum directory (default: --path)")]
+        path: Option<String>,
+    },
+    #[clap(
+        about = "Print a datum re-serialized as TOML, JSON or YAML",
+        long_about = "Parse a datum with the schema for its type and print it in another format, for tools that only accept JSON or YAML.\n\nUnset optional fields are omitted. TOML (the default) round-trips the datum in normalized form.\n\nExamples:\n  b00t datum export qdrant --format json\n  b00t datum export github --type mcp --format yaml\n  b00t datum export claude-3-5-sonnet.ai_model"
+    )]
+    Export {
+        #[clap(help = "Datum name, optionally with type (e.g., qdrant or qdrant.docker)")]
+        name: String,
+        #[clap(
+            long = "type",
+            help = "Datum type when the name is ambiguous (docker, mcp, cli, ...)"
+        )]
+        datum_type: Option<String>,
+        #[clap(long, value_enum, default_value_t = ExportFormat::Toml, help = "Output format")]
+        format: ExportFormat,
+    },
+    #[clap(
+        about = "Clone a datum under a new name",
+        long_about = "Copy <source> to <new-name>.<same-type>.toml in the datum directory, renaming [b00t] name and applying --set overrides.\n\nA --set key without a table (image=...) targets [b00t]; use a dotted key (ai_model.context_window=...) for other tables. Values are parsed as TOML (numbers, booleans, arrays) and fall back to a plain string.\n\nThe result must validate before it is written, and an existing file is never overwritten.\n\nExamples:\n  b00t datum copy claude-3-5-sonnet claude-3-7-sonnet --set ai_model.litellm_model=anthropic/claude-3-7-sonnet\n  b00t datum copy qdrant qdrant-test --set image=qdrant/qdrant:v1.12.0\n  b00t datum copy github.mcp github-enterprise --set 'args=[\"--enterprise\"]'"
+    )]
+    Copy {
+        #[clap(help = "Source datum name, optionally with type (e.g., qdrant or qdrant.docker)")]
+        source: String,
+        #[clap(help = "Name of the new datum")]
+        new_name: String,
+        #[clap(
+            long = "set",
+            value_name = "KEY=VALUE",
+            help = "Override a field in the copy (repeatable)"
+        )]
+        sets: Vec<String>,
+    },
+    #[clap(
+        about = "Watch a datum directory and validate files as they change",
+        long_about = "Watch a datum directory for .toml files being added, modified or removed and print each change with the validation result for that file.\n\nWith --auto-status, `b00t orchestrator status` is shown after every batch of changes. Runs until interrupted (Ctrl-C).\n\nExamples:\n  b00t datum watch\n  b00t datum watch ~/.dotfiles/_b00t_ --auto-status"
+    )]
+    Watch {
+        #[clap(help = "Datum directory (default: --path)")]
+        path: Option<String>,
+        #[clap(long, help = "Show orchestrator status after each change")]
+        auto_status: bool,
+    },
 }
 
 pub fn handle_datum_command(path: &str, datum_command: &DatumCommands) -> Result<()> {
     match datum_command {
         DatumCommands::Show { name } => handle_show(path, name),
+        DatumCommands::Validate { path: target } => {
+            handle_validate(target.as_deref().unwrap_or(path))
+        }
+        DatumCommands::Lint {
+            path: target,
+            strict,
+        } => handle_lint(target.as_deref().unwrap_or(path), *strict),
+        DatumCommands::List {
+            types,
+            json,
+            path: target,
+        } => handle_list(target.as_deref().unwrap_or(path), types, *json),
+        DatumCommands::Diff { name, ref1, ref2 } => {
+            handle_diff(path, name, ref1.as_deref(), ref2.as_deref())
+        }
+        DatumCommands::Search {
+            query,
+            field,
+            path: target,
+        } => handle_search(target.as_deref().unwrap_or(path), query, field.as_deref()),
+        DatumCommands::Export {
+            name,
+            datum_type,
+            format,
+        } => handle_export(path, name, datum_type.as_deref(), *format),
+        DatumCommands::Copy {
+            source,
+            new_name,
+            sets,
+        } => handle_copy(path, source, new_name, sets),
+        DatumCommands::Watch {
+            path: target,
+            auto_status,
+        } => handle_watch(target.as_deref().unwrap_or(path), *auto_status),
+    }
+}
+
+/// One field that differs between two versions of a datum
+#[derive(Debug, PartialEq)]
+pub enum FieldChange {
+    Added(String, serde_json::Value),
+    Removed(String, serde_json::Value),
+    Changed(String, serde_json::Value, serde_json::Value),
+}
+
+/// Field-level changes between two parsed datums, keyed by dotted path (`b00t.image`)
+///
+/// Tables are walked recursively; arrays and scalars are compared as a whole.
+pub fn diff_datum_values(old: &serde_json::Value, new: &serde_json::Value) -> Vec<FieldChange> {
+    let mut old_fields = std::collections::BTreeMap::new();
+    let mut new_fields = std::collections::BTreeMap::new();
+    flatten_fields("", old, &mut old_fields);
+    flatten_fields("", new, &mut new_fields);
+
+    let mut keys: Vec<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter_map(|key| match (old_fields.get(key), new_fields.get(key)) {
+            (Some(old), Some(new)) if old != new => Some(FieldChange::Changed(
+                key.clone(),
+                (*old).clone(),
+                (*new).clone(),
+            )),
+            (Some(old), None) => Some(FieldChange::Removed(key.clone(), (*old).clone())),
+            (None, Some(new)) => Some(FieldChange::Added(key.clone(), (*new).clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn flatten_fields<'a>(
+    prefix: &str,
+    value: &'a serde_json::Value,
+    fields: &mut std::collections::BTreeMap<String, &'a serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_fields(&path, value, fields);
+            }
+        }
+        // 🤓 Unset optional fields serialize as null; treat them as absent
+        serde_json::Value::Null => {}
+        _ => {
+            fields.insert(prefix.to_string(), value);
+        }
+    }
+}
+
+/// Parse a datum with the schema for its type, normalized to JSON for comparison
+fn parse_datum_value(path: &Path, content: &str) -> Result<serde_json::Value> {
+    let value = match datum_type_for(path) {
+        Some(DatumType::Ai) => serde_json::to_value(toml::from_str::<AiConfig>(content)?)?,
+        Some(DatumType::AiModel) => {
+            serde_json::to_value(toml::from_str::<AiModelConfig>(content)?)?
+        }
+        _ => serde_json::to_value(toml::from_str::<UnifiedConfig>(content)?)?,
+    };
+    Ok(value)
+}
+
+/// One line matched by `datum search`
+#[derive(Debug, PartialEq)]
+pub struct SearchMatch {
+    pub file: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    pub content: String,
+}
+
+/// Key assigned by a TOML line (`key = ...`, `"key" = ...`, `b00t.key = ...` → `key`)
+fn assigned_key(line: &str) -> Option<&str> {
+    let (key, _) = line.split_once('=')?;
+    let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+    if key.is_empty() || key.starts_with('#') || key.contains(char::is_whitespace) {
+        return None;
+    }
+    key.rsplit('.').next()
+}
+
+/// Delimiter that keeps a value open past its first line (`[` arrays, `'''`/`"""` strings)
+fn open_value(value: &str) -> Option<&'static str> {
+    let value = value.trim();
+    for quote in ["'''", "\"\"\""] {
+        if value.starts_with(quote) {
+            return (value.matches(quote).count() == 1).then_some(quote);
+        }
+    }
+    (value.starts_with('[') && value.matches('[').count() > value.matches(']').count())
+        .then_some("]")
+}
+
+/// Case-insensitive line search over the datum files in `dir`
+///
+/// With `field`, only lines of that key's value are searched, including the
+/// continuation lines of multi-line arrays and strings.
+///
+/// 🤓 Lines are scanned as text without parsing TOML, so a broken datum is
+/// still searchable and large directories stay fast
+pub fn search_datums(dir: &Path, query: &str, field: Option<&str>) -> Result<Vec<SearchMatch>> {
+    let needle = query.to_lowercase();
+    let mut matches = Vec::new();
+    for file in datum_files(dir)? {
+        let bytes =
+            std::fs::read(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+        let content = String::from_utf8_lossy(&bytes);
+        // (key, closing delimiter) of a value spanning several lines
+        let mut open: Option<(&str, &str)> = None;
+        for (index, line) in content.lines().enumerate() {
+            let key = match open {
+                Some((key, close)) => {
+                    if line.contains(close) {
+                        open = None;
+                    }
+                    Some(key)
+                }
+                None => {
+                    let key = assigned_key(line);
+                    if let (Some(key), Some((_, value))) = (key, line.split_once('=')) {
+                        open = open_value(value).map(|close| (key, close));
+                    }
+                    key
+                }
+            };
+            if field.is_some_and(|field| key != Some(field)) {
+                continue;
+            }
+            if line.to_lowercase().contains(&needle) {
+                matches.push(SearchMatch {
+                    file: file.clone(),
+                    line: index + 1,
+                    content: line.to_string(),
+                });
+            }
+        }
+    }
+    Ok(matches)
+}
+
+fn handle_search(target: &str, query: &str, field: Option<&str>) -> Result<()> {
+    let dir = get_expanded_path(target)?;
+    let matches = search_datums(&dir, query, field)?;
+    if matches.is_empty() {
+        eprintln!("🔍 No datum lines match '{}'", query);
+        std::process::exit(1);
+    }
+    for m in &matches {
+        println!("{}:{}:{}", m.file.display(), m.line, m.content);
+    }
+    Ok(())
+}
+
+/// Re-serialize a datum file's content in `format`, dropping unset (null) fields
+pub fn export_datum(path: &Path, content: &str, format: ExportFormat) -> Result<String> {
+    let mut value = parse_datum_value(path, content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    strip_nulls(&mut value);
+
+    let output = match format {
+        ExportFormat::Toml => toml::to_string_pretty(&value)?,
+        ExportFormat::Json => serde_json::to_string_pretty(&value)? + "\n",
+        ExportFormat::Yaml => serde_yaml::to_string(&value)?,
+    };
+    Ok(output)
+}
+
+fn strip_nulls(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+fn handle_export(
+    path: &str,
+    name: &str,
+    datum_type: Option<&str>,
+    format: ExportFormat,
+) -> Result<()> {
+    let dir = get_expanded_path(path)?;
+    let name = match datum_type {
+        Some(datum_type) => {
+            datum_type_slug(datum_type)
+                .with_context(|| format!("Unknown datum type '{}'", datum_type))?;
+            format!("{}.{}", name, datum_type)
+        }
+        None => name.to_string(),
+    };
+    let file = find_datum_file(&dir, &name)?;
+    let content = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    print!("{}", export_datum(&file, &content, format)?);
+    Ok(())
+}
+
+/// Content of a datum renamed to `new_name` with `key=value` overrides applied
+///
+/// Bare keys go to `[b00t]`, dotted keys (`ai_model.context_window`) name their table.
+/// A value keeps the type of the field it replaces; new fields are typed TOML unless
+/// the schema for `dest` only accepts them as a string.
+pub fn copy_datum(dest: &Path, content: &str, new_name: &str, sets: &[String]) -> Result<String> {
+    let mut table: toml::Table = toml::from_str(content)?;
+    set_datum_field(
+        &mut table,
+        "b00t.name",
+        toml::Value::String(new_name.to_string()),
+    )?;
+    for set in sets {
+        let (key, raw) = set
+            .split_once('=')
+            .with_context(|| format!("Invalid --set '{}', expected key=value", set))?;
+        let key = key.trim();
+        let key = if key.contains('.') {
+            key.to_string()
+        } else {
+            format!("b00t.{}", key)
+        };
+        let raw = raw.trim();
+        let value = match get_datum_field(&table, &key) {
+            // 🤓 `env.PORT=6334` must stay a string, not become an integer
+            Some(toml::Value::String(_)) => toml::Value::String(raw.to_string()),
+            Some(_) => parse_set_value(raw),
+            None => {
+                let typed = parse_set_value(raw);
+                let mut trial = table.clone();
+                set_datum_field(&mut trial, &key, typed.clone())?;
+                if typed.is_str() || schema_accepts(dest, &trial) {
+                    typed
+                } else {
+                    toml::Value::String(raw.to_string())
+                }
+            }
+        };
+        set_datum_field(&mut table, &key, value)?;
+    }
+    Ok(toml::to_string_pretty(&table)?)
+}
+
+fn get_datum_field<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Value> {
+    let (tables, field) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut current = table;
+    for part in tables.split('.').filter(|part| !part.is_empty()) {
+        current = current.get(part)?.as_table()?;
+    }
+    current.get(field)
+}
+
+/// Whether `table` parses with the schema for the datum type of `path`
+fn schema_accepts(path: &Path, table: &toml::Table) -> bool {
+    let value = toml::Value::Table(table.clone());
+    match datum_type_for(path) {
+        Some(DatumType::Ai) => AiConfig::deserialize(value).is_ok(),
+        Some(DatumType::AiModel) => AiModelConfig::deserialize(value).is_ok(),
+        _ => UnifiedConfig::deserialize(value).is_ok(),
+    }
+}
+
+/// A `--set` value as TOML (`8080`, `true`, `["a"]`), else the raw string
+fn parse_set_value(raw: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {}", raw))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
+
+fn set_datum_field(table: &mut toml::Table, key: &str, value: toml::Value) -> Result<()> {
+    let (tables, field) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut current = table;
+    for part in tables.split('.').filter(|part| !part.is_empty()) {
+        current = current
+            .entry(part)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .with_context(|| format!("Cannot set '{}': '{}' is not a table", key, part))?;
+    }
+    current.insert(field.to_string(), value);
+    Ok(())
+}
+
+fn handle_copy(path: &str, source: &str, new_name: &str, sets: &[String]) -> Result<()> {
+    let dir = get_expanded_path(path)?;
+    let file = find_datum_file(&dir, source)?;
+    let suffix = file
+        .file_name()
+        .and_then(|f| f.to_str())
+        .and_then(|f| f.strip_suffix(".toml"))
+        .and_then(|stem| stem.rsplit_once('.'))
+        .map(|(_, suffix)| suffix)
+        .with_context(|| format!("Cannot determine datum type of {}", file.display()))?;
+    let dest = dir.join(format!("{}.{}.toml", new_name, suffix));
+    if dest.exists() {
+        anyhow::bail!("{} already exists", dest.display());
+    }
+
+    let content = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let copied = copy_datum(&dest, &content, new_name, sets)
+        .with_context(|| format!("Failed to copy {}", file.display()))?;
+
+    let validation = validate_datum(&dest, &copied);
+    for warning in &validation.warnings {
+        eprintln!("⚠️  {}", format_issue(&dest, warning));
+    }
+    if !validation.errors.is_empty() {
+        for error in &validation.errors {
+            eprintln!("❌ {}", format_issue(&dest, error));
+        }
+        anyhow::bail!(
+            "Copy of {} does not validate, nothing written",
+            file.display()
+        );
+    }
+
+    std::fs::write(&dest, copied).with_context(|| format!("Failed to write {}", dest.display()))?;
+    println!("✅ Copied {} to {}", file.display(), dest.display());
+    Ok(())
+}
+
+/// What happened to a file seen by `datum watch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatumChange {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// The change a filesystem event represents, judged by whether the file still exists
+///
+/// 🤓 Editors save via rename or remove+create, so the final state decides
+/// rather than the raw event kind.
+fn datum_change(kind: &notify::EventKind, exists: bool) -> Option<DatumChange> {
+    use notify::EventKind;
+    match kind {
+        EventKind::Create(_) if exists => Some(DatumChange::Added),
+        EventKind::Modify(_) if exists => Some(DatumChange::Modified),
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) if !exists => {
+            Some(DatumChange::Removed)
+        }
+        EventKind::Remove(_) => Some(DatumChange::Modified),
+        _ => None,
+    }
+}
+
+fn handle_watch(target: &str, auto_status: bool) -> Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::collections::BTreeMap;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let dir = get_expanded_path(target)?;
+    if !dir.is_dir() {
+        anyhow::bail!("Not a directory: {}", dir.display());
+    }
+
+    let (tx, rx) = mpsc::channel::<notify::Result<notify::Event>>();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = tx.send(event);
+    })
+    .context("Failed to create file watcher")?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .with_context(|| format!("Failed to watch {}", dir.display()))?;
+    println!("👀 Watching {} (Ctrl-C to stop)", dir.display());
+
+    while let Ok(first) = rx.recv() {
+        // Coalesce the burst of events a single save produces
+        let mut changes = BTreeMap::new();
+        let mut pending = Some(first);
+        while let Some(event) = pending {
+            match event {
+                Ok(event) => {
+                    for file in event.paths {
+                        if file.extension().is_some_and(|ext| ext == "toml") {
+                            let first_kind = changes.get(&file).copied();
+                            if let Some(change) = datum_change(&event.kind, file.exists()) {
+                                // A file created in this batch stays "added" however often it is written
+                                let change = match (first_kind, change) {
+                                    (Some(DatumChange::Added), DatumChange::Modified) => {
+                                        DatumChange::Added
+                                    }
+                                    _ => change,
+                                };
+                                changes.insert(file, change);
+                            }
+                        }
+                    }
+                }
+                Err(e) => eprintln!("⚠️  Watch error: {}", e),
+            }
+            pending = rx.recv_timeout(Duration::from_millis(200)).ok();
+        }
+
+        if changes.is_empty() {
+            continue;
+        }
+        for (file, change) in &changes {
+            let name = file.file_name().unwrap_or_default().to_string_lossy();
+            match change {
+                DatumChange::Removed => {
+                    println!("➖ {} removed", name);
+                    continue;
+                }
+                DatumChange::Added => println!("➕ {} added", name),
+                DatumChange::Modified => println!("✏️  {} modified", name),
+            }
+            let content = match std::fs::read_to_string(file) {
+                Ok(content) => content,
+                Err(e) => {
+                    eprintln!("   ❌ Failed to read: {}", e);
+                    continue;
+                }
+            };
+            let validation = validate_datum(file, &content);
+            for error in &validation.errors {
+                eprintln!("   ❌ {}", format_issue(file, error));
+            }
+            for warning in &validation.warnings {
+                eprintln!("   ⚠️  {}", format_issue(file, warning));
+            }
+            if validation.errors.is_empty() {
+                println!("   ✅ valid");
+            }
+        }
+        if auto_status {
+            println!();
+            if let Err(e) = crate::commands::orchestrator::handle_status(target, false) {
+                eprintln!("❌ Failed to show orchestrator status: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The datum file for `name` (`qdrant` or `qdrant.docker`) in `dir`
+fn find_datum_file(dir: &Path, name: &str) -> Result<PathBuf> {
+    let direct = dir.join(format!("{}.toml", name));
+    if datum_type_for(&direct).is_some_and(|_| direct.is_file()) {
+        return Ok(direct);
+    }
+
+    let matches: Vec<PathBuf> = list_datums(dir, &[])?
+        .into_iter()
+        .filter(|entry| entry.name == name)
+        .map(|entry| entry.file)
+        .collect();
+    match matches.as_slice() {
+        [file] => Ok(file.clone()),
+        [] => anyhow::bail!("No datum named '{}' in {}", name, dir.display()),
+        _ => anyhow::bail!(
+            "'{}' is ambiguous ({}); add the type, e.g. {}",
+            name,
+            matches
+                .iter()
+                .filter_map(|f| f.file_name()?.to_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            matches[0]
+                .file_name()
+                .and_then(|f| f.to_str())
+                .and_then(|f| f.strip_suffix(".toml"))
+                .unwrap_or(name)
+        ),
+    }
+}
+
+/// File content at a git revision (`git show <rev>:./<file>` in the datum directory)
+fn git_show(dir: &Path, rev: &str, file_name: &str) -> Result<String> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["show", &format!("{}:./{}", rev, file_name)])
+        .output()
+        .context("Failed to run git show")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git show {}:{} failed: {}",
+            rev,
+            file_name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn handle_diff(path: &str, name: &str, ref1: Option<&str>, ref2: Option<&str>) -> Result<()> {
+    let dir = get_expanded_path(path)?;
+    let file = find_datum_file(&dir, name)?;
+    let file_name = file
+        .file_name()
+        .and_then(|f| f.to_str())
+        .context("Datum file name is not valid UTF-8")?;
+
+    let old_rev = ref1.unwrap_or("HEAD");
+    let old_content = git_show(&dir, old_rev, file_name)?;
+    let (new_label, new_content) = match ref2 {
+        Some(rev) => (rev.to_string(), git_show(&dir, rev, file_name)?),
+        None => (
+            "working tree".to_string(),
+            std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read {}", file.display()))?,
+        ),
+    };
+
+    let old = parse_datum_value(&file, &old_content)
+        .with_context(|| format!("Failed to parse {} at {}", file_name, old_rev))?;
+    let new = parse_datum_value(&file, &new_content)
+        .with_context(|| format!("Failed to parse {} at {}", file_name, new_label))?;
+
+    println!("📝 {} ({} → {})", file_name, old_rev, new_label);
+    let changes = diff_datum_values(&old, &new);
+    if changes.is_empty() {
+        println!("   ✅ No field changes");
+        return Ok(());
+    }
+    for change in &changes {
+        match change {
+            FieldChange::Added(key, value) => println!("   ➕ {}: {}", key, value),
+            FieldChange::Removed(key, value) => println!("   ➖ {}: {}", key, value),
+            FieldChange::Changed(key, old, new) => println!("   🔄 {}: {} → {}", key, old, new),
+        }
+    }
+    println!("\n📊 {} field(s) changed", changes.len());
+
+    Ok(())
+}
+
+/// A datum file found by `datum list`
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DatumListEntry {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub datum_type: String,
+    pub file: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Datum files in `dir`, optionally restricted to `types` (case-insensitive)
+pub fn list_datums(dir: &Path, types: &[String]) -> Result<Vec<DatumListEntry>> {
+    let wanted = types
+        .iter()
+        .map(|t| {
+            let t = t.trim().to_lowercase();
+            datum_type_slug(&t)
+                .map(|_| t.clone())
+                .with_context(|| format!("Unknown datum type '{}'", t))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut entries = Vec::new();
+    for file in datum_files(dir)? {
+        let Some(stem) = file
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".toml"))
+        else {
+            continue;
+        };
+        let Some((name, suffix)) = stem.rsplit_once('.') else {
+            continue;
+        };
+        if !wanted.is_empty() && !wanted.iter().any(|t| t == suffix) {
+            continue;
+        }
+        // 🤓 Only `[b00t] description` is needed, so skip the typed schema (ai datums differ)
+        let description = std::fs::read_to_string(&file)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+            .and_then(|table| {
+                table
+                    .get("b00t")?
+                    .get("description")?
+                    .as_str()
+                    .map(str::to_string)
+            });
+        entries.push(DatumListEntry {
+            name: name.to_string(),
+            datum_type: suffix.to_string(),
+            file,
+            description,
+        });
+    }
+
+    entries.sort_by(|a, b| (&a.datum_type, &a.name).cmp(&(&b.datum_type, &b.name)));
+    Ok(entries)
+}
+
+fn handle_list(target: &str, types: &[String], json: bool) -> Result<()> {
+    let dir = get_expanded_path(target)?;
+    let entries = list_datums(&dir, types)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("ℹ️  No matching datums found in {}", dir.display());
+        return Ok(());
+    }
+
+    let width = entries.iter().map(|e| e.name.len()).max().unwrap_or(0);
+    let mut current_type = "";
+    for entry in &entries {
+        if entry.datum_type != current_type {
+            current_type = &entry.datum_type;
+            let count = entries
+                .iter()
+                .filter(|e| e.datum_type == current_type)
+                .count();
+            println!("\n📦 {} ({})", current_type, count);
+        }
+        println!("   {:width$}  {}", entry.name, entry.file.display());
+        if let Some(description) = &entry.description {
+            println!("   {:width$}  {}", "", description);
+        }
+    }
+
+    Ok(())
+}
+
+/// A problem found in a datum file
+#[derive(Debug, PartialEq)]
+pub struct ValidationIssue {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Schema errors (fatal), unknown/deprecated-key warnings and missing
+/// recommended-key hints for one datum file
+#[derive(Debug, Default)]
+pub struct DatumValidation {
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+    pub hints: Vec<ValidationIssue>,
+}
+
+fn handle_validate(target: &str) -> Result<()> {
+    let Some(summary) = check_datum_files(target, true)? else {
+        return Ok(());
+    };
+    if summary.failed > 0 {
+        anyhow::bail!(
+            "{} of {} datum files failed validation",
+            summary.failed,
+            summary.files
+        );
+    }
+    println!("✅ {} datum files valid", summary.files);
+    Ok(())
+}
+
+fn handle_lint(target: &str, strict: bool) -> Result<()> {
+    let Some(summary) = check_datum_files(target, false)? else {
+        return Ok(());
+    };
+    if summary.failed > 0 {
+        anyhow::bail!(
+            "{} of {} datum files failed to parse",
+            summary.failed,
+            summary.files
+        );
+    }
+    if strict && summary.warnings > 0 {
+        anyhow::bail!(
+            "{} lint warning(s) in {} datum files",
+            summary.warnings,
+            summary.files
+        );
+    }
+    println!(
+        "✅ {} datum files linted: {} warning(s), {} hint(s)",
+        summary.files, summary.warnings, summary.hints
+    );
+    Ok(())
+}
+
+/// Issue counts from one `check_datum_files` pass
+struct CheckSummary {
+    files: usize,
+    failed: usize,
+    warnings: usize,
+    hints: usize,
+}
+
+/// Run `validate_datum` over every datum in `target`, printing each issue;
+/// `references` also resolves stack/depends_on references against the
+/// directory. Returns `None` when there are no datum files.
+fn check_datum_files(target: &str, references: bool) -> Result<Option<CheckSummary>> {
+    let target = get_expanded_path(target)?;
+    let files = datum_files(&target)?;
+    if files.is_empty() {
+        println!("ℹ️  No datum files found in {}", target.display());
+        return Ok(None);
+    }
+
+    let known = datum_keys(if target.is_file() {
+        target.parent().unwrap_or(Path::new("."))
+    } else {
+        &target
+    });
+
+    let mut summary = CheckSummary {
+        files: files.len(),
+        failed: 0,
+        warnings: 0,
+        hints: 0,
+    };
+    for file in &files {
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let mut validation = validate_datum(file, &content);
+        if references && let Some(datum) = parse_boot_datum(file, &content) {
+            validation
+                .errors
+                .extend(datum.validate_references(&known).into_iter().map(|error| {
+                    ValidationIssue {
+                        line: None,
+                        message: error.to_string(),
+                    }
+                }));
+        }
+        if !validation.errors.is_empty() {
+            summary.failed += 1;
+        }
+        summary.warnings += validation.warnings.len();
+        summary.hints += validation.hints.len();
+        for error in &validation.errors {
+            eprintln!("❌ {}", format_issue(file, error));
+        }
+        for warning in &validation.warnings {
+            eprintln!("⚠️  {}", format_issue(file, warning));
+        }
+        if !references {
+            for hint in &validation.hints {
+                eprintln!("💡 {}", format_issue(file, hint));
+            }
+        }
+    }
+    Ok(Some(summary))
+}
+
+/// `[b00t]` keys that still parse (as serde aliases) but have a newer spelling
+const DEPRECATED_KEYS: &[(&str, &str)] = &[("services", "members")];
+
+/// `[b00t]` keys worth setting for a datum type even though the schema allows omitting them
+fn recommended_keys(datum_type: Option<&DatumType>) -> &'static [&'static str] {
+    match datum_type {
+        Some(DatumType::Cli) => &["description", "install", "version", "version_regex"],
+        Some(DatumType::Docker) => &["description", "health_url"],
+        Some(DatumType::Mcp) => &["description", "keywords"],
+        _ => &["description"],
+    }
+}
+
+fn format_issue(file: &Path, issue: &ValidationIssue) -> String {
+    match issue.line {
+        Some(line) => format!("{}:{}: {}", file.display(), line, issue.message),
+        None => format!("{}: {}", file.display(), issue.message),
+    }
+}
+
+/// `<name>.<type>.toml` files in `target` (or `target` itself when it is a file)
+fn datum_files(target: &Path) -> Result<Vec<PathBuf>> {
+    if target.is_file() {
+        return Ok(vec![target.to_path_buf()]);
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(target)
+        .with_context(|| format!("Failed to read directory {}", target.display()))?
+    {
+        let path = entry?.path();
+        if path.is_file() && datum_type_for(&path).is_some() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Datum type from the `.<type>.toml` filename suffix
+fn datum_type_for(path: &Path) -> Option<DatumType> {
+    let file_name = path.file_name()?.to_str()?;
+    let stem = file_name.strip_suffix(".toml")?;
+    let (name, suffix) = stem.rsplit_once('.')?;
+    if name.is_empty() {
+        return None;
+    }
+    datum_type_slug(suffix)
+}
+
+/// Datum type for a filename suffix / type slug (`docker`, `ai_model`, ...)
+fn datum_type_slug(slug: &str) -> Option<DatumType> {
+    match serde_json::from_value(serde_json::Value::String(slug.to_string())).ok()? {
+        DatumType::Unknown | DatumType::Config => None,
+        datum_type => Some(datum_type),
+    }
+}
+
+/// Validate one datum file against the schema for its type suffix
+pub fn validate_datum(path: &Path, content: &str) -> DatumValidation {
+    let mut validation = DatumValidation::default();
+    let table: toml::Table = match toml::from_str(content) {
+        Ok(table) => table,
+        Err(e) => {
+            validation.errors.push(toml_error(content, &e));
+            return validation;
+        }
+    };
+
+    let (parsed, unknown_tables) = match datum_type_for(path) {
+        Some(DatumType::Ai) => (
+            check_schema::<AiConfig>(content),
+            unknown_fields::<StrictAiConfig>(&table),
+        ),
+        Some(DatumType::AiModel) => (
+            check_schema::<AiModelConfig>(content),
+            unknown_fields::<StrictAiModelConfig>(&table),
+        ),
+        _ => (
+            check_schema::<UnifiedConfig>(content),
+            unknown_fields::<StrictUnifiedConfig>(&table),
+        ),
+    };
+    validation.errors.extend(parsed.err());
+    let datum = parse_boot_datum(path, content);
+    if let Some(datum) = &datum {
+        validation
+            .errors
+            .extend(datum.validate().into_iter().map(|error| ValidationIssue {
+                line: None,
+                message: error.to_string(),
+            }));
+    }
+
+    for key in unknown_tables {
+        validation.warnings.push(unknown_key(content, None, &key));
+    }
+    if let Some(toml::Value::Table(b00t)) = table.get("b00t") {
+        for key in unknown_fields::<StrictBootDatum>(b00t) {
+            validation
+                .warnings
+                .push(unknown_key(content, Some("b00t"), &key));
+        }
+        if let Some(datum) = &datum {
+            for (old, new) in DEPRECATED_KEYS {
+                if b00t.contains_key(*old) {
+                    let mut issue = unknown_key(content, Some("b00t"), old);
+                    issue.message = format!("deprecated field `{}` in [b00t], use `{}`", old, new);
+                    validation.warnings.push(issue);
+                }
+            }
+            for key in recommended_keys(datum.datum_type.as_ref()) {
+                if !b00t.contains_key(*key) {
+                    validation.hints.push(ValidationIssue {
+                        line: None,
+                        message: format!("consider setting `{}` in [b00t]", key),
+                    });
+                }
+            }
+        }
+    }
+    validation
+}
+
+/// The `[b00t]` datum of a non-AI datum file, typed from the filename when `type` is omitted
+fn parse_boot_datum(path: &Path, content: &str) -> Option<BootDatum> {
+    let file_type = datum_type_for(path);
+    if matches!(file_type, Some(DatumType::Ai | DatumType::AiModel)) {
+        return None;
+    }
+    let mut datum = toml::from_str::<UnifiedConfig>(content).ok()?.b00t;
+    if datum.datum_type.is_none() {
+        datum.datum_type = file_type;
+    }
+    Some(datum)
+}
+
+/// `<name>.<type>` keys of the datum files in `dir`
+fn datum_keys(dir: &Path) -> std::collections::HashSet<String> {
+    datum_files(dir)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|file| file.file_name()?.to_str()?.strip_suffix(".toml"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// `BootDatum::validate` for one datum file, plus `depends_on`/`members`
+/// references checked against the datum files in the same directory
+pub fn validate_datum_file(file: &Path) -> Result<Vec<crate::ValidationError>> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    check_schema::<UnifiedConfig>(&content)
+        .map_err(|issue| anyhow::anyhow!("{}", format_issue(file, &issue)))?;
+    let Some(datum) = parse_boot_datum(file, &content) else {
+        return Ok(Vec::new());
+    };
+
+    let dir = file.parent().unwrap_or(Path::new("."));
+    let mut errors = datum.validate();
+    errors.extend(datum.validate_references(&datum_keys(dir)));
+    Ok(errors)
+}
+
+fn check_schema<T: DeserializeOwned>(content: &str) -> Result<(), ValidationIssue> {
+    toml::from_str::<T>(content)
+        .map(|_| ())
+        .map_err(|e| toml_error(content, &e))
+}
+
+fn toml_error(content: &str, error: &toml::de::Error) -> ValidationIssue {
+    ValidationIssue {
+        line: error.span().map(|span| line_of(content, span.start)),
+        message: error.message().to_string(),
     }
 }
 
+fn unknown_key(content: &str, table: Option<&str>, key: &str) -> ValidationIssue {
+    // 🤓 Point at the first `key =` or `[table.key` header; good enough for a typo report
+    let header = match table {
+        Some(table) => format!("{}.{}", table, key),
+        None => key.to_string(),
+    };
+    let line = content
+        .lines()
+        .position(|line| {
+            let line = line.trim_start();
+            let is_assignment = line
+                .strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with('='));
+            let is_header = line
+                .strip_prefix('[')
+                .map(|rest| rest.trim_start_matches('['))
+                .and_then(|rest| rest.strip_prefix(header.as_str()))
+                .is_some_and(|rest| rest.starts_with(['.', ']']));
+            is_assignment || is_header
+        })
+        .map(|idx| idx + 1);
+    let message = match table {
+        Some(table) => format!("unknown field `{}` in [{}]", key, table),
+        None => format!("unknown top-level table `{}`", key),
+    };
+    ValidationIssue { line, message }
+}
+
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+/// Keys of `table` that the `deny_unknown_fields` struct `T` rejects, in serde's order
+///
+/// 🤓 serde stops at the first unknown field, so drop each reported key and retry.
+/// Any other error (a wrong type) is a schema error reported elsewhere and ends
+/// the scan, so keys after it are only reported once the datum parses.
+fn unknown_fields<T: DeserializeOwned>(table: &toml::Table) -> Vec<String> {
+    let mut table = table.clone();
+    let mut unknown = Vec::new();
+    while let Err(e) = T::deserialize(toml::Value::Table(table.clone())) {
+        let message = e.to_string();
+        let Some(key) = message
+            .strip_prefix("unknown field `")
+            .and_then(|rest| rest.split_once('`'))
+            .map(|(key, _)| key.to_string())
+        else {
+            break;
+        };
+        if table.remove(&key).is_none() {
+            break;
+        }
+        unknown.push(key);
+    }
+    unknown
+}
+
 fn handle_show(b00t_path: &str, datum_name: &str) -> Result<()> {
     // Find the datum
     let datum = datum_utils::find_datum_by_pattern(b00t_path, datum_name)?
@@ -144,3 +1282,379 @@ fn handle_show(b00t_path: &str, datum_name: &str) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_datum_values_reports_field_changes() {
+        let old = parse_datum_value(
+            Path::new("qdrant.docker.toml"),
+            r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector DB"
+image = "qdrant/qdrant:v1.9"
+docker_args = ["-p", "6333:6333"]
+"#,
+        )
+        .unwrap();
+        let new = parse_datum_value(
+            Path::new("qdrant.docker.toml"),
+            r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector DB"
+image = "qdrant/qdrant:latest"
+restart = "always"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            diff_datum_values(&old, &new),
+            vec![
+                FieldChange::Removed(
+                    "b00t.docker_args".to_string(),
+                    serde_json::json!(["-p", "6333:6333"])
+                ),
+                FieldChange::Changed(
+                    "b00t.image".to_string(),
+                    serde_json::json!("qdrant/qdrant:v1.9"),
+                    serde_json::json!("qdrant/qdrant:latest")
+                ),
+                FieldChange::Added("b00t.restart".to_string(), serde_json::json!("always")),
+            ]
+        );
+        assert!(diff_datum_values(&old, &old).is_empty());
+    }
+
+    fn validate(file_name: &str, content: &str) -> DatumValidation {
+        validate_datum(Path::new(file_name), content)
+    }
+
+    #[test]
+    fn test_list_datums_filters_by_type() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "qdrant.docker.toml",
+            "github.mcp.toml",
+            "just.cli.toml",
+            "bootstrap.toml",
+        ] {
+            std::fs::write(dir.path().join(file), "").unwrap();
+        }
+
+        let all = list_datums(dir.path(), &[]).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let filtered = list_datums(dir.path(), &["MCP".to_string(), "docker".to_string()]).unwrap();
+        let names: Vec<_> = filtered
+            .iter()
+            .map(|e| (e.datum_type.as_str(), e.name.as_str()))
+            .collect();
+        assert_eq!(names, vec![("docker", "qdrant"), ("mcp", "github")]);
+
+        assert!(list_datums(dir.path(), &["nope".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_deprecated_keys_and_hints() {
+        let lint = validate(
+            "devstack.stack.toml",
+            "[b00t]\nname = \"devstack\"\nhint = \"dev\"\nservices = []\nimagee = \"x\"\n",
+        );
+        assert!(lint.errors.is_empty(), "{:?}", lint.errors);
+        let warnings: Vec<_> = lint.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert_eq!(
+            warnings,
+            [
+                "unknown field `imagee` in [b00t]",
+                "deprecated field `services` in [b00t], use `members`"
+            ]
+        );
+        assert_eq!(lint.warnings[1].line, Some(4));
+        assert_eq!(lint.hints.len(), 1);
+
+        let lint = validate(
+            "jq.cli.toml",
+            "[b00t]\nname = \"jq\"\nhint = \"json\"\nversion = \"jq --version\"\n",
+        );
+        assert!(lint.warnings.is_empty());
+        let hints: Vec<_> = lint.hints.iter().map(|h| h.message.as_str()).collect();
+        assert_eq!(
+            hints,
+            [
+                "consider setting `description` in [b00t]",
+                "consider setting `install` in [b00t]",
+                "consider setting `version_regex` in [b00t]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_warns_on_unknown_top_level_table() {
+        let lint = validate(
+            "langchain.ai.toml",
+            "[b00t]\nname = \"langchain\"\nhint = \"llm\"\n\n[env]\nKEY = \"x\"\n\n[langchain]\nchain = \"x\"\n",
+        );
+        assert!(lint.errors.is_empty(), "{:?}", lint.errors);
+        assert_eq!(
+            lint.warnings,
+            vec![ValidationIssue {
+                line: Some(8),
+                message: "unknown top-level table `langchain`".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lint_fails_on_warnings_only_when_strict() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("jq.cli.toml"),
+            "[b00t]\nname = \"jq\"\nhint = \"json\"\nimagee = \"x\"\n",
+        )
+        .unwrap();
+        let target = dir.path().to_str().unwrap();
+
+        assert!(handle_lint(target, false).is_ok());
+        assert!(handle_lint(target, true).is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_datum() {
+        let content = "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"vector db\"\nimage = \"qdrant/qdrant\"\n";
+        let validation = validate("qdrant.docker.toml", content);
+        assert!(validation.errors.is_empty());
+        assert!(validation.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_warns_on_typo_with_line() {
+        let content = "[b00t]\nname = \"qdrant\"\nhint = \"vector db\"\nimgae = \"qdrant/qdrant\"\n\n[b00t.helth]\nurl = \"x\"\n";
+        let validation = validate("qdrant.docker.toml", content);
+        // 🤓 The typo also leaves the docker datum without an image
+        assert_eq!(
+            validation.errors,
+            vec![ValidationIssue {
+                line: None,
+                message:
+                    "image: docker datum needs an image (or oci_uri, resource_path, use_compose)"
+                        .to_string(),
+            }]
+        );
+        assert_eq!(
+            validation.warnings,
+            vec![
+                ValidationIssue {
+                    line: Some(6),
+                    message: "unknown field `helth` in [b00t]".to_string(),
+                },
+                ValidationIssue {
+                    line: Some(4),
+                    message: "unknown field `imgae` in [b00t]".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_datum_file_checks_references() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ollama.docker.toml"),
+            "[b00t]\nname = \"ollama\"\nhint = \"llm\"\nimage = \"ollama/ollama\"\n",
+        )
+        .unwrap();
+        let file = dir.path().join("rag.stack.toml");
+        std::fs::write(
+            &file,
+            "[b00t]\nname = \"rag\"\nhint = \"rag stack\"\nmembers = [\"ollama.docker\", \"qdrant.docker\"]\ndepends_on = [\"ollama\"]\n",
+        )
+        .unwrap();
+
+        let errors = validate_datum_file(&file).unwrap();
+        assert_eq!(
+            errors,
+            vec![crate::ValidationError {
+                field: "members".to_string(),
+                message: "unknown datum 'qdrant.docker'".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_requires_mcp_command_or_url() {
+        let content = "[b00t]\nname = \"github\"\nhint = \"gh\"\n";
+        let validation = validate("github.mcp.toml", content);
+        assert_eq!(validation.errors.len(), 1);
+        assert!(validation.errors[0].message.starts_with("command: "));
+
+        let content = "[b00t]\nname = \"aws\"\nhint = \"docs\"\n\n[b00t.mcp.httpstream]\nurl = \"https://example.com/mcp\"\n";
+        assert!(validate("aws.mcp.toml", content).errors.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_schema_error_with_line() {
+        let content = "[b00t]\nname = \"qdrant\"\nhint = \"vector db\"\ndepends_on = \"ollama\"\n";
+        let validation = validate("qdrant.docker.toml", content);
+        assert_eq!(validation.errors.len(), 1);
+        assert_eq!(validation.errors[0].line, Some(4));
+    }
+
+    #[test]
+    fn test_validate_uses_ai_schema_for_ai_suffix() {
+        let content = "[b00t]\nname = \"openai\"\nhint = \"provider\"\n\n[models]\ngpt-4 = {}\n";
+        let validation = validate("openai.ai.toml", content);
+        assert!(validation.errors.is_empty() && validation.warnings.is_empty());
+
+        let validation = validate("openai.mcp.toml", content);
+        assert_eq!(validation.warnings[0].line, Some(5));
+    }
+
+    #[test]
+    fn test_datum_type_for_suffix() {
+        assert_eq!(
+            datum_type_for(Path::new("gpt-4o.ai_model.toml")),
+            Some(DatumType::AiModel)
+        );
+        assert_eq!(datum_type_for(Path::new("bootstrap.toml")), None);
+        assert_eq!(datum_type_for(Path::new("_b00t_.config.toml")), None);
+    }
+
+    #[test]
+    fn test_export_datum_formats() {
+        let path = Path::new("qdrant.docker.toml");
+        let content = r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector DB"
+image = "qdrant/qdrant:latest"
+docker_args = ["-p", "6333:6333"]
+
+[b00t.env]
+QDRANT_URL = "http://localhost:6333"
+"#;
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export_datum(path, content, ExportFormat::Json).unwrap())
+                .unwrap();
+        assert_eq!(json["b00t"]["image"], "qdrant/qdrant:latest");
+        assert!(json["b00t"].get("depends_on").is_none());
+
+        let yaml: serde_json::Value =
+            serde_yaml::from_str(&export_datum(path, content, ExportFormat::Yaml).unwrap())
+                .unwrap();
+        assert_eq!(yaml, json);
+
+        let toml_out = export_datum(path, content, ExportFormat::Toml).unwrap();
+        assert_eq!(
+            parse_datum_value(path, &toml_out).unwrap(),
+            parse_datum_value(path, content).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_copy_datum_renames_and_applies_sets() {
+        let content = r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector database"
+image = "qdrant/qdrant:latest"
+
+[b00t.env]
+QDRANT_PORT = "6333"
+"#;
+        let sets = vec![
+            "image=qdrant/qdrant:v1.12.0".to_string(),
+            "b00t.env.QDRANT_PORT=6334".to_string(),
+            "oci_uri = \"docker.io/qdrant\"".to_string(),
+        ];
+        let dest = Path::new("qdrant-test.docker.toml");
+        let copied: toml::Table =
+            toml::from_str(&copy_datum(dest, content, "qdrant-test", &sets).unwrap()).unwrap();
+        let b00t = copied["b00t"].as_table().unwrap();
+        assert_eq!(b00t["name"].as_str(), Some("qdrant-test"));
+        assert_eq!(b00t["image"].as_str(), Some("qdrant/qdrant:v1.12.0"));
+        assert_eq!(b00t["oci_uri"].as_str(), Some("docker.io/qdrant"));
+        assert_eq!(b00t["hint"].as_str(), Some("Vector database"));
+        assert_eq!(b00t["env"]["QDRANT_PORT"].as_str(), Some("6334"));
+
+        assert!(copy_datum(dest, content, "x", &["image".to_string()]).is_err());
+        assert!(copy_datum(dest, content, "x", &["b00t.hint.sub=1".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_copy_datum_types_new_fields_by_schema() {
+        let content = "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nimage = \"qdrant/qdrant\"\n";
+        let sets = vec![
+            "b00t.env.QDRANT_PORT=6334".to_string(),
+            "hint=42".to_string(),
+            "docker_args=[\"-p\", \"6333:6333\"]".to_string(),
+        ];
+        let dest = Path::new("qdrant-test.docker.toml");
+        let copied: toml::Table =
+            toml::from_str(&copy_datum(dest, content, "qdrant-test", &sets).unwrap()).unwrap();
+        let b00t = copied["b00t"].as_table().unwrap();
+        assert_eq!(b00t["env"]["QDRANT_PORT"].as_str(), Some("6334"));
+        assert_eq!(b00t["hint"].as_str(), Some("42"));
+        assert_eq!(b00t["docker_args"].as_array().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn test_datum_change_uses_final_file_state() {
+        use notify::EventKind;
+        use notify::event::{CreateKind, ModifyKind, RemoveKind, RenameMode};
+
+        let create = EventKind::Create(CreateKind::File);
+        let modify = EventKind::Modify(ModifyKind::Any);
+        let rename = EventKind::Modify(ModifyKind::Name(RenameMode::Any));
+        let remove = EventKind::Remove(RemoveKind::File);
+
+        assert_eq!(datum_change(&create, true), Some(DatumChange::Added));
+        assert_eq!(datum_change(&modify, true), Some(DatumChange::Modified));
+        assert_eq!(datum_change(&rename, true), Some(DatumChange::Modified));
+        assert_eq!(datum_change(&rename, false), Some(DatumChange::Removed));
+        assert_eq!(datum_change(&remove, false), Some(DatumChange::Removed));
+        // Removed then recreated before the event was handled (atomic save)
+        assert_eq!(datum_change(&remove, true), Some(DatumChange::Modified));
+        assert_eq!(
+            datum_change(&EventKind::Access(notify::event::AccessKind::Any), true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_search_datums_with_and_without_field() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("qdrant.docker.toml"),
+            "[b00t]\nname = \"qdrant\"\nhint = \"Vector DB\"\nimage = \"qdrant/qdrant:latest\"\ndocker_args = [\n  \"-p\", \"6333:6333\",\n]\nhealth_url = \"http://localhost:6333\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.md"), "qdrant is not a datum\n").unwrap();
+
+        let all = search_datums(dir.path(), "QDRANT", None).unwrap();
+        assert_eq!(all.iter().map(|m| m.line).collect::<Vec<_>>(), vec![2, 4]);
+
+        let image = search_datums(dir.path(), "qdrant", Some("image")).unwrap();
+        assert_eq!(image.len(), 1);
+        assert_eq!(image[0].content, "image = \"qdrant/qdrant:latest\"");
+
+        assert!(
+            search_datums(dir.path(), "6333", Some("image"))
+                .unwrap()
+                .is_empty()
+        );
+        let ports = search_datums(dir.path(), "6333", Some("docker_args")).unwrap();
+        assert_eq!(ports.iter().map(|m| m.line).collect::<Vec<_>>(), vec![6]);
+        assert_eq!(assigned_key("b00t.image = \"x\""), Some("image"));
+        assert_eq!(assigned_key("  \"-p\", \"a=b\","), None);
+    }
+}
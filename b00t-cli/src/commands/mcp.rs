@@ -31,6 +31,43 @@ pub enum McpCommands {
         )]
         command_args: Vec<String>,
     },
+    #[clap(
+        about = "Create a new .mcp.toml datum for an MCP server",
+        long_about = "Scaffold a <name>.mcp.toml datum in the datum directory.\n\nThe command must exist on PATH. Use --interactive to be prompted for each field.\n\nExamples:\n  b00t-cli mcp add --name filesystem --command npx --args=-y,@modelcontextprotocol/server-filesystem\n  b00t-cli mcp add --name brave-search --command npx --args=-y,@modelcontextprotocol/server-brave-search --env BRAVE_API_KEY=${BRAVE_API_KEY}\n  b00t-cli mcp add --interactive"
+    )]
+    Add {
+        #[clap(
+            long,
+            help = "MCP server name",
+            required_unless_present = "interactive"
+        )]
+        name: Option<String>,
+        #[clap(
+            long,
+            help = "Server command (must be on PATH, e.g. npx, uvx, docker)",
+            required_unless_present = "interactive"
+        )]
+        command: Option<String>,
+        #[clap(
+            long,
+            value_delimiter = ',',
+            allow_hyphen_values = true,
+            help = "Server arguments (comma-separated)"
+        )]
+        args: Vec<String>,
+        #[clap(
+            long = "env",
+            value_name = "KEY=VAL",
+            help = "Environment variable (repeatable)"
+        )]
+        env: Vec<String>,
+        #[clap(long, help = "Description/hint for the MCP server")]
+        hint: Option<String>,
+        #[clap(long, help = "Longer description of what the MCP server does")]
+        description: Option<String>,
+        #[clap(short, long, help = "Prompt for each field")]
+        interactive: bool,
+    },
     #[clap(
         about = "List available MCP server configurations",
         long_about = "List available MCP server configurations.\n\nExamples:\n  b00t-cli mcp list\n  b00t-cli mcp list --json"
@@ -64,16 +101,132 @@ pub enum McpCommands {
     },
     #[clap(
         about = "Output MCP servers in various formats",
-        long_about = "Output MCP servers in various formats for configuration files.\n\nExamples:\n  b00t-cli mcp output filesystem,brave-search\n  b00t-cli mcp output --json filesystem\n  b00t-cli mcp output --mcpServers filesystem,brave-search"
+        long_about = "Output MCP servers in various formats for configuration files.\n\nExamples:\n  b00t-cli mcp output filesystem,brave-search\n  b00t-cli mcp output --json filesystem\n  b00t-cli mcp output --mcpServers filesystem,brave-search\n  b00t-cli mcp output --format cursor filesystem,aws-knowledge > ~/.cursor/mcp.json\n  b00t-cli mcp output --prefix work qdrant,github"
     )]
     Output {
         #[clap(long = "json", help = "Output raw JSON format without wrapper", action = clap::ArgAction::SetTrue)]
         json: bool,
         #[clap(long = "mcpServers", help = "Output in mcpServers format (default)", action = clap::ArgAction::SetTrue)]
         mcp_servers: bool,
+        #[clap(
+            long,
+            value_enum,
+            conflicts_with_all = ["json", "mcp_servers"],
+            help = "Output format (wrapper = mcpServers, json = bare map, cursor = Cursor's mcp.json)"
+        )]
+        format: Option<crate::McpOutputFormat>,
+        #[clap(
+            long,
+            value_name = "NAMESPACE",
+            help = "Prepend <NAMESPACE>_ to every server name in the output"
+        )]
+        prefix: Option<String>,
         #[clap(help = "Comma-separated list of MCP server names to output")]
         servers: String,
     },
+    #[clap(
+        about = "Check that an MCP server answers `initialize`",
+        long_about = "Start the server from its datum (stdio transport), send a JSON-RPC initialize request and wait for the response.\n\nExit codes: 0 = working, 1 = no response within the timeout, 2 = invalid response.\n\nExamples:\n  b00t-cli mcp test filesystem\n  b00t-cli mcp test brave-search --timeout 15"
+    )]
+    Test {
+        #[clap(help = "MCP server name")]
+        name: String,
+        #[clap(long, default_value_t = 5, help = "Seconds to wait for the response")]
+        timeout: u64,
+    },
+    #[clap(
+        about = "Delete an MCP server datum",
+        long_about = "Delete <name>.mcp.toml after a confirmation prompt (skip it with --force). The file is looked up like every other mcp command: the datum directory, then the default datum path, then ~/.b00t.\n\nOther datums that still name the server in depends_on or a [b00t.requires] prefer/fallback are listed as warnings first, since those references will dangle.\n\nExamples:\n  b00t-cli mcp remove brave-search\n  b00t-cli mcp remove filesystem --force"
+    )]
+    Remove {
+        #[clap(help = "MCP server name")]
+        name: String,
+        #[clap(long, help = "Delete without asking for confirmation")]
+        force: bool,
+    },
+    #[clap(
+        about = "Show which MCP servers are running",
+        long_about = "Check every MCP datum: stdio servers are looked up in the process table (ps) by their package or command, httpstream servers get a GET to their URL and show the HTTP status.\n\nstdio servers only run while a client holds them open, so `stopped` is normal when no agent is connected.\n\nExamples:\n  b00t-cli mcp status\n  b00t-cli mcp status --timeout 10"
+    )]
+    Status {
+        #[clap(
+            long,
+            default_value_t = 3,
+            help = "Seconds to wait for each HTTP server"
+        )]
+        timeout: u64,
+    },
+    #[clap(
+        about = "Print the environment an MCP server needs, ready to source",
+        long_about = "Print the env block of an MCP server datum as KEY=VALUE lines (values quoted for the shell).\n\n$VAR / ${VAR} references are expanded from the current environment; unset ones are left as-is with a warning on stderr.\n\nExamples:\n  b00t-cli mcp env github\n  eval \"$(b00t-cli mcp env github --export)\"\n  b00t-cli mcp env grok-guru --json"
+    )]
+    Env {
+        #[clap(help = "MCP server name")]
+        name: String,
+        #[clap(long, help = "Prefix each line with `export`")]
+        export: bool,
+        #[clap(long, help = "Output a JSON object instead", conflicts_with = "export")]
+        json: bool,
+    },
+    #[clap(
+        about = "Run an MCP server over stdio and log every JSON-RPC message",
+        long_about = "Start the server from its datum with its stdin/stdout bridged to this terminal (or the MCP client that launched b00t), teeing each JSON-RPC line to stderr or --log-file.\n\nLog lines are prefixed with an RFC 3339 timestamp and the direction: `→ server` for requests sent to the server, `← client` for replies going back to the client.\n\nExamples:\n  b00t-cli mcp proxy filesystem\n  b00t-cli mcp proxy github --log-file /tmp/github-mcp.log"
+    )]
+    Proxy {
+        #[clap(help = "MCP server name")]
+        name: String,
+        #[clap(long, help = "Append the message log to this file instead of stderr")]
+        log_file: Option<std::path::PathBuf>,
+    },
+    #[clap(
+        about = "Add, remove or sync MCP servers in Claude Desktop's config",
+        long_about = "Patch the mcpServers section of claude_desktop_config.json (~/Library/Application Support/Claude on macOS).\n\n--sync replaces the whole section with every MCP datum b00t knows about; --add merges the named servers and --remove drops them. The file is written atomically; restart Claude Desktop to pick up changes.\n\nExamples:\n  b00t-cli mcp claude-desktop --add filesystem,github\n  b00t-cli mcp claude-desktop --remove brave-search\n  b00t-cli mcp claude-desktop --sync"
+    )]
+    #[clap(group = clap::ArgGroup::new("claude_desktop_action").required(true).multiple(true).args(["add", "remove", "sync"]))]
+    ClaudeDesktop {
+        #[clap(
+            long,
+            value_delimiter = ',',
+            help = "MCP servers to add (comma-separated)"
+        )]
+        add: Vec<String>,
+        #[clap(
+            long,
+            value_delimiter = ',',
+            help = "MCP servers to remove (comma-separated)"
+        )]
+        remove: Vec<String>,
+        #[clap(
+            long,
+            conflicts_with_all = ["add", "remove"],
+            help = "Replace all servers with b00t's MCP datums"
+        )]
+        sync: bool,
+        #[clap(
+            long,
+            help = "Config file to patch instead of Claude Desktop's default"
+        )]
+        config: Option<std::path::PathBuf>,
+    },
+    #[clap(
+        about = "Write MCP servers into VS Code's settings.json",
+        long_about = "Merge MCP server datums into the \"mcp.servers\" section of VS Code settings.json (used by Copilot Chat).\n\n--workspace (default) patches .vscode/settings.json in the current directory; --user patches the user settings (~/.config/Code/User/settings.json on Linux). With no servers listed, every MCP datum b00t knows about is written. Other settings are preserved, the original file is backed up to settings.json.bak and the new file is written atomically.\n\nExamples:\n  b00t-cli mcp vscode filesystem,github\n  b00t-cli mcp vscode --user brave-search\n  b00t-cli mcp vscode --workspace"
+    )]
+    Vscode {
+        #[clap(
+            value_delimiter = ',',
+            help = "MCP servers to write (comma-separated, default: all)"
+        )]
+        servers: Vec<String>,
+        #[clap(
+            long,
+            conflicts_with = "user",
+            help = "Patch .vscode/settings.json in the current directory (default)"
+        )]
+        workspace: bool,
+        #[clap(long, help = "Patch the VS Code user settings.json")]
+        user: bool,
+    },
     #[clap(
         about = "MCP Registry operations (list, search, install dependencies)",
         long_about = "Interact with b00t MCP registry for server management and dependency installation.\n\nExamples:\n  b00t-cli mcp registry list\n  b00t-cli mcp registry search --tag docker\n  b00t-cli mcp registry get io.b00t/server-name\n  b00t-cli mcp registry install-deps io.b00t/server-name\n  b00t-cli mcp registry sync-official\n  b00t-cli mcp registry sync-datums --path ~/.dotfiles/_b00t_"
@@ -151,12 +304,8 @@ pub enum RegistryAction {
     },
     #[clap(about = "Sync registry from datum TOML files")]
     SyncDatums {
-        #[clap(
-            long,
-            help = "Path to datums directory",
-            default_value = "~/.dotfiles/_b00t_"
-        )]
-        path: String,
+        #[clap(long, help = "Path to datums directory (default: --path)")]
+        path: Option<String>,
     },
 }
 
@@ -206,6 +355,30 @@ impl McpCommands {
                     }
                 }
             }
+            McpCommands::Add {
+                name,
+                command,
+                args,
+                env,
+                hint,
+                description,
+                interactive,
+            } => {
+                let spec = McpAddSpec {
+                    name: name.clone().unwrap_or_default(),
+                    command: command.clone().unwrap_or_default(),
+                    args: args.clone(),
+                    env: env.clone(),
+                    hint: hint.clone(),
+                    description: description.clone(),
+                };
+                let spec = if *interactive {
+                    prompt_mcp_spec(spec)?
+                } else {
+                    spec
+                };
+                mcp_add(&spec, path)
+            }
             McpCommands::List { json } => crate::mcp_list(path, *json),
             McpCommands::Install {
                 name,
@@ -253,7 +426,7 @@ impl McpCommands {
                     }
                     "stdout" => {
                         // Output just the JSON for the specified server
-                        crate::mcp_output(path, false, name)
+                        crate::mcp_output(path, crate::McpOutputFormat::Json, name, None)
                     }
                     _ => {
                         anyhow::bail!(
@@ -266,12 +439,63 @@ impl McpCommands {
             McpCommands::Output {
                 json,
                 mcp_servers,
+                format,
+                prefix,
                 servers,
             } => {
-                let use_mcp_servers_wrapper = !json && (*mcp_servers || !servers.contains(','));
-                crate::mcp_output(path, use_mcp_servers_wrapper, servers)
+                let format = match format {
+                    Some(format) => *format,
+                    None if !json && (*mcp_servers || !servers.contains(',')) => {
+                        crate::McpOutputFormat::Wrapper
+                    }
+                    None => crate::McpOutputFormat::Json,
+                };
+                crate::mcp_output(path, format, servers, prefix.as_deref())
+            }
+            McpCommands::Test { name, timeout } => {
+                let info = crate::get_mcp_server_info(name, path)?;
+                if info.command.is_empty() || info.command == "HTTP" {
+                    anyhow::bail!("'{}' has no stdio command to test", name);
+                }
+
+                println!(
+                    "🧪 Testing {} ({} {})",
+                    name,
+                    info.command,
+                    info.args.join(" ")
+                );
+                match test_mcp_server(&info, std::time::Duration::from_secs(*timeout)).await? {
+                    McpTestOutcome::Working(server_info) => {
+                        println!("✅ {} responded to initialize", name);
+                        if let Some(server_info) = server_info {
+                            println!("   server: {}", server_info);
+                        }
+                        Ok(())
+                    }
+                    McpTestOutcome::NoResponse => {
+                        eprintln!("❌ {} did not respond within {}s", name, timeout);
+                        std::process::exit(1);
+                    }
+                    McpTestOutcome::Invalid(reason) => {
+                        eprintln!("❌ {} sent an invalid response: {}", name, reason);
+                        std::process::exit(2);
+                    }
+                }
+            }
+            McpCommands::Remove { name, force } => mcp_remove(path, name, *force),
+            McpCommands::Status { timeout } => mcp_status(path, *timeout).await,
+            McpCommands::Env { name, export, json } => mcp_env(path, name, *export, *json),
+            McpCommands::Proxy { name, log_file } => {
+                mcp_proxy(path, name, log_file.as_deref()).await
             }
-            McpCommands::Registry { action } => action.execute_async().await,
+            McpCommands::ClaudeDesktop {
+                add,
+                remove,
+                sync,
+                config,
+            } => claude_desktop(path, add, remove, *sync, config.as_deref()),
+            McpCommands::Vscode { servers, user, .. } => mcp_vscode(path, servers, *user),
+            McpCommands::Registry { action } => action.execute_async(path).await,
             McpCommands::Execute {
                 server_or_tool,
                 tool,
@@ -444,7 +668,7 @@ impl McpCommands {
 }
 
 impl RegistryAction {
-    pub async fn execute_async(&self) -> Result<()> {
+    pub async fn execute_async(&self, datum_path: &str) -> Result<()> {
         use b00t_c0re_lib::mcp_registry::McpRegistry;
 
         let mut registry = McpRegistry::default();
@@ -527,7 +751,7 @@ impl RegistryAction {
             }
             RegistryAction::SyncDatums { path } => {
                 println!("🔄 Syncing registry from datum files...");
-                let count = registry.sync_from_datums(path)?;
+                let count = registry.sync_from_datums(path.as_deref().unwrap_or(datum_path))?;
                 println!("✅ Synced {} MCP servers from datum files", count);
                 Ok(())
             }
@@ -535,10 +759,1279 @@ impl RegistryAction {
     }
 }
 
+/// Claude Desktop's config file for this OS (`~/Library/Application Support/Claude/...` on macOS)
+fn claude_desktop_config_path() -> Result<std::path::PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine the user config directory"))?;
+    Ok(config_dir.join("Claude").join("claude_desktop_config.json"))
+}
+
+/// Apply `--sync`/`--add`/`--remove` to a parsed Claude Desktop config.
+/// `servers` are the entries to add (or the full set when syncing); other
+/// top-level keys are left alone.
+fn patch_claude_desktop_config(
+    config: &mut serde_json::Value,
+    servers: serde_json::Map<String, serde_json::Value>,
+    remove: &[String],
+    sync: bool,
+) -> Result<()> {
+    if config.is_null() {
+        *config = serde_json::json!({});
+    }
+    let root = config
+        .as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("Claude Desktop config is not a JSON object"))?;
+
+    if sync || !root.get("mcpServers").is_some_and(|v| v.is_object()) {
+        root.insert(
+            "mcpServers".to_string(),
+            serde_json::Value::Object(Default::default()),
+        );
+    }
+    let mcp_servers = root["mcpServers"].as_object_mut().unwrap();
+    mcp_servers.extend(servers);
+    for name in remove {
+        if mcp_servers.remove(name).is_none() {
+            eprintln!("⚠️  {} is not configured in Claude Desktop", name);
+        }
+    }
+    Ok(())
+}
+
+/// Write `value` to `path.tmp` and rename it over `path`, so a crash never leaves half a file
+fn write_json_atomic(path: &std::path::Path, value: &serde_json::Value) -> Result<()> {
+    use anyhow::Context;
+
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = std::path::PathBuf::from(tmp_name);
+
+    std::fs::write(&tmp_path, serde_json::to_string_pretty(value)? + "\n")
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+/// Claude Desktop `mcpServers` entry; its config only launches local commands,
+/// so HTTP-only servers are an error (skipped with a warning by `--sync`)
+fn claude_desktop_server_json(datum: &crate::BootDatum) -> Result<serde_json::Value> {
+    if let Some(url) = crate::mcp_http_only_url(datum) {
+        anyhow::bail!(
+            "{} is HTTP-only ({}), which Claude Desktop's config cannot launch",
+            datum.name,
+            url
+        );
+    }
+    crate::mcp_server_json(datum)
+}
+
+fn claude_desktop(
+    path: &str,
+    add: &[String],
+    remove: &[String],
+    sync: bool,
+    config_path: Option<&std::path::Path>,
+) -> Result<()> {
+    use anyhow::Context;
+
+    let config_path = match config_path {
+        Some(p) => p.to_path_buf(),
+        None => claude_desktop_config_path()?,
+    };
+    if !config_path.parent().is_some_and(|dir| dir.is_dir()) {
+        anyhow::bail!(
+            "Claude Desktop config directory not found for {} (is Claude Desktop installed?)",
+            config_path.display()
+        );
+    }
+
+    let names = if sync {
+        crate::known_mcp_server_names(path)
+    } else {
+        let names: Vec<&str> = add
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        crate::check_mcp_servers_exist(path, &names)?;
+        names.into_iter().map(String::from).collect()
+    };
+
+    let mut servers = serde_json::Map::new();
+    for name in &names {
+        match crate::get_mcp_config(name, path).and_then(|datum| claude_desktop_server_json(&datum))
+        {
+            Ok(entry) => {
+                servers.insert(name.clone(), entry);
+            }
+            Err(e) if sync => eprintln!("⚠️  Skipping {}: {}", name, e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut config = if config_path.exists() {
+        let content = std::fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", config_path.display()))?
+    } else {
+        serde_json::Value::Null
+    };
+
+    let added: Vec<String> = servers.keys().cloned().collect();
+    patch_claude_desktop_config(&mut config, servers, remove, sync)?;
+    write_json_atomic(&config_path, &config)?;
+
+    if sync {
+        println!("🔄 Synced {} MCP server(s) to Claude Desktop", added.len());
+    } else if !added.is_empty() {
+        println!("✅ Added to Claude Desktop: {}", added.join(", "));
+    }
+    if !remove.is_empty() {
+        println!("🗑️  Removed from Claude Desktop: {}", remove.join(", "));
+    }
+    println!(
+        "📝 {} (restart Claude Desktop to apply)",
+        config_path.display()
+    );
+    Ok(())
+}
+
+/// VS Code's user settings.json (`~/.config/Code/User/settings.json` on Linux)
+fn vscode_user_settings_path() -> Result<std::path::PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine the user config directory"))?;
+    Ok(config_dir.join("Code").join("User").join("settings.json"))
+}
+
+/// Merge `servers` into `mcp.servers` of a parsed settings.json, leaving every other key alone
+fn patch_vscode_settings(
+    settings: &mut serde_json::Value,
+    servers: serde_json::Map<String, serde_json::Value>,
+) -> Result<()> {
+    if settings.is_null() {
+        *settings = serde_json::json!({});
+    }
+    let root = settings
+        .as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("VS Code settings.json is not a JSON object"))?;
+
+    let mcp = root.entry("mcp").or_insert_with(|| serde_json::json!({}));
+    if !mcp.is_object() {
+        *mcp = serde_json::json!({});
+    }
+    let mcp = mcp.as_object_mut().unwrap();
+    let existing = mcp
+        .entry("servers")
+        .or_insert_with(|| serde_json::json!({}));
+    if !existing.is_object() {
+        *existing = serde_json::json!({});
+    }
+    existing.as_object_mut().unwrap().extend(servers);
+    Ok(())
+}
+
+/// VS Code `mcp.servers` entry: the Cursor shape, with `"type": "http"` spelled out for
+/// HTTP-only servers (VS Code wants the transport explicit)
+fn vscode_server_json(datum: &crate::BootDatum) -> Result<serde_json::Value> {
+    let mut entry = crate::cursor_server_json(datum)?;
+    if crate::mcp_http_only_url(datum).is_some() {
+        entry["type"] = serde_json::json!("http");
+    }
+    Ok(entry)
+}
+
+fn mcp_vscode(path: &str, servers: &[String], user: bool) -> Result<()> {
+    use anyhow::Context;
+
+    let settings_path = if user {
+        let settings_path = vscode_user_settings_path()?;
+        if !settings_path.parent().is_some_and(|dir| dir.is_dir()) {
+            anyhow::bail!(
+                "VS Code user settings directory not found for {} (is VS Code installed?)",
+                settings_path.display()
+            );
+        }
+        settings_path
+    } else {
+        let vscode_dir = std::path::Path::new(".vscode");
+        std::fs::create_dir_all(vscode_dir)
+            .with_context(|| format!("Failed to create {}", vscode_dir.display()))?;
+        vscode_dir.join("settings.json")
+    };
+
+    let sync_all = servers.iter().all(|s| s.trim().is_empty());
+    let names = if sync_all {
+        crate::known_mcp_server_names(path)
+    } else {
+        let names: Vec<&str> = servers
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        crate::check_mcp_servers_exist(path, &names)?;
+        names.into_iter().map(String::from).collect()
+    };
+
+    let mut entries = serde_json::Map::new();
+    for name in &names {
+        match crate::get_mcp_config(name, path).and_then(|datum| vscode_server_json(&datum)) {
+            Ok(entry) => {
+                entries.insert(name.clone(), entry);
+            }
+            Err(e) if sync_all => eprintln!("⚠️  Skipping {}: {}", name, e),
+            Err(e) => return Err(e),
+        }
+    }
+    if entries.is_empty() {
+        anyhow::bail!("No MCP servers to write to {}", settings_path.display());
+    }
+
+    let mut settings = if settings_path.exists() {
+        let content = std::fs::read_to_string(&settings_path)
+            .with_context(|| format!("Failed to read {}", settings_path.display()))?;
+        let settings = serde_json::from_str(&content).with_context(|| {
+            format!(
+                "Failed to parse {} (comments and trailing commas are not supported)",
+                settings_path.display()
+            )
+        })?;
+
+        let mut backup_name = settings_path.as_os_str().to_owned();
+        backup_name.push(".bak");
+        std::fs::write(&backup_name, &content).with_context(|| {
+            format!(
+                "Failed to back up {} to {}",
+                settings_path.display(),
+                std::path::Path::new(&backup_name).display()
+            )
+        })?;
+        settings
+    } else {
+        serde_json::Value::Null
+    };
+
+    let added: Vec<String> = entries.keys().cloned().collect();
+    patch_vscode_settings(&mut settings, entries)?;
+    write_json_atomic(&settings_path, &settings)?;
+
+    println!("✅ Added to VS Code: {}", added.join(", "));
+    println!("📝 {}", settings_path.display());
+    Ok(())
+}
+
+/// Expand `$VAR` / `${VAR}` in an env value; unset variables are left as written
+/// and returned so the caller can warn about them
+fn expand_env_value(value: &str) -> (String, Vec<String>) {
+    let mut missing = Vec::new();
+    let expanded = shellexpand::env_with_context_no_errors(value, |var: &str| {
+        let found = std::env::var(var).ok();
+        if found.is_none() {
+            missing.push(var.to_string());
+        }
+        found
+    });
+    (expanded.into_owned(), missing)
+}
+
+/// A server's env map with every value expanded, as passed to the spawned process
+fn expanded_env(
+    env: &std::collections::HashMap<String, String>,
+) -> std::collections::HashMap<String, String> {
+    env.iter()
+        .map(|(key, value)| (key.clone(), expand_env_value(value).0))
+        .collect()
+}
+
+/// `KEY=VALUE` lines (sorted, shell-quoted), optionally prefixed with `export`
+fn env_lines(env: &std::collections::BTreeMap<String, String>, export: bool) -> Vec<String> {
+    env.iter()
+        .map(|(key, value)| {
+            let quoted = shlex::try_quote(value)
+                .map(|q| q.into_owned())
+                .unwrap_or_else(|_| format!("'{}'", value.replace('\'', r"'\''")));
+            let prefix = if export { "export " } else { "" };
+            format!("{}{}={}", prefix, key, quoted)
+        })
+        .collect()
+}
+
+/// `file: field` for every other datum in `dir` whose depends_on or requires names
+/// the MCP server (`github` or `github.mcp`)
+fn mcp_references(dir: &std::path::Path, name: &str) -> Result<Vec<String>> {
+    use anyhow::Context;
+
+    let key = format!("{}.mcp", name);
+    let own_file = format!("{}.toml", key);
+    let names_server = |value: &toml::Value| {
+        value
+            .as_str()
+            .is_some_and(|value| value == name || value == key)
+    };
+
+    let mut references = Vec::new();
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|file| {
+            file.extension().is_some_and(|ext| ext == "toml")
+                && file.file_name().is_some_and(|f| f != own_file.as_str())
+        })
+        .collect();
+    entries.sort();
+
+    for file in entries {
+        let Some(b00t) = std::fs::read_to_string(&file)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+            .and_then(|mut table| table.remove("b00t"))
+        else {
+            continue;
+        };
+        let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+
+        if b00t
+            .get("depends_on")
+            .and_then(|deps| deps.as_array())
+            .is_some_and(|deps| deps.iter().any(names_server))
+        {
+            references.push(format!("{}: depends_on", file_name));
+        }
+        for (slot, requirement) in b00t
+            .get("requires")
+            .and_then(|requires| requires.as_table())
+            .into_iter()
+            .flatten()
+        {
+            let prefers = requirement
+                .get("prefer")
+                .and_then(|prefer| prefer.as_array())
+                .is_some_and(|prefer| prefer.iter().any(names_server));
+            let fallback = requirement.get("fallback").is_some_and(names_server);
+            if prefers || fallback {
+                references.push(format!("{}: requires.{}", file_name, slot));
+            }
+        }
+    }
+    Ok(references)
+}
+
+fn mcp_remove(path: &str, name: &str, force: bool) -> Result<()> {
+    use std::io::{BufRead, Write};
+
+    let Some(file) = crate::find_mcp_datum_file(name, path)? else {
+        crate::check_mcp_servers_exist(path, &[name])?;
+        anyhow::bail!("MCP server configuration not found: {}", name);
+    };
+    let dir = file.parent().unwrap_or(std::path::Path::new("."));
+
+    let references = mcp_references(dir, name)?;
+    if !references.is_empty() {
+        eprintln!("⚠️  {} is still referenced by (these will dangle):", name);
+        for reference in &references {
+            eprintln!("   - {}", reference);
+        }
+    }
+
+    if !force {
+        print!("🗑️  Delete {}? [y/N]: ", file.display());
+        std::io::stdout().flush()?;
+        let answer = std::io::stdin()
+            .lock()
+            .lines()
+            .next()
+            .transpose()?
+            .unwrap_or_default();
+        if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+            println!("❎ Not removed");
+            return Ok(());
+        }
+    }
+
+    crate::mcp_remove(name, path)
+}
+
+/// Launchers whose first positional argument names the actual server package
+const MCP_RUNNERS: &[&str] = &["npx", "uvx", "bunx", "pnpx", "pipx", "pnpm", "uv", "bun"];
+
+/// Text to look for in `ps` output to tell whether a stdio MCP server is running
+///
+/// 🤓 `npx -y @scope/server /tmp` shows up as `npm exec @scope/server /tmp` and
+/// `uv run python -m pkg.server` as `python -m pkg.server`, so match from the package
+/// onwards rather than on the launcher; `docker run ... image` matches on the image.
+fn mcp_process_pattern(command: &str, args: &[String]) -> String {
+    let program = std::path::Path::new(command)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .unwrap_or(command);
+    match program {
+        "docker" | "podman" => run_image(args).unwrap_or(program).to_string(),
+        runner if MCP_RUNNERS.contains(&runner) => {
+            let start = args.iter().position(|arg| {
+                !arg.starts_with('-') && !matches!(arg.as_str(), "run" | "dlx" | "exec" | "x")
+            });
+            match start {
+                Some(start) => args[start..].join(" "),
+                None => program.to_string(),
+            }
+        }
+        _ => std::iter::once(program)
+            .chain(args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+/// `docker run` flags that take the next argument as their value
+const DOCKER_VALUE_FLAGS: &[&str] = &[
+    "-e",
+    "--env",
+    "--env-file",
+    "-v",
+    "--volume",
+    "-p",
+    "--publish",
+    "--name",
+    "--network",
+    "--mount",
+    "-w",
+    "--workdir",
+    "-u",
+    "--user",
+    "--entrypoint",
+    "-l",
+    "--label",
+    "--pod",
+];
+
+/// The image of a `docker run [flags] image [command...]` argument list
+fn run_image(args: &[String]) -> Option<&str> {
+    let mut args = args.iter().skip_while(|arg| *arg == "run");
+    while let Some(arg) = args.next() {
+        if DOCKER_VALUE_FLAGS.contains(&arg.as_str()) {
+            args.next();
+        } else if !arg.starts_with('-') {
+            return Some(arg);
+        }
+    }
+    None
+}
+
+/// Pids of `ps -eo pid=,args=` lines whose command line contains `pattern`
+fn matching_pids(ps_output: &str, pattern: &str) -> Vec<u32> {
+    let own_pid = std::process::id();
+    ps_output
+        .lines()
+        .filter_map(|line| {
+            let (pid, args) = line.trim().split_once(char::is_whitespace)?;
+            let pid = pid.parse::<u32>().ok()?;
+            (pid != own_pid && args.contains(pattern)).then_some(pid)
+        })
+        .collect()
+}
+
+async fn mcp_status(path: &str, timeout: u64) -> Result<()> {
+    use anyhow::Context;
+
+    let servers = crate::mcp_list_structured(path, &[])?;
+    if servers.is_empty() {
+        println!("ℹ️  No MCP servers configured");
+        return Ok(());
+    }
+
+    let ps_output = std::process::Command::new("ps")
+        .args(["-eo", "pid=,args="])
+        .output()
+        .context("Failed to run ps")?;
+    let ps_output = String::from_utf8_lossy(&ps_output.stdout);
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(timeout))
+        .build()?;
+
+    for server in &servers {
+        if let Some(error) = &server.error {
+            println!("❌ {}: {}", server.name, error);
+        } else if server.command == "HTTP" {
+            let Some(url) = server.args.first() else {
+                println!("❓ {}: httpstream without url", server.name);
+                continue;
+            };
+            match client.get(url).send().await {
+                Ok(response) => {
+                    println!("🌐 {}: {} (HTTP {})", server.name, url, response.status())
+                }
+                Err(e) => println!("🔴 {}: {} unreachable ({})", server.name, url, e),
+            }
+        } else if server.command.is_empty() {
+            println!("❓ {}: no command", server.name);
+        } else {
+            let pattern = mcp_process_pattern(&server.command, &server.args);
+            let pids = matching_pids(&ps_output, &pattern);
+            if pids.is_empty() {
+                println!("⚪ {}: stopped", server.name);
+            } else {
+                let pids: Vec<String> = pids.iter().map(u32::to_string).collect();
+                println!("🟢 {}: running (pid {})", server.name, pids.join(", "));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn mcp_env(path: &str, name: &str, export: bool, json: bool) -> Result<()> {
+    let info = crate::get_mcp_server_info(name, path)?;
+
+    let mut env = std::collections::BTreeMap::new();
+    for (key, value) in &info.env {
+        let (expanded, missing) = expand_env_value(value);
+        for var in missing {
+            eprintln!("⚠️  {}: ${} is not set", key, var);
+        }
+        env.insert(key.clone(), expanded);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&env)?);
+    } else if env.is_empty() {
+        eprintln!("ℹ️  {} declares no environment variables", name);
+    } else {
+        for line in env_lines(&env, export) {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+/// Direction of a message relayed by `b00t mcp proxy`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ProxyDirection {
+    ToServer,
+    ToClient,
+}
+
+/// One `b00t mcp proxy` log line: `<timestamp> → server <message>`
+fn proxy_log_line(
+    timestamp: chrono::DateTime<chrono::Utc>,
+    direction: ProxyDirection,
+    message: &str,
+) -> String {
+    let arrow = match direction {
+        ProxyDirection::ToServer => "→ server",
+        ProxyDirection::ToClient => "← client",
+    };
+    format!(
+        "{} {} {}\n",
+        timestamp.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        arrow,
+        message.trim_end()
+    )
+}
+
+type ProxyLog = std::sync::Arc<std::sync::Mutex<Box<dyn std::io::Write + Send>>>;
+
+/// Copy lines from `reader` to `writer`, logging each one with `direction`
+async fn relay_lines<R, W>(
+    reader: R,
+    mut writer: W,
+    direction: ProxyDirection,
+    log: ProxyLog,
+) -> std::io::Result<()>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use std::io::Write;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        {
+            // 🤓 Logging must never break the relay, so write errors are ignored
+            let mut log = log.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let _ = log.write_all(proxy_log_line(chrono::Utc::now(), direction, &line).as_bytes());
+            let _ = log.flush();
+        }
+        writer.write_all(line.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        writer.flush().await?;
+    }
+    Ok(())
+}
+
+async fn mcp_proxy(path: &str, name: &str, log_file: Option<&std::path::Path>) -> Result<()> {
+    use anyhow::Context;
+    use tokio::process::Command;
+
+    let info = crate::get_mcp_server_info(name, path)?;
+    if info.command.is_empty() || info.command == "HTTP" {
+        anyhow::bail!("'{}' has no stdio command to proxy", name);
+    }
+
+    let sink: Box<dyn std::io::Write + Send> = match log_file {
+        Some(file) => Box::new(
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(file)
+                .with_context(|| format!("Failed to open {}", file.display()))?,
+        ),
+        None => Box::new(std::io::stderr()),
+    };
+    let log: ProxyLog = std::sync::Arc::new(std::sync::Mutex::new(sink));
+
+    let env = expanded_env(&info.env);
+
+    eprintln!(
+        "🔌 Proxying {} ({} {})",
+        name,
+        info.command,
+        info.args.join(" ")
+    );
+    let mut child = Command::new(&info.command)
+        .args(&info.args)
+        .envs(&env)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::inherit())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("Failed to start {}", info.command))?;
+
+    let server_stdin = child.stdin.take().context("Server stdin unavailable")?;
+    let server_stdout = child.stdout.take().context("Server stdout unavailable")?;
+
+    // 🤓 Client EOF drops the server's stdin, which is how stdio MCP servers learn to exit
+    let to_server = tokio::spawn(relay_lines(
+        tokio::io::stdin(),
+        server_stdin,
+        ProxyDirection::ToServer,
+        log.clone(),
+    ));
+    relay_lines(
+        server_stdout,
+        tokio::io::stdout(),
+        ProxyDirection::ToClient,
+        log,
+    )
+    .await
+    .context("Failed to relay server output")?;
+    to_server.abort();
+
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {}", name, status);
+    }
+    Ok(())
+}
+
+/// Fields for a new MCP datum (`b00t mcp add`)
+#[derive(Debug, Clone, Default)]
+pub struct McpAddSpec {
+    pub name: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub env: Vec<String>, // KEY=VAL
+    pub hint: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Build the MCP datum for `spec`, checking it survives a TOML round-trip
+pub fn build_mcp_datum(spec: &McpAddSpec) -> Result<crate::BootDatum> {
+    if spec.name.trim().is_empty() {
+        anyhow::bail!("MCP server name is required");
+    }
+    if spec.command.trim().is_empty() {
+        anyhow::bail!("MCP server command is required");
+    }
+
+    let mut env = serde_json::Map::new();
+    for pair in &spec.env {
+        let (key, value) = pair
+            .split_once('=')
+            .filter(|(key, _)| !key.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Invalid --env '{}', expected KEY=VAL", pair))?;
+        env.insert(
+            key.to_string(),
+            serde_json::Value::String(value.to_string()),
+        );
+    }
+
+    let mut json = serde_json::json!({
+        "name": spec.name.trim(),
+        "command": spec.command.trim(),
+        "args": spec.args,
+    });
+    if !env.is_empty() {
+        json["env"] = serde_json::Value::Object(env);
+    }
+
+    let mut datum = crate::normalize_mcp_json(&json.to_string(), false)?;
+    if let Some(hint) = spec.hint.as_ref().filter(|h| !h.trim().is_empty()) {
+        datum.hint = hint.trim().to_string();
+    }
+    datum.description = spec
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::to_string);
+
+    // 🤓 What we write must be exactly what the datum loader reads back
+    let config = crate::UnifiedConfig {
+        b00t: datum.clone(),
+        env: None,
+    };
+    let toml_content = toml::to_string(&config)?;
+    let reparsed: crate::UnifiedConfig = toml::from_str(&toml_content)?;
+    if reparsed.b00t != datum {
+        anyhow::bail!(
+            "Generated MCP datum for '{}' does not round-trip",
+            spec.name
+        );
+    }
+
+    Ok(datum)
+}
+
+/// Scaffold `<name>.mcp.toml` in the datum directory
+fn mcp_add(spec: &McpAddSpec, path: &str) -> Result<()> {
+    let datum = build_mcp_datum(spec)?;
+
+    if !crate::check_command_available(&spec.command) {
+        anyhow::bail!(
+            "Command '{}' not found in PATH. Install it first or pass a full path.",
+            spec.command
+        );
+    }
+
+    let target = crate::get_expanded_path(path)?.join(format!("{}.mcp.toml", datum.name));
+    if target.exists() {
+        anyhow::bail!(
+            "MCP datum already exists: {} (remove it first with: b00t-cli mcp remove {})",
+            target.display(),
+            datum.name
+        );
+    }
+
+    crate::create_mcp_toml_config(&datum, path)?;
+    println!("✅ MCP server '{}' added", datum.name);
+    println!(
+        "💡 Install it with: b00t-cli mcp install {} claudecode",
+        datum.name
+    );
+    Ok(())
+}
+
+/// Result of `b00t mcp test`
+#[derive(Debug, PartialEq)]
+pub enum McpTestOutcome {
+    /// Valid initialize result (with `serverInfo` when the server sent one)
+    Working(Option<serde_json::Value>),
+    NoResponse,
+    Invalid(String),
+}
+
+/// Spawn a stdio MCP server, send `initialize` and classify the first reply line
+pub async fn test_mcp_server(
+    info: &crate::McpServerInfo,
+    timeout: std::time::Duration,
+) -> Result<McpTestOutcome> {
+    use anyhow::Context;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::process::Command;
+
+    let mut child = Command::new(&info.command)
+        .args(&info.args)
+        .envs(expanded_env(&info.env))
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("Failed to start {}", info.command))?;
+
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": {"name": "b00t", "version": b00t_c0re_lib::version::VERSION},
+        },
+    });
+
+    let mut stdin = child.stdin.take().context("Server stdin unavailable")?;
+    let stdout = child.stdout.take().context("Server stdout unavailable")?;
+
+    let exchange = async {
+        stdin.write_all(format!("{}\n", request).as_bytes()).await?;
+        stdin.flush().await?;
+
+        let mut lines = BufReader::new(stdout).lines();
+        while let Some(line) = lines.next_line().await? {
+            if !line.trim().is_empty() {
+                return Ok::<_, std::io::Error>(Some(line));
+            }
+        }
+        Ok(None)
+    };
+
+    let reply = tokio::time::timeout(timeout, exchange).await;
+    let _ = child.kill().await;
+
+    let line = match reply {
+        Err(_) | Ok(Ok(None)) | Ok(Err(_)) => return Ok(McpTestOutcome::NoResponse),
+        Ok(Ok(Some(line))) => line,
+    };
+
+    Ok(classify_initialize_response(&line))
+}
+
+fn classify_initialize_response(line: &str) -> McpTestOutcome {
+    let response: serde_json::Value = match serde_json::from_str(line) {
+        Ok(value) => value,
+        Err(e) => return McpTestOutcome::Invalid(format!("not JSON ({})", e)),
+    };
+
+    if response.get("jsonrpc").and_then(|v| v.as_str()) != Some("2.0") {
+        return McpTestOutcome::Invalid("missing jsonrpc \"2.0\"".to_string());
+    }
+    if response.get("id") != Some(&serde_json::json!(1)) {
+        return McpTestOutcome::Invalid("response id does not match request".to_string());
+    }
+    if let Some(error) = response.get("error") {
+        return McpTestOutcome::Invalid(format!("error response: {}", error));
+    }
+    match response.get("result") {
+        Some(result) if result.is_object() => {
+            McpTestOutcome::Working(result.get("serverInfo").cloned())
+        }
+        _ => McpTestOutcome::Invalid("missing result object".to_string()),
+    }
+}
+
+/// Guided prompts for `b00t mcp add --interactive` (flags become defaults)
+fn prompt_mcp_spec(defaults: McpAddSpec) -> Result<McpAddSpec> {
+    use std::io::{self, BufRead, Write};
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut ask = |label: &str, default: &str| -> Result<String> {
+        if default.is_empty() {
+            print!("{}: ", label);
+        } else {
+            print!("{} [{}]: ", label, default);
+        }
+        io::stdout().flush()?;
+        let answer = lines.next().transpose()?.unwrap_or_default();
+        let answer = answer.trim();
+        Ok(if answer.is_empty() {
+            default.to_string()
+        } else {
+            answer.to_string()
+        })
+    };
+
+    let name = ask("🏷️  Server name", &defaults.name)?;
+    let command = ask("⚙️  Command (npx, uvx, docker, ...)", &defaults.command)?;
+    let args = ask("📎 Arguments (comma-separated)", &defaults.args.join(","))?;
+    let env = ask(
+        "🔑 Environment (KEY=VAL, comma-separated)",
+        &defaults.env.join(","),
+    )?;
+    let hint = ask("💡 Hint", defaults.hint.as_deref().unwrap_or(""))?;
+    let description = ask(
+        "📝 Description (what the server does)",
+        defaults.description.as_deref().unwrap_or(""),
+    )?;
+
+    let split = |value: &str| -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+
+    Ok(McpAddSpec {
+        name,
+        command,
+        args: split(&args),
+        env: split(&env),
+        hint: Some(hint).filter(|h| !h.is_empty()),
+        description: Some(description).filter(|d| !d.is_empty()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_mcp_references_finds_depends_on_and_requires() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |file: &str, content: &str| std::fs::write(dir.path().join(file), content).unwrap();
+        write(
+            "github.mcp.toml",
+            "[b00t]\nname = \"github\"\ntype = \"mcp\"\nhint = \"h\"\ndepends_on = [\"github.mcp\"]\n",
+        );
+        write(
+            "dev.stack.toml",
+            "[b00t]\nname = \"dev\"\ntype = \"stack\"\nhint = \"h\"\ndepends_on = [\"qdrant.docker\", \"github.mcp\"]\n",
+        );
+        write(
+            "agent.mcp.toml",
+            "[b00t]\nname = \"agent\"\ntype = \"mcp\"\nhint = \"h\"\n\n[b00t.requires.vcs]\ncapability = \"vcs\"\nprefer = [\"gitlab\", \"github\"]\n",
+        );
+        write(
+            "other.mcp.toml",
+            "[b00t]\nname = \"other\"\ntype = \"mcp\"\nhint = \"h\"\ndepends_on = [\"github-enterprise.mcp\"]\n",
+        );
+        write("broken.mcp.toml", "not = [toml");
+
+        assert_eq!(
+            mcp_references(dir.path(), "github").unwrap(),
+            vec!["agent.mcp.toml: requires.vcs", "dev.stack.toml: depends_on"]
+        );
+        assert!(mcp_references(dir.path(), "gitlab-ci").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_mcp_process_pattern_and_matching_pids() {
+        let args = |args: &[&str]| args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            mcp_process_pattern(
+                "npx",
+                &args(&["-y", "@modelcontextprotocol/server-filesystem", "/tmp"])
+            ),
+            "@modelcontextprotocol/server-filesystem /tmp"
+        );
+        assert_eq!(
+            mcp_process_pattern(
+                "uv",
+                &args(&["run", "python", "-m", "b00t_grok_guru.server"])
+            ),
+            "python -m b00t_grok_guru.server"
+        );
+        assert_eq!(
+            mcp_process_pattern(
+                "/usr/bin/docker",
+                &args(&[
+                    "run",
+                    "-i",
+                    "--rm",
+                    "-e",
+                    "GITHUB_TOKEN",
+                    "ghcr.io/github/github-mcp-server",
+                    "stdio"
+                ])
+            ),
+            "ghcr.io/github/github-mcp-server"
+        );
+        assert_eq!(
+            mcp_process_pattern("/usr/local/bin/b00t-mcp", &args(&["--stdio"])),
+            "b00t-mcp --stdio"
+        );
+
+        let ps = "    1 /sbin/init\n  4242 npm exec @modelcontextprotocol/server-filesystem /tmp\n  4243 node /home/u/.npm/_npx/x/node_modules/.bin/mcp-server-filesystem /tmp\n";
+        assert_eq!(
+            matching_pids(ps, "@modelcontextprotocol/server-filesystem /tmp"),
+            vec![4242]
+        );
+        assert!(matching_pids(ps, "python -m b00t_grok_guru.server").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relay_lines_copies_and_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("proxy.log");
+        let log: ProxyLog = std::sync::Arc::new(std::sync::Mutex::new(Box::new(
+            std::fs::File::create(&log_path).unwrap(),
+        )));
+
+        let input = b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n\n".as_slice();
+        let mut output = Vec::new();
+        relay_lines(input, &mut output, ProxyDirection::ToServer, log)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n\n"
+        );
+        let logged = std::fs::read_to_string(&log_path).unwrap();
+        let first = logged.lines().next().unwrap();
+        assert!(first.ends_with("→ server {\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}"));
+    }
+
+    #[test]
+    fn test_proxy_log_line_format() {
+        let timestamp = chrono::DateTime::parse_from_rfc3339("2025-01-02T03:04:05.678Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(
+            proxy_log_line(timestamp, ProxyDirection::ToClient, "{\"id\":1}\r"),
+            "2025-01-02T03:04:05.678Z ← client {\"id\":1}\n"
+        );
+    }
+
+    #[test]
+    fn test_claude_desktop_sync_skips_http_only_servers() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("zz-remote-only.mcp.toml"),
+            "[b00t]\nname = \"zz-remote-only\"\ntype = \"mcp\"\nhint = \"h\"\n\n[b00t.mcp.httpstream]\nurl = \"https://example.com/mcp\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("zz-local.mcp.toml"),
+            "[b00t]\nname = \"zz-local\"\ntype = \"mcp\"\nhint = \"h\"\ncommand = \"echo\"\n",
+        )
+        .unwrap();
+        let path = dir.path().to_str().unwrap();
+        let config_path = dir.path().join("claude_desktop_config.json");
+
+        claude_desktop(path, &[], &[], true, Some(&config_path)).unwrap();
+        let config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["mcpServers"]["zz-local"]["command"], "echo");
+        assert!(config["mcpServers"].get("zz-remote-only").is_none());
+
+        let added = ["zz-remote-only".to_string()];
+        assert!(claude_desktop(path, &added, &[], false, Some(&config_path)).is_err());
+    }
+
+    #[test]
+    fn test_patch_claude_desktop_config() {
+        let mut config = serde_json::json!({
+            "globalShortcut": "Ctrl+Space",
+            "mcpServers": {
+                "old": {"command": "npx", "args": []},
+                "keep": {"command": "uvx", "args": []}
+            }
+        });
+        let new_servers = serde_json::json!({"github": {"command": "docker", "args": ["run"]}});
+        let new_servers = new_servers.as_object().unwrap();
+
+        patch_claude_desktop_config(
+            &mut config,
+            new_servers.clone(),
+            &["old".to_string()],
+            false,
+        )
+        .unwrap();
+        let servers = config["mcpServers"].as_object().unwrap();
+        assert_eq!(servers.len(), 2);
+        assert!(servers.contains_key("keep") && servers.contains_key("github"));
+        assert_eq!(config["globalShortcut"], "Ctrl+Space");
+
+        patch_claude_desktop_config(&mut config, new_servers.clone(), &[], true).unwrap();
+        assert_eq!(
+            config["mcpServers"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["github"]
+        );
+
+        let mut empty = serde_json::Value::Null;
+        patch_claude_desktop_config(&mut empty, new_servers.clone(), &[], false).unwrap();
+        assert_eq!(empty["mcpServers"]["github"]["command"], "docker");
+    }
+
+    #[test]
+    fn test_vscode_server_json_uses_http_for_remote_servers() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("remote.mcp.toml"),
+            "[b00t]\nname = \"remote\"\ntype = \"mcp\"\nhint = \"h\"\n\n[b00t.mcp.httpstream]\nurl = \"https://example.com/mcp\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("local.mcp.toml"),
+            "[b00t]\nname = \"local\"\ntype = \"mcp\"\nhint = \"h\"\ncommand = \"echo\"\nargs = [\"hi\"]\n",
+        )
+        .unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let remote = vscode_server_json(&crate::get_mcp_config("remote", path).unwrap()).unwrap();
+        assert_eq!(
+            remote,
+            serde_json::json!({"type": "http", "url": "https://example.com/mcp"})
+        );
+        let local = vscode_server_json(&crate::get_mcp_config("local", path).unwrap()).unwrap();
+        assert_eq!(local["type"], "stdio");
+        assert_eq!(local["command"], "echo");
+    }
+
+    #[test]
+    fn test_patch_vscode_settings() {
+        let mut settings = serde_json::json!({
+            "editor.fontSize": 14,
+            "mcp": {"servers": {"keep": {"type": "stdio", "command": "uvx"}}}
+        });
+        let new_servers = serde_json::json!({"github": {"type": "stdio", "command": "docker"}});
+
+        patch_vscode_settings(&mut settings, new_servers.as_object().unwrap().clone()).unwrap();
+        let servers = settings["mcp"]["servers"].as_object().unwrap();
+        assert_eq!(servers.len(), 2);
+        assert_eq!(servers["github"]["command"], "docker");
+        assert_eq!(settings["editor.fontSize"], 14);
+
+        let mut empty = serde_json::Value::Null;
+        patch_vscode_settings(&mut empty, new_servers.as_object().unwrap().clone()).unwrap();
+        assert_eq!(empty["mcp"]["servers"]["github"]["type"], "stdio");
+
+        assert!(patch_vscode_settings(&mut serde_json::json!([]), Default::default()).is_err());
+    }
+
+    #[test]
+    fn test_write_json_atomic_replaces_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("claude_desktop_config.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        write_json_atomic(&path, &serde_json::json!({"mcpServers": {}})).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written, serde_json::json!({"mcpServers": {}}));
+        assert!(!dir.path().join("claude_desktop_config.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_env_lines_expand_and_quote() {
+        unsafe {
+            std::env::set_var("B00T_TEST_MCP_TOKEN", "tok en");
+            std::env::remove_var("B00T_TEST_MCP_UNSET");
+        }
+
+        let (value, missing) = expand_env_value("${B00T_TEST_MCP_TOKEN}");
+        assert_eq!(value, "tok en");
+        assert!(missing.is_empty());
+
+        let (value, missing) = expand_env_value("$B00T_TEST_MCP_UNSET");
+        assert_eq!(value, "$B00T_TEST_MCP_UNSET");
+        assert_eq!(missing, vec!["B00T_TEST_MCP_UNSET"]);
+
+        let env = std::collections::BTreeMap::from([
+            ("TOKEN".to_string(), "tok en".to_string()),
+            ("LEVEL".to_string(), "ERROR".to_string()),
+        ]);
+        assert_eq!(
+            env_lines(&env, false),
+            vec!["LEVEL=ERROR", "TOKEN='tok en'"]
+        );
+        assert_eq!(
+            env_lines(&env, true),
+            vec!["export LEVEL=ERROR", "export TOKEN='tok en'"]
+        );
+    }
+
+    fn fake_server(script: &str) -> crate::McpServerInfo {
+        crate::McpServerInfo {
+            name: "fake".to_string(),
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            env: std::collections::HashMap::new(),
+            hint: None,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mcp_server_outcomes() {
+        let timeout = std::time::Duration::from_secs(5);
+
+        let working = fake_server(
+            r#"read line; echo '{"jsonrpc":"2.0","id":1,"result":{"serverInfo":{"name":"fake"}}}'"#,
+        );
+        assert_eq!(
+            test_mcp_server(&working, timeout).await.unwrap(),
+            McpTestOutcome::Working(Some(serde_json::json!({"name": "fake"})))
+        );
+
+        let invalid = fake_server("read line; echo 'hello'");
+        assert!(matches!(
+            test_mcp_server(&invalid, timeout).await.unwrap(),
+            McpTestOutcome::Invalid(_)
+        ));
+
+        let silent = fake_server("sleep 10");
+        assert_eq!(
+            test_mcp_server(&silent, std::time::Duration::from_millis(200))
+                .await
+                .unwrap(),
+            McpTestOutcome::NoResponse
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mcp_server_expands_env() {
+        unsafe {
+            std::env::set_var("B00T_TEST_MCP_SERVER_TOKEN", "secret");
+        }
+        let mut server = fake_server(
+            r#"read line; [ "$TOKEN" = secret ] && echo '{"jsonrpc":"2.0","id":1,"result":{}}'"#,
+        );
+        server.env.insert(
+            "TOKEN".to_string(),
+            "${B00T_TEST_MCP_SERVER_TOKEN}".to_string(),
+        );
+
+        assert_eq!(
+            test_mcp_server(&server, std::time::Duration::from_secs(5))
+                .await
+                .unwrap(),
+            McpTestOutcome::Working(None)
+        );
+    }
+
+    #[test]
+    fn test_build_mcp_datum_round_trips() {
+        let spec = McpAddSpec {
+            name: "brave-search".to_string(),
+            command: "npx".to_string(),
+            args: vec![
+                "-y".to_string(),
+                "@modelcontextprotocol/server-brave-search".to_string(),
+            ],
+            env: vec!["BRAVE_API_KEY=${BRAVE_API_KEY}".to_string()],
+            hint: Some("Web search".to_string()),
+            description: Some("Brave Search API for web and news results".to_string()),
+        };
+
+        let datum = build_mcp_datum(&spec).unwrap();
+        assert_eq!(datum.name, "brave-search");
+        assert_eq!(datum.hint, "Web search");
+        assert_eq!(
+            datum.description.as_deref(),
+            Some("Brave Search API for web and news results")
+        );
+        assert_eq!(
+            datum.env.unwrap().get("BRAVE_API_KEY").map(String::as_str),
+            Some("${BRAVE_API_KEY}")
+        );
+        let stdio = datum.mcp.unwrap().stdio.unwrap();
+        assert_eq!(stdio[0]["command"], "npx");
+    }
+
+    #[test]
+    fn test_build_mcp_datum_rejects_bad_env() {
+        let spec = McpAddSpec {
+            name: "x".to_string(),
+            command: "npx".to_string(),
+            env: vec!["NO_EQUALS".to_string()],
+            ..Default::default()
+        };
+        assert!(build_mcp_datum(&spec).is_err());
+    }
+
     #[test]
     fn test_mcp_commands_exist() {
         // Test with JSON format
@@ -5,6 +5,7 @@ pub mod bootstrap;
 pub mod budget;
 pub mod chat;
 pub mod cli_cmd;
+pub mod config;
 pub mod datum;
 pub mod grok;
 pub mod init;
@@ -14,6 +15,7 @@ pub mod k8s;
 pub mod learn;
 pub mod mcp;
 pub mod model;
+pub mod orchestrator;
 pub mod script;
 pub mod session;
 pub mod stack;
@@ -27,6 +29,7 @@ pub use bootstrap::BootstrapCommands;
 pub use budget::BudgetCommands;
 pub use chat::ChatCommands;
 pub use cli_cmd::CliCommands;
+pub use config::ConfigCommands;
 pub use datum::DatumCommands;
 pub use grok::GrokCommands;
 pub use init::InitCommands;
@@ -35,6 +38,7 @@ pub use job::JobCommands;
 pub use k8s::K8sCommands;
 pub use mcp::McpCommands;
 pub use model::ModelCommands;
+pub use orchestrator::OrchestratorCommands;
 pub use session::SessionCommands;
 pub use stack::StackCommands;
 pub use whatismy::WhatismyCommands;
@@ -25,6 +25,11 @@ pub enum ModelCommands {
     List {
         #[clap(long, help = "Emit JSON instead of human-readable output")]
         json: bool,
+        #[clap(
+            long,
+            help = "Only list models from this provider (e.g. anthropic, ollama)"
+        )]
+        provider: Option<String>,
     },
     #[clap(
         about = "Show metadata for an AI model datum",
@@ -117,7 +122,9 @@ pub enum ModelCommands {
 impl ModelCommands {
     pub fn execute(&self, path: &str) -> Result<()> {
         match self {
-            ModelCommands::List { json } => list_models_cmd(path, *json),
+            ModelCommands::List { json, provider } => {
+                list_models_cmd(path, *json, provider.as_deref())
+            }
             ModelCommands::Info { name, json } => info_cmd(path, name.as_deref(), *json),
             ModelCommands::Env { name, plain, json } => {
                 env_cmd(path, name.as_deref(), *plain, *json)
@@ -156,8 +163,8 @@ impl ModelCommands {
     }
 }
 
-fn list_models_cmd(path: &str, json_output: bool) -> Result<()> {
-    let models = list_models(path)?;
+fn list_models_cmd(path: &str, json_output: bool, provider: Option<&str>) -> Result<()> {
+    let models = list_models(path, provider)?;
     if json_output {
         println!("{}", serde_json::to_string_pretty(&models)?);
         return Ok(());
@@ -0,0 +1,204 @@
+use crate::orchestrator::Orchestrator;
+pub use crate::orchestrator::ServiceState;
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+
+#[derive(Parser, Debug)]
+pub enum OrchestratorCommands {
+    #[clap(
+        about = "Show every docker datum and its container state",
+        long_about = "Show every docker datum the orchestrator knows about, like `docker ps`.\n\nStatus is running, stopped (container exists) or missing (never created).\n\nExamples:\n  b00t orchestrator status\n  b00t orchestrator status --json"
+    )]
+    Status {
+        #[clap(
+            long,
+            help = "Output a JSON array of {name, type, status, image, ports}"
+        )]
+        json: bool,
+    },
+    #[clap(
+        about = "Print every loaded datum as JSON",
+        long_about = "Serialize the orchestrator's loaded datum state (keyed by <name>.<type>, sorted) to JSON, for debugging, diffing before/after runs or feeding to an LLM as context.\n\nExamples:\n  b00t orchestrator snapshot > state.json\n  diff <(jq . before.json) <(b00t orchestrator snapshot)"
+    )]
+    Snapshot,
+    #[clap(
+        about = "Show the container logs of a docker datum",
+        long_about = "Resolve a docker datum and run `docker logs` (or podman) on its container, streaming stdout/stderr to the terminal.\n\nFails when the container is not running.\n\nExamples:\n  b00t orchestrator logs qdrant\n  b00t orchestrator logs qdrant.docker --tail 100\n  b00t orchestrator logs postgres --follow"
+    )]
+    Logs {
+        #[clap(help = "Datum key or name (e.g. qdrant or qdrant.docker)")]
+        datum_key: String,
+        #[clap(short, long, help = "Keep streaming new log lines")]
+        follow: bool,
+        #[clap(long, help = "Only show the last N lines")]
+        tail: Option<u32>,
+    },
+}
+
+impl ServiceState {
+    fn icon(&self) -> &'static str {
+        match self {
+            ServiceState::Running => "🟢",
+            ServiceState::Stopped => "🟡",
+            ServiceState::Missing => "⚪",
+        }
+    }
+}
+
+/// One row of `orchestrator status`
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ServiceStatus {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub datum_type: String,
+    pub status: ServiceState,
+    pub image: Option<String>,
+    pub ports: Vec<String>,
+    pub description: Option<String>,
+}
+
+pub fn handle_orchestrator_command(path: &str, command: &OrchestratorCommands) -> Result<()> {
+    match command {
+        OrchestratorCommands::Status { json } => handle_status(path, *json),
+        OrchestratorCommands::Snapshot => {
+            println!("{}", Orchestrator::new(path)?.snapshot()?);
+            Ok(())
+        }
+        OrchestratorCommands::Logs {
+            datum_key,
+            follow,
+            tail,
+        } => Orchestrator::new(path)?.stream_logs(datum_key, *follow, *tail),
+    }
+}
+
+/// Status of every docker datum, sorted by name
+pub fn service_statuses(orchestrator: &Orchestrator) -> Result<Vec<ServiceStatus>> {
+    Ok(orchestrator
+        .docker_states()?
+        .into_iter()
+        .map(|(key, status)| {
+            let datum = &orchestrator.datums()[key];
+            ServiceStatus {
+                name: datum.name.clone(),
+                datum_type: "docker".to_string(),
+                status,
+                image: datum.image.clone(),
+                ports: published_ports(datum.docker_args.as_deref().unwrap_or_default()),
+                description: datum.description.clone(),
+            }
+        })
+        .collect())
+}
+
+/// Port mappings from `-p`/`--publish` in a datum's `docker_args`
+fn published_ports(docker_args: &[String]) -> Vec<String> {
+    Orchestrator::split_published_ports(docker_args).0
+}
+
+pub(crate) fn handle_status(path: &str, json: bool) -> Result<()> {
+    let orchestrator = Orchestrator::new(path)?;
+    // 🤓 Fail once up front instead of once per datum when docker/podman is missing
+    orchestrator.get_container_runtime()?;
+    let statuses = service_statuses(&orchestrator)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&statuses)?);
+        return Ok(());
+    }
+
+    if statuses.is_empty() {
+        println!("ℹ️  No docker datums found in {}", path);
+        return Ok(());
+    }
+
+    let name_width = statuses.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    let image_width = statuses
+        .iter()
+        .map(|s| s.image.as_deref().unwrap_or("-").len())
+        .max()
+        .unwrap_or(0);
+
+    let ports: Vec<String> = statuses
+        .iter()
+        .map(|s| {
+            if s.ports.is_empty() {
+                "-".to_string()
+            } else {
+                s.ports.join(", ")
+            }
+        })
+        .collect();
+    let ports_width = ports.iter().map(String::len).max().unwrap_or(0).max(5);
+
+    println!(
+        "    {:name_width$}  {:6}  {:7}  {:image_width$}  {:ports_width$}  DESCRIPTION",
+        "NAME", "TYPE", "STATUS", "IMAGE", "PORTS"
+    );
+    for (status, ports) in statuses.iter().zip(&ports) {
+        println!(
+            "{}  {:name_width$}  {:6}  {:7}  {:image_width$}  {:ports_width$}  {}",
+            status.status.icon(),
+            status.name,
+            status.datum_type,
+            status.status,
+            status.image.as_deref().unwrap_or("-"),
+            ports,
+            status.description.as_deref().unwrap_or("-")
+        );
+    }
+
+    let running = statuses
+        .iter()
+        .filter(|s| s.status == ServiceState::Running)
+        .count();
+    println!("\n📊 {}/{} running", running, statuses.len());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_published_ports() {
+        let args: Vec<String> = [
+            "-p",
+            "6333:6333",
+            "--publish=6334:6334",
+            "-v",
+            "qdrant_storage:/qdrant/storage",
+            "--publish",
+            "127.0.0.1:8080:80",
+            "-p",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            published_ports(&args),
+            vec!["6333:6333", "6334:6334", "127.0.0.1:8080:80"]
+        );
+        assert!(published_ports(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_service_status_json_shape() {
+        let status = ServiceStatus {
+            name: "qdrant".to_string(),
+            datum_type: "docker".to_string(),
+            status: ServiceState::Missing,
+            image: Some("qdrant/qdrant:latest".to_string()),
+            ports: vec!["6333:6333".to_string()],
+            description: None,
+        };
+
+        let value = serde_json::to_value(&status).unwrap();
+        assert_eq!(value["type"], "docker");
+        assert_eq!(value["status"], "missing");
+        assert_eq!(value["ports"][0], "6333:6333");
+        assert!(value["description"].is_null());
+    }
+}
@@ -3,6 +3,7 @@ use clap::Parser;
 use std::collections::HashMap;
 
 use crate::datum_stack::StackDatum;
+use crate::orchestrator::Orchestrator;
 use crate::traits::DatumCrdDisplay;
 use crate::{BootDatum, get_expanded_path};
 
@@ -46,6 +47,28 @@ pub enum StackCommands {
         #[clap(long, help = "Show what would be installed without installing")]
         dry_run: bool,
     },
+    #[clap(
+        about = "Start every service of a stack",
+        long_about = "Start the stack's services (members) and their dependencies in dependency order.\n\nEach service waits up to its datum's start_timeout_secs (default 6s) to become ready.\n\nExamples:\n  b00t-cli stack up rag-stack\n  b00t-cli stack up rag-stack --max-start-timeout 300"
+    )]
+    Up {
+        #[clap(help = "Stack name")]
+        name: String,
+        #[clap(
+            long,
+            value_name = "SECS",
+            help = "Cap on how long any service may take to start"
+        )]
+        max_start_timeout: Option<u64>,
+    },
+    #[clap(
+        about = "Stop every service of a stack",
+        long_about = "Stop the stack's running services in reverse dependency order.\n\nExamples:\n  b00t-cli stack down rag-stack"
+    )]
+    Down {
+        #[clap(help = "Stack name")]
+        name: String,
+    },
     #[clap(
         about = "Generate docker-compose.yml from stack",
         long_about = "Generate a docker-compose.yml file from a Docker-based stack.\n\nExamples:\n  b00t-cli stack compose postgres-dev-stack\n  b00t-cli stack compose postgres-dev-stack --output docker-compose.yml"
@@ -89,8 +112,22 @@ pub enum StackCommands {
 }
 
 impl StackCommands {
+    pub async fn execute_async(&self, path: &str) -> Result<()> {
+        match self {
+            StackCommands::Up {
+                name,
+                max_start_timeout,
+            } => stack_up(name, path, *max_start_timeout).await,
+            StackCommands::Down { name } => stack_down(name, path).await,
+            _ => self.execute(path),
+        }
+    }
+
     pub fn execute(&self, path: &str) -> Result<()> {
         match self {
+            StackCommands::Up { .. } | StackCommands::Down { .. } => {
+                anyhow::bail!("stack up/down need the async runtime (use execute_async)")
+            }
             StackCommands::List { json } => list_stacks(path, *json),
             StackCommands::Show { name, json } => show_stack(name, path, *json),
             StackCommands::Validate { name, all } => {
@@ -331,6 +368,59 @@ fn install_stack(name: &str, path: &str, dry_run: bool) -> Result<()> {
     Ok(())
 }
 
+/// Load the orchestrator and return the key of stack `name`
+fn stack_orchestrator(name: &str, path: &str) -> Result<(Orchestrator, String)> {
+    let orchestrator = Orchestrator::new(path)?;
+    let stack_key = format!("{}.stack", name);
+
+    if !orchestrator.datums().contains_key(&stack_key) {
+        anyhow::bail!("Stack '{}' not found in {}", name, path);
+    }
+
+    Ok((orchestrator, stack_key))
+}
+
+/// Start a stack's services in dependency order
+async fn stack_up(name: &str, path: &str, max_start_timeout: Option<u64>) -> Result<()> {
+    let (mut orchestrator, stack_key) = stack_orchestrator(name, path)?;
+    if let Some(secs) = max_start_timeout {
+        orchestrator = orchestrator.with_max_start_timeout(secs);
+    }
+
+    println!("🚀 Starting stack '{}'...", name);
+    let started = orchestrator.ensure_dependencies(&stack_key).await?;
+
+    if started.is_empty() {
+        println!("✅ Stack '{}' already up", name);
+    } else {
+        for key in &started {
+            println!("   ✅ {}", key);
+        }
+        println!("✅ Stack '{}' up ({} started)", name, started.len());
+    }
+
+    Ok(())
+}
+
+/// Stop a stack's running services in reverse dependency order
+async fn stack_down(name: &str, path: &str) -> Result<()> {
+    let (orchestrator, stack_key) = stack_orchestrator(name, path)?;
+
+    println!("🛑 Stopping stack '{}'...", name);
+    let stopped = orchestrator.stop_dependencies(&stack_key).await?;
+
+    if stopped.is_empty() {
+        println!("ℹ️  No running services in stack '{}'", name);
+    } else {
+        for key in &stopped {
+            println!("   🛑 {}", key);
+        }
+        println!("✅ Stack '{}' down ({} stopped)", name, stopped.len());
+    }
+
+    Ok(())
+}
+
 /// Generate docker-compose.yml from stack
 fn generate_compose(name: &str, path: &str, output_file: Option<&str>) -> Result<()> {
     let stack_path = get_expanded_path(path)?.join(format!("{}.stack.toml", name));
@@ -687,8 +777,8 @@ fn load_all_datums(path: &str) -> Result<HashMap<String, BootDatum>> {
                             let datum_type = datum
                                 .datum_type
                                 .as_ref()
-                                .map(|t| format!("{:?}", t).to_lowercase())
-                                .unwrap_or_else(|| "unknown".to_string());
+                                .map(|t| t.type_slug())
+                                .unwrap_or("unknown");
                             let key = format!("{}.{}", datum.name, datum_type);
                             datums.insert(key, datum);
                         }
@@ -2,15 +2,17 @@ use crate::traits::*;
 use crate::{BootDatum, check_command_available, get_expanded_path};
 use anyhow::{Context, Result};
 use b00t_c0re_lib::datum_ai_model::{AiModelDatum, ModelProvider};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-#[derive(Deserialize)]
-struct AiModelConfig {
+datum_struct! {
+#[derive(Deserialize, Serialize)]
+pub(crate) struct AiModelConfig, strict StrictAiModelConfig {
     pub b00t: BootDatum,
     pub ai_model: AiModelDatum,
 }
+}
 
 /// Datum wrapper that binds BootDatum metadata with AI model configuration.
 pub struct AiModelDatumEntry {
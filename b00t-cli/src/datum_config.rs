@@ -294,6 +294,15 @@ impl ConfigDatum {
             entangled_apis: None,
             entangled_docker: None,
             entangled_k8s: None,
+            network: None,
+            description: None,
+            pod_name: None,
+            restart_policy: None,
+            use_compose: None,
+            compose_file: None,
+            start_timeout_secs: None,
+            health_url: None,
+            health_timeout_secs: None,
         };
 
         Ok(Self { datum, config })
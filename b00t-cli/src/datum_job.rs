@@ -445,43 +445,7 @@ steps = [
         ];
 
         let datum = JobDatum {
-            datum: crate::BootDatum {
-                name: "test".to_string(),
-                datum_type: Some(crate::DatumType::Job),
-                hint: "Test job".to_string(),
-                desires: None,
-                install: None,
-                update: None,
-                version: None,
-                version_regex: None,
-                command: None,
-                args: None,
-                vsix_id: None,
-                script: None,
-                image: None,
-                docker_args: None,
-                oci_uri: None,
-                resource_path: None,
-                chart_path: None,
-                namespace: None,
-                values_file: None,
-                keywords: None,
-                package_name: None,
-                env: None,
-                require: None,
-                aliases: None,
-                depends_on: None,
-                members: None,
-                mcp: None,
-                protocol: None,
-                implements: None,
-                provides: None,
-                requires: None,
-                learn: None,
-                usage: None,
-                lfmf_category: None,
-                job: None,
-            },
+            datum: crate::BootDatum::for_test("test", crate::DatumType::Job, "Test job"),
         };
 
         let order = datum.topological_sort(&steps).unwrap();
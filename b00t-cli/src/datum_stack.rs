@@ -522,89 +522,30 @@ mod tests {
 
     fn create_test_docker_datum(name: &str, image: &str) -> BootDatum {
         BootDatum {
-            name: name.to_string(),
-            datum_type: Some(DatumType::Docker),
-            desires: None,
-            hint: format!("Test docker datum {}", name),
-            install: None,
-            update: None,
-            version: None,
-            version_regex: None,
-            command: None,
-            args: None,
-            vsix_id: None,
-            script: None,
             image: Some(image.to_string()),
             docker_args: Some(vec!["-p".to_string(), "5432:5432".to_string()]),
-            oci_uri: None,
-            resource_path: None,
-            chart_path: None,
-            namespace: None,
-            values_file: None,
-            keywords: None,
-            package_name: None,
             env: Some({
                 let mut env = HashMap::new();
                 env.insert("TEST_VAR".to_string(), "test_value".to_string());
                 env
             }),
-            require: None,
-            aliases: None,
-            depends_on: None,
-            members: None,
-            mcp: None,
-            protocol: None,
-            implements: None,
-            provides: None,
-            requires: None,
-            learn: None,
-            usage: None,
-            lfmf_category: None,
-            job: None,
+            ..BootDatum::for_test(
+                name,
+                DatumType::Docker,
+                &format!("Test docker datum {}", name),
+            )
         }
     }
 
     fn create_test_stack(name: &str, members: Vec<String>) -> BootDatum {
         BootDatum {
-            name: name.to_string(),
-            datum_type: Some(DatumType::Stack),
-            desires: None,
-            hint: format!("Test stack {}", name),
-            install: None,
-            update: None,
-            version: None,
-            version_regex: None,
-            command: None,
-            args: None,
-            vsix_id: None,
-            script: None,
-            image: None,
-            docker_args: None,
-            oci_uri: None,
-            resource_path: None,
-            chart_path: None,
-            namespace: None,
-            values_file: None,
-            keywords: None,
-            package_name: None,
             env: Some({
                 let mut env = HashMap::new();
                 env.insert("STACK_VAR".to_string(), "stack_value".to_string());
                 env
             }),
-            require: None,
-            aliases: None,
-            depends_on: None,
             members: Some(members),
-            mcp: None,
-            protocol: None,
-            implements: None,
-            provides: None,
-            requires: None,
-            learn: None,
-            usage: None,
-            lfmf_category: None,
-            job: None,
+            ..BootDatum::for_test(name, DatumType::Stack, &format!("Test stack {}", name))
         }
     }
 
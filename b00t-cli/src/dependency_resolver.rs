@@ -16,8 +16,8 @@ impl<'a> DependencyResolver<'a> {
             let type_str = datum
                 .datum_type
                 .as_ref()
-                .map(|t| format!("{:?}", t).to_lowercase())
-                .unwrap_or_else(|| "unknown".to_string());
+                .map(|t| t.type_slug())
+                .unwrap_or("unknown");
             let key = format!("{}.{}", datum.name, type_str);
             datum_map.insert(key, datum);
         }
@@ -180,49 +180,16 @@ mod tests {
 
     fn create_test_datum(name: &str, r#type: &str, depends_on: Option<Vec<String>>) -> BootDatum {
         let datum_type = match r#type {
-            "cli" => Some(DatumType::Cli),
-            "mcp" => Some(DatumType::Mcp),
-            "docker" => Some(DatumType::Docker),
-            "k8s" => Some(DatumType::K8s),
-            _ => Some(DatumType::Unknown),
+            "cli" => DatumType::Cli,
+            "mcp" => DatumType::Mcp,
+            "docker" => DatumType::Docker,
+            "k8s" => DatumType::K8s,
+            _ => DatumType::Unknown,
         };
 
         BootDatum {
-            name: name.to_string(),
-            datum_type,
-            desires: None,
-            hint: format!("Test datum {}", name),
-            install: None,
-            update: None,
-            version: None,
-            version_regex: None,
-            command: None,
-            args: None,
-            vsix_id: None,
-            script: None,
-            image: None,
-            docker_args: None,
-            oci_uri: None,
-            resource_path: None,
-            chart_path: None,
-            namespace: None,
-            values_file: None,
-            keywords: None,
-            package_name: None,
-            env: None,
-            require: None,
-            aliases: None,
             depends_on,
-            members: None,
-            mcp: None,
-            protocol: None,
-            implements: None,
-            provides: None,
-            requires: None,
-            learn: None,
-            usage: None,
-            lfmf_category: None,
-            job: None,
+            ..BootDatum::for_test(name, datum_type, &format!("Test datum {}", name))
         }
     }
 
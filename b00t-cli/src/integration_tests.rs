@@ -238,4 +238,24 @@ baz = "learn/baz.md"
         let result_json = crate::mcp_list(temp_path, true);
         assert!(result_json.is_ok());
     }
+
+    #[test]
+    fn test_mcp_list_structured() {
+        let temp_dir = setup_temp_dir();
+        let temp_path = temp_dir.path().to_str().unwrap();
+
+        let json = r#"{"filesystem": {"command": "npx", "args": ["-y", "@modelcontextprotocol/server-filesystem"], "env": {"ROOT": "/tmp"}}}"#;
+        mcp_add_json(json, false, temp_path).unwrap();
+
+        let servers = b00t_cli::mcp_list_structured(temp_path, &[]).unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].name, "filesystem");
+        assert_eq!(servers[0].command, "npx");
+        assert_eq!(
+            servers[0].args,
+            vec!["-y", "@modelcontextprotocol/server-filesystem"]
+        );
+        assert_eq!(servers[0].env.get("ROOT").map(String::as_str), Some("/tmp"));
+        assert!(servers[0].error.is_none());
+    }
 }
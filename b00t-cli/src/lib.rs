@@ -3,6 +3,32 @@ use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+/// Define a datum struct together with a `deny_unknown_fields` twin of the same fields
+///
+/// 🤓 Loading datums stays lenient so an old or hand-edited file still works;
+/// `b00t datum lint` deserializes the strict twin to report the keys serde rejects.
+macro_rules! datum_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident, strict $strict:ident {
+            $( $(#[$field_meta:meta])* $field_vis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $(#[$field_meta])* $field_vis $field: $ty ),*
+        }
+
+        /// Lint-only copy of the datum struct that rejects unknown keys
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        #[allow(dead_code)]
+        pub(crate) struct $strict {
+            $( $(#[$field_meta])* $field: $ty ),*
+        }
+    };
+}
+
 pub mod bootstrap;
 pub mod budget_controller;
 pub mod cloud_sync;
@@ -54,11 +80,13 @@ pub struct McpConfig {
     pub mcp: McpServer,
 }
 
+datum_struct! {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub struct UnifiedConfig {
+pub struct UnifiedConfig, strict StrictUnifiedConfig {
     pub b00t: BootDatum,
     pub env: Option<std::collections::HashMap<String, String>>,
 }
+}
 
 // Orchestration metadata for k8s/stack integration
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
@@ -162,13 +190,16 @@ pub struct BudgetConstraint {
     pub on_budget_exceeded: Option<String>,
 }
 
+datum_struct! {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub struct BootDatum {
+pub struct BootDatum, strict StrictBootDatum {
     pub name: String,
     #[serde(rename = "type")]
     pub datum_type: Option<DatumType>,
     pub desires: Option<String>,
     pub hint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>, // Longer human-readable explanation; `hint` stays a one-liner
 
     pub install: Option<String>,
     pub update: Option<String>,
@@ -190,6 +221,22 @@ pub struct BootDatum {
     pub docker_args: Option<Vec<String>>,
     pub oci_uri: Option<String>,
     pub resource_path: Option<String>, // Path to Dockerfile/compose relative to _b00t_/
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_url: Option<String>, // HTTP endpoint that must return 2xx before the service is ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_timeout_secs: Option<u64>, // Health check timeout (default: 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timeout_secs: Option<u64>, // Container start timeout (default: 6)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_compose: Option<bool>, // Start via `<compose> up -d <name>` instead of `run`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compose_file: Option<String>, // Compose file for use_compose, relative to the datum directory
+    #[serde(rename = "restart", skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>, // `docker run --restart` policy (default: unless-stopped)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pod_name: Option<String>, // Podman pod to create/join (ignored by docker)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>, // Network to attach the container to (created if missing)
 
     // K8s fields
     pub chart_path: Option<String>, // Path to helm chart relative to REPO_ROOT
@@ -217,7 +264,8 @@ pub struct BootDatum {
 
     // Stack members - list of datum IDs that form this stack (only for DatumType::Stack)
     // Format: ["postgres.docker", "pgadmin.docker", "redis.docker"]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    // `services = [...]` is accepted as an alias
+    #[serde(alias = "services", skip_serializing_if = "Option::is_none")]
     pub members: Option<Vec<String>>,
 
     // MCP-specific multi-method support - these will be handled by datum_mcp module
@@ -319,6 +367,7 @@ pub struct BootDatum {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub entangled_k8s: Option<Vec<String>>,
 }
+}
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct McpMethods {
@@ -396,11 +445,75 @@ pub struct McpListItem {
     pub error: Option<String>,
 }
 
+/// An MCP server as returned by `mcp_list_structured`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct McpServerInfo {
+    pub name: String,
+    /// Launch command (`"HTTP"` for httpstream servers, empty when unreadable)
+    pub command: String,
+    /// Command arguments (the URL for httpstream servers)
+    pub args: Vec<String>,
+    pub env: std::collections::HashMap<String, String>,
+    pub hint: Option<String>,
+    pub error: Option<String>,
+}
+
+impl From<McpServerInfo> for McpListItem {
+    fn from(info: McpServerInfo) -> Self {
+        let has_command = !info.command.is_empty();
+        Self {
+            name: info.name,
+            command: has_command.then_some(info.command),
+            args: has_command.then_some(info.args),
+            hint: info.hint,
+            error: info.error,
+        }
+    }
+}
+
+datum_struct! {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub struct AiConfig {
+pub struct AiConfig, strict StrictAiConfig {
     pub b00t: BootDatum,
     pub models: Option<std::collections::HashMap<String, serde_json::Value>>,
     pub env: Option<std::collections::HashMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<AiHealthCheck>,
+}
+}
+
+/// `[health]` endpoint of an `.ai.toml` provider, used to test an API key
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AiHealthCheck {
+    /// URL that answers 2xx for a valid key (e.g. the provider's model list)
+    pub url: String,
+    /// Env var holding the key (default: the `[env]` key ending in `_API_KEY`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_env: Option<String>,
+    /// Header carrying the raw key; without it `Authorization: Bearer <key>` is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Extra request headers (e.g. `anthropic-version`)
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub headers: std::collections::HashMap<String, String>,
+}
+
+impl AiConfig {
+    /// Env var holding this provider's API key
+    pub fn api_key_env(&self) -> Option<String> {
+        if let Some(env_var) = self.health.as_ref().and_then(|h| h.api_key_env.clone()) {
+            return Some(env_var);
+        }
+        let mut keys: Vec<&String> = self
+            .env
+            .iter()
+            .flatten()
+            .map(|(key, _)| key)
+            .filter(|key| key.ends_with("_API_KEY"))
+            .collect();
+        keys.sort();
+        keys.first().map(|key| key.to_string())
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -614,6 +727,15 @@ fn create_mcp_datum_from_json(
         entangled_apis: None,
         entangled_docker: None,
         entangled_k8s: None,
+        network: None,
+        description: None,
+        pod_name: None,
+        restart_policy: None,
+        use_compose: None,
+        compose_file: None,
+        start_timeout_secs: None,
+        health_url: None,
+        health_timeout_secs: None,
     }
 }
 
@@ -720,6 +842,15 @@ pub fn normalize_mcp_json(input: &str, dwiw: bool) -> Result<BootDatum> {
                 entangled_apis: None,
                 entangled_docker: None,
                 entangled_k8s: None,
+                network: None,
+                description: None,
+                pod_name: None,
+                restart_policy: None,
+                use_compose: None,
+                compose_file: None,
+                start_timeout_secs: None,
+                health_url: None,
+                health_timeout_secs: None,
             });
         }
 
@@ -896,6 +1027,67 @@ impl DatumType {
             DatumType::Unknown // Default fallback for .toml files
         }
     }
+
+    /// Fixed lowercase name used in datum keys and file suffixes (`docker`, `ai_model`)
+    pub fn type_slug(&self) -> &'static str {
+        match self {
+            DatumType::Unknown => "unknown",
+            DatumType::Agent => "agent",
+            DatumType::Mcp => "mcp",
+            DatumType::Bash => "bash",
+            DatumType::Vscode => "vscode",
+            DatumType::Docker => "docker",
+            DatumType::K8s => "k8s",
+            DatumType::Apt => "apt",
+            DatumType::Nix => "nix",
+            DatumType::Ai => "ai",
+            DatumType::AiModel => "ai_model",
+            DatumType::Api => "api",
+            DatumType::Cli => "cli",
+            DatumType::Stack => "stack",
+            DatumType::Config => "config",
+            DatumType::Job => "job",
+        }
+    }
+}
+
+/// A semantic problem in a datum that deserialization alone doesn't catch
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationError {
+    /// Offending `[b00t]` field (e.g. `image`, `depends_on`)
+    pub field: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[cfg(test)]
+impl BootDatum {
+    /// Datum with only name, type and hint set, for test fixtures to extend with
+    /// `..BootDatum::for_test(..)` instead of listing every field
+    pub(crate) fn for_test(name: &str, datum_type: DatumType, hint: &str) -> Self {
+        toml::from_str::<UnifiedConfig>(&format!(
+            "[b00t]\nname = {:?}\ntype = {:?}\nhint = {:?}\n",
+            name,
+            datum_type.type_slug(),
+            hint
+        ))
+        .expect("test datum parses")
+        .b00t
+    }
 }
 
 impl BootDatum {
@@ -906,6 +1098,76 @@ impl BootDatum {
                 .unwrap_or(DatumType::Unknown)
         })
     }
+
+    /// Checks that need only this datum (empty when valid); see also `validate_references`
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push(ValidationError::new("name", "must not be empty"));
+        }
+
+        match self.datum_type {
+            Some(DatumType::Docker) => {
+                let has_source = self.image.is_some()
+                    || self.oci_uri.is_some()
+                    || self.resource_path.is_some()
+                    || self.use_compose == Some(true);
+                if !has_source {
+                    errors.push(ValidationError::new(
+                        "image",
+                        "docker datum needs an image (or oci_uri, resource_path, use_compose)",
+                    ));
+                }
+            }
+            Some(DatumType::Mcp) => {
+                let methods = self.mcp.as_ref();
+                let has_stdio = methods
+                    .and_then(|m| m.stdio.as_ref())
+                    .is_some_and(|stdio| stdio.iter().any(|method| method.contains_key("command")));
+                let has_url = methods
+                    .and_then(|m| m.httpstream.as_ref())
+                    .is_some_and(|http| http.contains_key("url"));
+                if self.command.is_none() && !has_stdio && !has_url {
+                    errors.push(ValidationError::new(
+                        "command",
+                        "mcp datum needs a command or a [b00t.mcp.httpstream] url",
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        errors
+    }
+
+    /// `depends_on`/`members` entries that match none of `known` datum keys
+    /// (`<name>.<type>`); bare names match any type
+    pub fn validate_references(
+        &self,
+        known: &std::collections::HashSet<String>,
+    ) -> Vec<ValidationError> {
+        let exists = |reference: &str| {
+            known.contains(reference)
+                || known.iter().any(|key| {
+                    key.rsplit_once('.')
+                        .is_some_and(|(name, _)| name == reference)
+                })
+        };
+
+        [("depends_on", &self.depends_on), ("members", &self.members)]
+            .into_iter()
+            .flat_map(|(field, references)| {
+                references
+                    .iter()
+                    .flatten()
+                    .filter(|reference| !exists(reference))
+                    .map(move |reference| {
+                        ValidationError::new(field, format!("unknown datum '{}'", reference))
+                    })
+            })
+            .collect()
+    }
 }
 
 pub fn create_mcp_toml_config(package: &BootDatum, path: &str) -> Result<()> {
@@ -972,7 +1234,7 @@ pub fn get_config(
     let search_paths = [
         format!("{}/_b00t_", path),
         path.to_string(),
-        "~/.dotfiles/_b00t_".to_string(),
+        commands::config::default_datum_path(),
         "~/.b00t".to_string(),
     ];
 
@@ -1003,21 +1265,16 @@ pub fn get_mcp_config(name: &str, path: &str) -> Result<BootDatum> {
     use anyhow::Context;
     use std::fs;
 
-    for base in [path, "~/.dotfiles/_b00t_", "~/.b00t"] {
-        let mut path_buf = get_expanded_path(base)?;
-        path_buf.push(format!("{}.mcp.toml", name));
-
-        if path_buf.exists() {
-            let content = fs::read_to_string(&path_buf).context(format!(
-                "Failed to read MCP config from {}",
-                path_buf.display()
-            ))?;
+    if let Some(path_buf) = find_mcp_datum_file(name, path)? {
+        let content = fs::read_to_string(&path_buf).context(format!(
+            "Failed to read MCP config from {}",
+            path_buf.display()
+        ))?;
 
-            let config: UnifiedConfig =
-                toml::from_str(&content).context("Failed to parse MCP config TOML")?;
+        let config: UnifiedConfig =
+            toml::from_str(&content).context("Failed to parse MCP config TOML")?;
 
-            return Ok(config.b00t);
-        }
+        return Ok(config.b00t);
     }
 
     anyhow::bail!(
@@ -1026,6 +1283,26 @@ pub fn get_mcp_config(name: &str, path: &str) -> Result<BootDatum> {
     );
 }
 
+/// Directories searched for `*.mcp.toml`, in order: `path`, the default datum path, `~/.b00t`
+pub fn mcp_datum_dirs(path: &str) -> Vec<String> {
+    vec![
+        path.to_string(),
+        commands::config::default_datum_path(),
+        "~/.b00t".to_string(),
+    ]
+}
+
+/// First `<name>.mcp.toml` in `mcp_datum_dirs(path)`
+pub fn find_mcp_datum_file(name: &str, path: &str) -> Result<Option<std::path::PathBuf>> {
+    for base in mcp_datum_dirs(path) {
+        let path_buf = get_expanded_path(&base)?.join(format!("{}.mcp.toml", name));
+        if path_buf.exists() {
+            return Ok(Some(path_buf));
+        }
+    }
+    Ok(None)
+}
+
 pub fn get_mcp_toml_files(path: &str) -> Result<Vec<String>> {
     use anyhow::Context;
     use std::fs;
@@ -1033,7 +1310,8 @@ pub fn get_mcp_toml_files(path: &str) -> Result<Vec<String>> {
     let mut seen = std::collections::HashSet::new();
     let mut mcp_files = Vec::new();
 
-    for base in [path, "~/.dotfiles/_b00t_", "~/.b00t"] {
+    let default_path = commands::config::default_datum_path();
+    for base in [path, default_path.as_str(), "~/.b00t"] {
         let expanded_path = match get_expanded_path(base) {
             Ok(p) => p,
             Err(_) => continue,
@@ -1064,63 +1342,27 @@ pub fn get_mcp_toml_files(path: &str) -> Result<Vec<String>> {
     Ok(mcp_files)
 }
 
-pub fn mcp_list(path: &str, json_output: bool) -> Result<()> {
-    use anyhow::Context;
-
+/// List MCP servers as structured data, without printing anything
+///
+/// Servers whose config cannot be read are still returned, with `error` set
+/// and an empty `command`. A non-empty `only` keeps just those server names
+/// (use `check_mcp_servers_exist` first to reject unknown ones).
+pub fn mcp_list_structured(path: &str, only: &[&str]) -> Result<Vec<McpServerInfo>> {
     let mcp_files = get_mcp_toml_files(path)?;
-    let mut mcp_items = Vec::new();
+    let mut servers = Vec::new();
 
     for server_name in mcp_files {
-        match get_mcp_config(&server_name, path) {
-            Ok(datum) => {
-                // Extract command and args from MCP structure (prioritizing stdio methods)
-                let (command, args) =
-                    if let Some(mcp) = &datum.mcp {
-                        if let Some(stdio_methods) = &mcp.stdio {
-                            if let Some(first_method) = stdio_methods.first() {
-                                let command = first_method
-                                    .get("command")
-                                    .and_then(|v| v.as_str())
-                                    .map(|s| s.to_string());
-                                let args = first_method.get("args").and_then(|v| v.as_array()).map(
-                                    |arr| {
-                                        arr.iter()
-                                            .filter_map(|v| v.as_str())
-                                            .map(|s| s.to_string())
-                                            .collect::<Vec<String>>()
-                                    },
-                                );
-                                (command, args)
-                            } else {
-                                (None, None)
-                            }
-                        } else if let Some(httpstream) = &mcp.httpstream {
-                            let url = httpstream
-                                .get("url")
-                                .and_then(|v| v.as_str())
-                                .map(|s| s.to_string());
-                            (Some("HTTP".to_string()), url.map(|u| vec![u]))
-                        } else {
-                            (None, None)
-                        }
-                    } else {
-                        // Fallback to legacy fields for backwards compatibility
-                        (datum.command.clone(), datum.args.clone())
-                    };
-
-                mcp_items.push(McpListItem {
-                    name: server_name,
-                    command,
-                    args,
-                    hint: Some(datum.hint.clone()),
-                    error: None,
-                });
-            }
+        if !only.is_empty() && !only.contains(&server_name.as_str()) {
+            continue;
+        }
+        match get_mcp_server_info(&server_name, path) {
+            Ok(info) => servers.push(info),
             Err(e) => {
-                mcp_items.push(McpListItem {
+                servers.push(McpServerInfo {
                     name: server_name,
-                    command: None,
-                    args: None,
+                    command: String::new(),
+                    args: Vec::new(),
+                    env: std::collections::HashMap::new(),
                     hint: None,
                     error: Some(e.to_string()),
                 });
@@ -1128,6 +1370,64 @@ pub fn mcp_list(path: &str, json_output: bool) -> Result<()> {
         }
     }
 
+    Ok(servers)
+}
+
+/// Command, args and env of a single MCP server datum
+pub fn get_mcp_server_info(server_name: &str, path: &str) -> Result<McpServerInfo> {
+    let datum = get_mcp_config(server_name, path)?;
+
+    // Extract command and args from MCP structure (prioritizing stdio methods)
+    let (command, args, env) = if let Some(mcp) = &datum.mcp {
+        if mcp
+            .stdio
+            .as_ref()
+            .is_some_and(|methods| !methods.is_empty())
+        {
+            // Method-level env wins over the datum's [b00t.env]
+            let (command, args, env) = extract_mcp_command_args(&datum);
+            let env = env.or_else(|| datum.env.clone()).unwrap_or_default();
+            (command, args, env)
+        } else if let Some(httpstream) = &mcp.httpstream {
+            let url = httpstream
+                .get("url")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            (
+                "HTTP".to_string(),
+                url.into_iter().collect(),
+                std::collections::HashMap::new(),
+            )
+        } else {
+            (String::new(), Vec::new(), std::collections::HashMap::new())
+        }
+    } else {
+        // Fallback to legacy fields for backwards compatibility
+        (
+            datum.command.clone().unwrap_or_default(),
+            datum.args.clone().unwrap_or_default(),
+            datum.env.clone().unwrap_or_default(),
+        )
+    };
+
+    Ok(McpServerInfo {
+        name: server_name.to_string(),
+        command,
+        args,
+        env,
+        hint: Some(datum.hint.clone()),
+        error: None,
+    })
+}
+
+pub fn mcp_list(path: &str, json_output: bool) -> Result<()> {
+    use anyhow::Context;
+
+    let mcp_items: Vec<McpListItem> = mcp_list_structured(path, &[])?
+        .into_iter()
+        .map(McpListItem::from)
+        .collect();
+
     if json_output {
         let expanded_path = get_expanded_path(path)?;
         let output = McpListOutput {
@@ -1255,67 +1555,171 @@ pub fn mcp_add_json(json: &str, dwiw: bool, path: &str) -> Result<()> {
 /// // Remove an MCP server configuration from the _b00t_ directory
 /// crate::mcp_remove("filesystem", "~/.dotfiles/_b00t_").unwrap();
 ///
-/// // CLI usage:
+/// // CLI usage (`mcp remove` asks first and warns about references):
+/// // b00t-cli mcp remove filesystem
 /// // b00t-cli mcp register --remove filesystem
 /// ```
 pub fn mcp_remove(name: &str, path: &str) -> Result<()> {
     use std::fs;
-    use std::path::PathBuf;
-
-    let expanded_path = get_expanded_path(path)?;
-    let mut mcp_path = PathBuf::from(expanded_path);
-
-    // Construct the filename
-    let filename = format!("{}.mcp.toml", name);
-    mcp_path.push(filename);
 
-    if mcp_path.exists() {
+    // 🤓 Same directories as get_mcp_config, so anything `mcp list` shows can be removed
+    if let Some(mcp_path) = find_mcp_datum_file(name, path)? {
         fs::remove_file(&mcp_path).with_context(|| {
             format!(
                 "Failed to remove MCP server configuration: {}",
                 mcp_path.display()
             )
         })?;
-        println!("Removed MCP server configuration: {}", name);
+        println!("Removed MCP server configuration: {}", mcp_path.display());
     } else {
-        anyhow::bail!("MCP server configuration not found: {}", name);
+        anyhow::bail!(
+            "MCP server configuration not found: {} (searched {})",
+            name,
+            mcp_datum_dirs(path).join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Names of every `*.mcp.toml` datum that `get_mcp_config` can find
+pub fn known_mcp_server_names(path: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for base in mcp_datum_dirs(path) {
+        let Ok(dir) = get_expanded_path(&base) else {
+            continue;
+        };
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        names.extend(entries.flatten().filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_suffix(".mcp.toml")
+                .map(str::to_string)
+        }));
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Up to three `candidates` within a small edit distance of `name`, closest first
+pub fn suggest_similar<'a>(name: &str, candidates: &'a [String]) -> Vec<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    let mut scored: Vec<(usize, &str)> = candidates
+        .iter()
+        .map(|candidate| (strsim::levenshtein(name, candidate), candidate.as_str()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    scored.sort();
+    scored.into_iter().take(3).map(|(_, c)| c).collect()
+}
+
+/// Error listing every requested server that has no `*.mcp.toml`, with typo suggestions
+pub fn check_mcp_servers_exist(path: &str, requested: &[&str]) -> Result<()> {
+    let known = known_mcp_server_names(path);
+    let missing: Vec<String> = requested
+        .iter()
+        .filter(|name| !known.iter().any(|k| k == *name))
+        .map(|name| match suggest_similar(name, &known).as_slice() {
+            [] => format!("  - {}", name),
+            similar => format!("  - {} (did you mean: {}?)", name, similar.join(", ")),
+        })
+        .collect();
+
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "MCP server(s) not found in _b00t_ directory:\n{}",
+            missing.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// JSON shape printed by `mcp_output`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum McpOutputFormat {
+    /// `{"mcpServers": {...}}` (Claude Code, Claude Desktop, .mcp.json)
+    Wrapper,
+    /// Bare `{"<server>": {...}}` map
+    Json,
+    /// Cursor's `mcp.json`: `mcpServers` with an explicit `type`, `url` for HTTP-only servers
+    Cursor,
+}
+
+/// The httpstream `url` of an MCP datum that has no stdio method to launch
+pub fn mcp_http_only_url(datum: &BootDatum) -> Option<&str> {
+    let methods = datum.mcp.as_ref();
+    let has_stdio = methods
+        .and_then(|m| m.stdio.as_ref())
+        .is_some_and(|stdio| !stdio.is_empty())
+        || datum.command.is_some();
+    if has_stdio {
+        return None;
     }
+    methods
+        .and_then(|m| m.httpstream.as_ref())
+        .and_then(|http| http.get("url"))
+        .and_then(|url| url.as_str())
+}
 
+/// Cursor `mcpServers` entry: stdio servers keep command/args/env, HTTP-only ones become `{"url"}`
+pub fn cursor_server_json(datum: &BootDatum) -> Result<serde_json::Value> {
+    if let Some(url) = mcp_http_only_url(datum) {
+        return Ok(serde_json::json!({ "url": url }));
+    }
+
+    let mut entry = mcp_server_json(datum)?;
+    entry["type"] = serde_json::Value::String("stdio".to_string());
+    Ok(entry)
+}
+
+/// Print the named servers as JSON (see [`mcp_output_string`])
+pub fn mcp_output(
+    path: &str,
+    format: McpOutputFormat,
+    servers: &str,
+    prefix: Option<&str>,
+) -> Result<()> {
+    println!("{}", mcp_output_string(path, format, servers, prefix)?);
     Ok(())
 }
 
-pub fn mcp_output(path: &str, use_mcp_servers_wrapper: bool, servers: &str) -> Result<()> {
+/// The named servers as pretty JSON; `prefix` renames each key to `<prefix>_<name>`
+/// so configs from several dotfile repos can be merged without collisions
+pub fn mcp_output_string(
+    path: &str,
+    format: McpOutputFormat,
+    servers: &str,
+    prefix: Option<&str>,
+) -> Result<String> {
     use anyhow::Context;
 
-    let requested_servers: Vec<&str> = servers.split(',').map(|s| s.trim()).collect();
+    let requested_servers: Vec<&str> = servers
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    check_mcp_servers_exist(path, &requested_servers)?;
+
     let mut server_configs = serde_json::Map::new();
+    let output_key = |server_name: &str| match prefix {
+        Some(prefix) => format!("{}_{}", prefix, server_name),
+        None => server_name.to_string(),
+    };
 
     for server_name in requested_servers {
-        if server_name.is_empty() {
-            continue;
-        }
-
         match get_mcp_config(server_name, path) {
             Ok(datum) => {
-                let (command, args, env) = extract_mcp_command_args(&datum);
-                let mut server_config = serde_json::Map::new();
-                server_config.insert("command".to_string(), serde_json::Value::String(command));
-                server_config.insert(
-                    "args".to_string(),
-                    serde_json::Value::Array(
-                        args.into_iter().map(serde_json::Value::String).collect(),
-                    ),
-                );
-                if let Some(env_map) = env {
-                    server_config.insert("env".to_string(), serde_json::to_value(env_map)?);
-                }
-
-                server_configs.insert(
-                    server_name.to_string(),
-                    serde_json::Value::Object(server_config),
-                );
+                let entry = match format {
+                    McpOutputFormat::Cursor => cursor_server_json(&datum)?,
+                    McpOutputFormat::Wrapper | McpOutputFormat::Json => mcp_server_json(&datum)?,
+                };
+                server_configs.insert(output_key(server_name), entry);
             }
-            Err(_) => {
+            Err(e) => {
                 // Create a cute poopy log error indicator instead of stderr warning
                 let mut error_config = serde_json::Map::new();
                 error_config.insert(
@@ -1337,21 +1741,21 @@ pub fn mcp_output(path: &str, use_mcp_servers_wrapper: bool, servers: &str) -> R
                     serde_json::Value::Array(vec![
                         serde_json::Value::String(utc_time),
                         serde_json::Value::String(format!(
-                            "server '{}' not found in _b00t_ directory",
-                            server_name
+                            "server '{}' failed to load: {}",
+                            server_name, e
                         )),
                     ]),
                 );
 
                 server_configs.insert(
-                    server_name.to_string(),
+                    output_key(server_name),
                     serde_json::Value::Object(error_config),
                 );
             }
         }
     }
 
-    let output = if use_mcp_servers_wrapper {
+    let output = if format != McpOutputFormat::Json {
         let mut wrapper = serde_json::Map::new();
         wrapper.insert(
             "mcpServers".to_string(),
@@ -1362,11 +1766,22 @@ pub fn mcp_output(path: &str, use_mcp_servers_wrapper: bool, servers: &str) -> R
         serde_json::Value::Object(server_configs)
     };
 
-    let json_str =
-        serde_json::to_string_pretty(&output).context("Failed to serialize MCP servers to JSON")?;
-    println!("{}", json_str);
+    serde_json::to_string_pretty(&output).context("Failed to serialize MCP servers to JSON")
+}
 
-    Ok(())
+/// `{"command", "args", "env"}` entry for an `mcpServers` map
+pub fn mcp_server_json(datum: &BootDatum) -> Result<serde_json::Value> {
+    let (command, args, env) = extract_mcp_command_args(datum);
+    let mut server_config = serde_json::Map::new();
+    server_config.insert("command".to_string(), serde_json::Value::String(command));
+    server_config.insert(
+        "args".to_string(),
+        serde_json::Value::Array(args.into_iter().map(serde_json::Value::String).collect()),
+    );
+    if let Some(env_map) = env {
+        server_config.insert("env".to_string(), serde_json::to_value(env_map)?);
+    }
+    Ok(serde_json::Value::Object(server_config))
 }
 
 /// Extract command, args, and env from MCP datum, handling both new multi-method and legacy formats
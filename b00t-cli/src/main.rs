@@ -25,8 +25,8 @@ use b00t_cli::utils::get_workspace_root;
 use b00t_cli::commands::learn::{LearnArgs, handle_learn};
 use b00t_cli::commands::{
     AiCommands, AppCommands, BootstrapCommands, BudgetCommands, ChatCommands, CliCommands,
-    DatumCommands, GrokCommands, InitCommands, InstallCommands, K8sCommands, McpCommands,
-    SessionCommands, StackCommands, WhatismyCommands,
+    ConfigCommands, DatumCommands, GrokCommands, InitCommands, InstallCommands, K8sCommands,
+    McpCommands, OrchestratorCommands, SessionCommands, StackCommands, WhatismyCommands,
 };
 
 // Re-export commonly used functions for datum modules
@@ -43,8 +43,13 @@ mod integration_tests;
 struct Cli {
     #[clap(subcommand)]
     command: Option<Commands>,
-    #[clap(short, long, env = "_B00T_Path", default_value = "~/.dotfiles/_b00t_")]
-    path: String,
+    #[clap(
+        short,
+        long,
+        env = "_B00T_Path",
+        help = "Datum directory [default: datum_path in ~/.b00t/config.toml, else ~/.dotfiles/_b00t_]"
+    )]
+    path: Option<String>,
     #[clap(
         long,
         help = "Output structured markdown documentation about internal structures"
@@ -200,6 +205,16 @@ Example:
         #[clap(subcommand)]
         datum_command: DatumCommands,
     },
+    #[clap(about = "Read and change b00t's own settings (~/.b00t/config.toml)")]
+    Config {
+        #[clap(subcommand)]
+        config_command: ConfigCommands,
+    },
+    #[clap(about = "Datum service orchestration status")]
+    Orchestrator {
+        #[clap(subcommand)]
+        orchestrator_command: OrchestratorCommands,
+    },
     #[clap(about = "Grok knowledgebase RAG system")]
     Grok {
         #[clap(subcommand)]
@@ -1099,9 +1114,43 @@ fn check_readme_status(memory: &mut b00t_cli::session_memory::SessionMemory) ->
     Ok(())
 }
 
+/// Install the stderr subscriber, at `log_level` from the b00t config unless `RUST_LOG` is set
+///
+/// 🤓 Runs before anything else reads the config, so a config that fails to
+/// load is reported here (once) instead of going to a not-yet-installed subscriber
+fn init_tracing() -> b00t_cli::commands::config::CliConfig {
+    use b00t_cli::commands::config::CliConfig;
+    use tracing_subscriber::EnvFilter;
+
+    let config = CliConfig::load();
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        let level = config
+            .as_ref()
+            .ok()
+            .and_then(|config| config.log_level.clone())
+            .unwrap_or_else(|| "warn".to_string());
+        EnvFilter::new(format!("warn,b00t_cli={0},b00t_c0re_lib={0}", level))
+    });
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .try_init();
+
+    config.unwrap_or_else(|e| {
+        tracing::warn!(error = format!("{:#}", e), "Ignoring b00t config");
+        CliConfig::default()
+    })
+}
+
 #[tokio::main]
 async fn main() {
+    let config = init_tracing();
     let cli = Cli::parse();
+    let path = cli.path.clone().unwrap_or_else(|| {
+        config
+            .datum_path
+            .unwrap_or_else(|| b00t_cli::commands::config::DEFAULT_DATUM_PATH.to_string())
+    });
 
     if cli.doc {
         generate_documentation();
@@ -1116,43 +1165,43 @@ async fn main() {
             }
         }
         Some(Commands::Mcp { mcp_command }) => {
-            if let Err(e) = mcp_command.execute_async(&cli.path).await {
+            if let Err(e) = mcp_command.execute_async(&path).await {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Ai { ai_command }) => {
-            if let Err(e) = ai_command.execute(&cli.path) {
+            if let Err(e) = ai_command.execute_async(&path).await {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Stack { stack_command }) => {
-            if let Err(e) = stack_command.execute(&cli.path) {
+            if let Err(e) = stack_command.execute_async(&path).await {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Budget { budget_command }) => {
-            if let Err(e) = budget_command.execute(&cli.path) {
+            if let Err(e) = budget_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::App { app_command }) => {
-            if let Err(e) = app_command.execute(&cli.path) {
+            if let Err(e) = app_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Cli { cli_command }) => {
-            if let Err(e) = cli_command.execute(&cli.path) {
+            if let Err(e) = cli_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Model { model_command }) => {
-            if let Err(e) = model_command.execute(&cli.path) {
+            if let Err(e) = model_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -1162,19 +1211,19 @@ async fn main() {
             let check_cmd = CliCommands::Check {
                 command: command.clone(),
             };
-            if let Err(e) = check_cmd.execute(&cli.path) {
+            if let Err(e) = check_cmd.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Init { init_command }) => {
-            if let Err(e) = init_command.execute(&cli.path) {
+            if let Err(e) = init_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Whoami) => {
-            if let Err(e) = whoami::whoami(&cli.path) {
+            if let Err(e) = whoami::whoami(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -1192,7 +1241,7 @@ async fn main() {
             }
         }
         Some(Commands::Whatismy { whatismy_command }) => {
-            if let Err(e) = whatismy_command.execute(&cli.path) {
+            if let Err(e) = whatismy_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -1203,7 +1252,7 @@ async fn main() {
             available,
         }) => {
             if let Err(e) = show_status(
-                &cli.path,
+                &path,
                 filter.as_ref().map(|s| s.as_str()),
                 *installed,
                 *available,
@@ -1213,19 +1262,19 @@ async fn main() {
             }
         }
         Some(Commands::K8s { k8s_command }) => {
-            if let Err(e) = k8s_command.execute(&cli.path) {
+            if let Err(e) = k8s_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Install { install_command }) => {
-            if let Err(e) = install_command.execute(&cli.path) {
+            if let Err(e) = install_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Session { session_command }) => {
-            if let Err(e) = session_command.execute(&cli.path) {
+            if let Err(e) = session_command.execute(&path) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -1239,7 +1288,7 @@ async fn main() {
             }
         }
         Some(Commands::Job { job_command }) => {
-            if let Err(e) = job_command.execute_async(&cli.path).await {
+            if let Err(e) = job_command.execute_async(&path).await {
                 eprintln!("Job Error: {}", e);
                 std::process::exit(1);
             }
@@ -1251,14 +1300,29 @@ async fn main() {
             }
         }
         Some(Commands::Learn(args)) => {
-            if let Err(e) = handle_learn(&cli.path, args.clone()) {
+            if let Err(e) = handle_learn(&path, args.clone()) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Config { config_command }) => {
+            if let Err(e) = config_command.execute() {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Some(Commands::Orchestrator {
+            orchestrator_command,
+        }) => {
+            use b00t_cli::commands::orchestrator::handle_orchestrator_command;
+            if let Err(e) = handle_orchestrator_command(&path, orchestrator_command) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
         Some(Commands::Datum { datum_command }) => {
             use b00t_cli::commands::datum::handle_datum_command;
-            if let Err(e) = handle_datum_command(&cli.path, datum_command) {
+            if let Err(e) = handle_datum_command(&path, datum_command) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -1273,7 +1337,7 @@ async fn main() {
             }
         }
         Some(Commands::Up { yes }) => {
-            if let Err(e) = handle_up_command(&cli.path, *yes) {
+            if let Err(e) = handle_up_command(&path, *yes) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -2,6 +2,7 @@ use crate::datum_ai_model::AiModelDatumEntry;
 use crate::traits::DatumChecker; // 🦨 Fix: trait needed for is_installed() method
 use crate::{check_command_available, get_expanded_path};
 use anyhow::{Context, Result, anyhow};
+use b00t_c0re_lib::datum_ai_model::{AiModelDatum, ModelCapability, ModelProvider, ModelSize};
 use duct::cmd;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -27,6 +28,8 @@ pub struct ModelRecord {
     pub dtype: Option<String>,
     pub rpm_limit: Option<u32>,
     pub context_window: Option<u32>,
+    pub input_price_per_1m: Option<f64>,
+    pub output_price_per_1m: Option<f64>,
     pub installed: bool,
     pub active: bool,
     pub aliases: Vec<String>,
@@ -206,16 +209,32 @@ fn record_from_entry(entry: &AiModelDatumEntry, active: bool) -> ModelRecord {
         dtype: entry.dtype(),
         rpm_limit: entry.model.rpm_limit,
         context_window: entry.model.context_window,
+        input_price_per_1m: entry.model.input_price_per_1m,
+        output_price_per_1m: entry.model.output_price_per_1m,
         installed: entry.is_installed(),
         active,
         aliases,
     }
 }
 
-pub fn list_models(path: &str) -> Result<Vec<ModelRecord>> {
+/// Provider name as written in the datum (`provider = "anthropic"`)
+fn provider_slug(provider: &ModelProvider) -> String {
+    match serde_json::to_value(provider) {
+        Ok(serde_json::Value::String(slug)) => slug,
+        _ => format!("{:?}", provider).to_lowercase(),
+    }
+}
+
+/// List model datums, optionally only those backed by `provider`
+pub fn list_models(path: &str, provider: Option<&str>) -> Result<Vec<ModelRecord>> {
     let active = read_active_model();
     let models = load_models(path)?
         .into_iter()
+        .filter(|entry| {
+            provider
+                .map(|wanted| provider_slug(&entry.model.provider).eq_ignore_ascii_case(wanted))
+                .unwrap_or(true)
+        })
         .map(|entry| {
             let is_active = active
                 .as_ref()
@@ -227,6 +246,73 @@ pub fn list_models(path: &str) -> Result<Vec<ModelRecord>> {
     Ok(models)
 }
 
+/// Parse a capability name, accepting task-style aliases (`function-calling`, `code-generation`)
+pub fn parse_capability(name: &str) -> Result<ModelCapability> {
+    let normalized = name.trim().to_lowercase().replace('-', "_");
+    let canonical = match normalized.as_str() {
+        "code_generation" | "coding" => "code",
+        "function_calling" | "tool_calling" | "tool_use" => "tools",
+        "embedding" => "embeddings",
+        "json" | "structured_output" => "json_mode",
+        other => other,
+    };
+    serde_json::from_value(serde_json::Value::String(canonical.to_string()))
+        .map_err(|_| anyhow!("Unknown capability '{}'", name))
+}
+
+/// Whether the model's API key env var (if any) is set
+fn provider_env_ready(model: &AiModelDatum) -> bool {
+    model
+        .api_key_env
+        .as_ref()
+        .map(|var| std::env::var(var).is_ok_and(|value| !value.is_empty()))
+        .unwrap_or(true)
+}
+
+/// Rank for size ordering: the preferred size first, then largest to smallest
+fn size_rank(size: &ModelSize, preferred: Option<&ModelSize>) -> u8 {
+    if preferred == Some(size) {
+        return 0;
+    }
+    match size {
+        ModelSize::Large => 1,
+        ModelSize::Medium => 2,
+        ModelSize::Small => 3,
+    }
+}
+
+/// Best enabled model with `capability` whose provider credentials are available
+///
+/// 🤓 Unparseable datums are skipped so one bad file cannot hide every model
+pub fn select_model_for_capability(
+    path: &str,
+    capability: &ModelCapability,
+    size: Option<&ModelSize>,
+) -> Result<Option<AiModelDatumEntry>> {
+    let mut candidates: Vec<AiModelDatumEntry> = enumerate_model_files(path)?
+        .into_iter()
+        .filter_map(|file| match AiModelDatumEntry::from_file(&file) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                tracing::debug!(file = %file.display(), error = format!("{:#}", e), "Skipping model datum");
+                None
+            }
+        })
+        .filter(|entry| {
+            entry.model.enabled
+                && entry.model.has_capability(capability)
+                && provider_env_ready(&entry.model)
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        size_rank(&a.model.size, size)
+            .cmp(&size_rank(&b.model.size, size))
+            .then_with(|| a.datum.name.cmp(&b.datum.name))
+    });
+    Ok(candidates.into_iter().next())
+}
+
 pub fn describe_model(path: &str, name: Option<&str>) -> Result<ModelRecord> {
     let entry = select_model(path, name)?;
     let active = read_active_model()
@@ -483,3 +569,87 @@ pub fn stop_model(path: &str, container_name: Option<&str>) -> Result<()> {
         .with_context(|| format!("Failed to stop docker container {}", target))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_model(dir: &std::path::Path, name: &str, provider: &str) {
+        let content = format!(
+            "[b00t]\nname = \"{name}\"\ntype = \"ai_model\"\nhint = \"test\"\n\n[ai_model]\nprovider = \"{provider}\"\nsize = \"small\"\ncapabilities = [\"chat\"]\nlitellm_model = \"{provider}/{name}\"\n"
+        );
+        fs::write(dir.join(format!("{}.ai_model.toml", name)), content).unwrap();
+    }
+
+    #[test]
+    fn test_list_models_provider_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        write_model(dir.path(), "claude-test", "anthropic");
+        write_model(dir.path(), "llama-test", "ollama");
+        let path = dir.path().to_str().unwrap();
+
+        assert_eq!(list_models(path, None).unwrap().len(), 2);
+
+        let anthropic = list_models(path, Some("Anthropic")).unwrap();
+        assert_eq!(anthropic.len(), 1);
+        assert_eq!(anthropic[0].name, "claude-test");
+
+        assert!(list_models(path, Some("groq")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_capability_aliases() {
+        assert_eq!(
+            parse_capability("function-calling").unwrap(),
+            ModelCapability::Tools
+        );
+        assert_eq!(
+            parse_capability("code-generation").unwrap(),
+            ModelCapability::Code
+        );
+        assert_eq!(
+            parse_capability("json_mode").unwrap(),
+            ModelCapability::JsonMode
+        );
+        assert!(parse_capability("telepathy").is_err());
+    }
+
+    #[test]
+    fn test_select_model_for_capability() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, size: &str, caps: &str, key_env: &str| {
+            let content = format!(
+                "[b00t]\nname = \"{name}\"\ntype = \"ai_model\"\nhint = \"test\"\n\n[ai_model]\nprovider = \"openrouter\"\nsize = \"{size}\"\ncapabilities = {caps}\nlitellm_model = \"openrouter/{name}\"\napi_key_env = \"{key_env}\"\n"
+            );
+            fs::write(dir.path().join(format!("{}.ai_model.toml", name)), content).unwrap();
+        };
+        write("big-tools", "large", r#"["chat", "tools"]"#, "PATH");
+        write("small-tools", "small", r#"["tools"]"#, "PATH");
+        write(
+            "locked-tools",
+            "large",
+            r#"["tools"]"#,
+            "B00T_TEST_UNSET_KEY",
+        );
+        write("chat-only", "large", r#"["chat"]"#, "PATH");
+        fs::write(dir.path().join("broken.ai_model.toml"), "[b00t").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let best = select_model_for_capability(path, &ModelCapability::Tools, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(best.model.litellm_model, "openrouter/big-tools");
+
+        let small =
+            select_model_for_capability(path, &ModelCapability::Tools, Some(&ModelSize::Small))
+                .unwrap()
+                .unwrap();
+        assert_eq!(small.datum.name, "small-tools");
+
+        assert!(
+            select_model_for_capability(path, &ModelCapability::Vision, None)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -54,6 +54,46 @@ pub struct McpCommand {
     pub arguments: Value, // JSON arguments for the tool
 }
 
+impl McpCommand {
+    /// Tool plus arguments as a CLI argv tail
+    ///
+    /// Object arguments become `--key value` flags (`true` is a bare flag),
+    /// array arguments are passed positionally.
+    pub fn cli_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if !self.tool.is_empty() {
+            args.push(self.tool.clone());
+        }
+
+        match &self.arguments {
+            Value::Null => {}
+            Value::Array(items) => args.extend(items.iter().map(value_to_arg)),
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match value {
+                        Value::Bool(true) => args.push(format!("--{}", key)),
+                        Value::Bool(false) | Value::Null => {}
+                        other => {
+                            args.push(format!("--{}", key));
+                            args.push(value_to_arg(other));
+                        }
+                    }
+                }
+            }
+            other => args.push(value_to_arg(other)),
+        }
+
+        args
+    }
+}
+
+fn value_to_arg(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
 /// Output from adapter translation
 #[derive(Debug, Clone)]
 pub struct AdapterOutput {
@@ -130,6 +170,17 @@ pub fn create_adapter(orchestrator: Orchestrator) -> Result<Box<dyn Orchestrator
         Orchestrator::Kubernetes => {
             Ok(Box::new(crate::orchestrator::k8s_adapter::K8sAdapter::new()))
         }
+        Orchestrator::DockerCompose => Ok(Box::new(
+            crate::orchestrator::docker_compose_adapter::DockerComposeAdapter::new(),
+        )),
+        Orchestrator::Nomad => Ok(Box::new(
+            crate::orchestrator::nomad_adapter::NomadAdapter::new(),
+        )),
         _ => anyhow::bail!("Adapter for {} not yet implemented", orchestrator),
     }
 }
+
+/// Create the adapter for the best orchestrator detected on this system
+pub fn detect_adapter() -> Result<Box<dyn OrchestratorAdapter>> {
+    create_adapter(crate::orchestrator::detection::detect_orchestrator()?)
+}
@@ -48,20 +48,30 @@ fn check_kubernetes() -> bool {
         .unwrap_or(false)
 }
 
+/// Compose front-ends in priority order: Docker Compose (v2 plugin, legacy) > podman-compose > podman's built-in compose
+pub const COMPOSE_COMMANDS: [&[&str]; 4] = [
+    &["docker", "compose"],
+    &["docker-compose"],
+    &["podman-compose"],
+    &["podman", "compose"],
+];
+
+/// First compose command from `COMPOSE_COMMANDS` that answers `version`
+pub fn detect_compose_command() -> Option<Vec<String>> {
+    COMPOSE_COMMANDS.iter().find_map(|cmd| {
+        Command::new(cmd[0])
+            .args(&cmd[1..])
+            .arg("version")
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|_| cmd.iter().map(|s| s.to_string()).collect())
+    })
+}
+
 fn check_docker_compose() -> bool {
-    // Check if docker-compose is installed
-    Command::new("docker-compose")
-        .arg("version")
-        .output()
-        .map(|out| out.status.success())
-        .unwrap_or_else(|_| {
-            // Try docker compose (v2 syntax)
-            Command::new("docker")
-                .args(&["compose", "version"])
-                .output()
-                .map(|out| out.status.success())
-                .unwrap_or(false)
-        })
+    // Any compose front-end (docker compose, docker-compose, podman-compose, podman compose)
+    detect_compose_command().is_some()
 }
 
 fn check_nomad() -> bool {
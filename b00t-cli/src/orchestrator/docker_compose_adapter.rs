@@ -0,0 +1,341 @@
+// Docker Compose adapter: Translates b00t datums to compose files + `docker compose run --rm` invocations
+// Uses the local compose CLI (v2 `docker compose` plugin, legacy `docker-compose` or `podman-compose`)
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::json;
+use serde_yaml::Mapping;
+use std::collections::BTreeMap;
+use std::process::Command;
+
+use super::adapter::{
+    AdapterMetadata, AdapterOutput, McpCommand, Orchestrator, OrchestratorAdapter,
+};
+use super::detection::detect_compose_command;
+use crate::datum_stack::{JobDatum, StackDatum};
+
+pub struct DockerComposeAdapter {
+    compose_command: Vec<String>, // ["docker", "compose"] or ["docker-compose"]
+    project_name: Option<String>,
+}
+
+/// Service row reported by `docker compose ps --format json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComposeService {
+    #[serde(rename = "Service")]
+    pub service: String,
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "State", default)]
+    pub state: String,
+    #[serde(rename = "Image", default)]
+    pub image: String,
+}
+
+impl DockerComposeAdapter {
+    pub fn new() -> Self {
+        Self {
+            compose_command: Self::detect_compose_command()
+                .unwrap_or_else(|| vec!["docker".to_string(), "compose".to_string()]),
+            project_name: None,
+        }
+    }
+
+    pub fn with_project_name(mut self, project_name: String) -> Self {
+        self.project_name = Some(project_name);
+        self
+    }
+
+    fn detect_compose_command() -> Option<Vec<String>> {
+        // Prefer the v2 plugin, then legacy docker-compose, then podman-compose
+        detect_compose_command()
+    }
+
+    /// Base compose invocation including the project flag
+    fn base_args(&self) -> Vec<String> {
+        let mut args = self.compose_command.clone();
+        if let Some(project) = &self.project_name {
+            args.push("-p".to_string());
+            args.push(project.clone());
+        }
+        args
+    }
+
+    /// Translate an MCP tool call into `docker compose run --rm <server> <tool> <args...>`
+    ///
+    /// The server is the compose service; arguments follow `McpCommand::cli_args`.
+    pub fn compose_run_args(&self, command: &McpCommand) -> Vec<String> {
+        let mut args = self.base_args();
+        args.extend([
+            "run".to_string(),
+            "--rm".to_string(),
+            command.server.clone(),
+        ]);
+        args.extend(command.cli_args());
+        args
+    }
+
+    /// Translate an MCP tool call into an adapter output carrying the shell invocation
+    pub fn translate_mcp_command(&self, command: &McpCommand) -> AdapterOutput {
+        let invocation = Self::shell_join(&self.compose_run_args(command));
+
+        AdapterOutput {
+            orchestrator: Orchestrator::DockerCompose,
+            manifests: vec![invocation],
+            mcp_commands: vec![command.clone()],
+            metadata: AdapterMetadata::default(),
+        }
+    }
+
+    /// List services of the current compose project via `docker compose ps --format json`
+    pub fn list_services(&self) -> Result<Vec<ComposeService>> {
+        let mut args = self.base_args();
+        args.extend(["ps", "--all", "--format", "json"].map(String::from));
+
+        let output = Command::new(&args[0])
+            .args(&args[1..])
+            .output()
+            .with_context(|| format!("Failed to run {}", args.join(" ")))?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "{} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Self::parse_ps_output(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Compose v2 emits one JSON object per line; older releases emit a JSON array
+    fn parse_ps_output(stdout: &str) -> Result<Vec<ComposeService>> {
+        let trimmed = stdout.trim();
+        if trimmed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if trimmed.starts_with('[') {
+            return serde_json::from_str(trimmed).context("Failed to parse compose ps output");
+        }
+
+        trimmed
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).context("Failed to parse compose ps output"))
+            .collect()
+    }
+
+    fn generate_job_compose(&self, job: &JobDatum) -> Result<String> {
+        let image = job
+            .datum
+            .image
+            .as_ref()
+            .context("Job datum missing image field")?;
+
+        // 🤓 Serialize instead of format!, so names and values with `:`, `#` or `*` stay scalars
+        let mut service = Mapping::new();
+        service.insert("image".into(), image.as_str().into());
+        if let Some(env) = &job.datum.env {
+            let environment: BTreeMap<&String, &String> = env.iter().collect();
+            service.insert("environment".into(), serde_yaml::to_value(environment)?);
+        }
+
+        let mut services = Mapping::new();
+        services.insert(job.datum.name.as_str().into(), service.into());
+        let mut compose = Mapping::new();
+        compose.insert("services".into(), services.into());
+        // serde_yaml leaves `no` bare, which YAML 1.1 composes (PyYAML) read as `false`
+        let mut compose = serde_yaml::to_string(&compose)?;
+        compose.push_str("    restart: \"no\"\n");
+        Ok(compose)
+    }
+
+    fn shell_join(args: &[String]) -> String {
+        args.iter()
+            .map(|arg| {
+                if !arg.is_empty()
+                    && arg
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-_./:=@".contains(c))
+                {
+                    arg.clone()
+                } else {
+                    format!("'{}'", arg.replace('\'', r"'\''"))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Default for DockerComposeAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrchestratorAdapter for DockerComposeAdapter {
+    fn translate_job(&self, job: &JobDatum) -> Result<AdapterOutput> {
+        let compose = self.generate_job_compose(job)?;
+
+        // The job's command + args run as a one-off container of the job service
+        let command = McpCommand {
+            server: job.datum.name.clone(),
+            tool: job.datum.command.clone().unwrap_or_default(),
+            arguments: json!(job.datum.args.clone().unwrap_or_default()),
+        };
+        let invocation = Self::shell_join(&self.compose_run_args(&command));
+
+        let mut metadata = AdapterMetadata::default();
+        if let Some(orch) = &job.datum.orchestration {
+            if orch.gpu_requirements.is_some() {
+                metadata.warnings.push(
+                    "GPU requirements are not translated for docker compose (add deploy.resources manually)"
+                        .to_string(),
+                );
+            }
+            if let Some(stacks) = &orch.requires_stacks {
+                metadata.dependencies.extend(stacks.iter().cloned());
+            }
+        }
+
+        Ok(AdapterOutput {
+            orchestrator: Orchestrator::DockerCompose,
+            manifests: vec![compose, invocation],
+            mcp_commands: vec![command],
+            metadata,
+        })
+    }
+
+    fn translate_stack(&self, stack: &StackDatum) -> Result<AdapterOutput> {
+        // Members are resolved against the datums next to the stack file
+        let b00t_dir = stack
+            .stack_path
+            .parent()
+            .context("Stack path has no parent directory")?;
+        let orchestrator =
+            crate::orchestrator::service::Orchestrator::new(&b00t_dir.to_string_lossy())?;
+
+        let compose = stack.generate_docker_compose(orchestrator.datums())?;
+
+        let metadata = AdapterMetadata {
+            dependencies: stack.get_members(),
+            ..Default::default()
+        };
+
+        Ok(AdapterOutput {
+            orchestrator: Orchestrator::DockerCompose,
+            manifests: vec![compose],
+            mcp_commands: vec![],
+            metadata,
+        })
+    }
+
+    fn orchestrator(&self) -> Orchestrator {
+        Orchestrator::DockerCompose
+    }
+
+    fn is_available(&self) -> bool {
+        Self::detect_compose_command().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter() -> DockerComposeAdapter {
+        DockerComposeAdapter {
+            compose_command: vec!["docker".to_string(), "compose".to_string()],
+            project_name: Some("b00t".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_compose_run_args_from_mcp_command() {
+        let command = McpCommand {
+            server: "grok-guru".to_string(),
+            tool: "ask".to_string(),
+            arguments: json!({"query": "what is b00t", "verbose": true}),
+        };
+
+        let args = adapter().compose_run_args(&command);
+        assert_eq!(
+            args,
+            vec![
+                "docker",
+                "compose",
+                "-p",
+                "b00t",
+                "run",
+                "--rm",
+                "grok-guru",
+                "ask",
+                "--query",
+                "what is b00t",
+                "--verbose"
+            ]
+        );
+
+        let output = adapter().translate_mcp_command(&command);
+        assert_eq!(output.orchestrator, Orchestrator::DockerCompose);
+        assert_eq!(
+            output.manifests[0],
+            "docker compose -p b00t run --rm grok-guru ask --query 'what is b00t' --verbose"
+        );
+    }
+
+    #[test]
+    fn test_job_compose_quotes_yaml_scalars() {
+        let datum: crate::BootDatum = toml::from_str(
+            r#"
+name = "etl: nightly"
+type = "job"
+hint = "ETL"
+image = "ghcr.io/acme/etl:1.2"
+
+[env]
+QUERY = "a: b # not a comment"
+GLOB = "*.csv"
+"#,
+        )
+        .unwrap();
+        let job = JobDatum {
+            datum,
+            job_path: "etl.job.toml".into(),
+        };
+
+        let compose = adapter().generate_job_compose(&job).unwrap();
+        let doc: serde_yaml::Value = serde_yaml::from_str(&compose).unwrap();
+        let service = &doc["services"]["etl: nightly"];
+        assert_eq!(service["image"].as_str(), Some("ghcr.io/acme/etl:1.2"));
+        assert_eq!(
+            service["environment"]["QUERY"].as_str(),
+            Some("a: b # not a comment")
+        );
+        assert_eq!(service["environment"]["GLOB"].as_str(), Some("*.csv"));
+        assert_eq!(service["restart"].as_str(), Some("no"));
+        assert!(compose.ends_with("    restart: \"no\"\n"));
+    }
+
+    #[test]
+    fn test_parse_ps_output_formats() {
+        let ndjson = r#"{"Service":"qdrant","Name":"b00t-qdrant-1","State":"running","Image":"qdrant/qdrant"}
+{"Service":"ollama","Name":"b00t-ollama-1","State":"exited","Image":"ollama/ollama"}"#;
+        let services = DockerComposeAdapter::parse_ps_output(ndjson).unwrap();
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0].service, "qdrant");
+        assert_eq!(services[1].state, "exited");
+
+        let array = r#"[{"Service":"qdrant","Name":"b00t-qdrant-1","State":"running"}]"#;
+        let services = DockerComposeAdapter::parse_ps_output(array).unwrap();
+        assert_eq!(services.len(), 1);
+
+        assert!(
+            DockerComposeAdapter::parse_ps_output("")
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,10 +1,17 @@
 // Orchestrator abstraction layer
 // Provides orchestrator-agnostic deployment via adapters
+// plus the datum service orchestrator (dependency startup)
 
 pub mod adapter;
 pub mod detection;
+pub mod docker_compose_adapter;
 pub mod k8s_adapter;
+pub mod nomad_adapter;
+pub mod service;
 
-pub use adapter::{AdapterOutput, McpCommand, Orchestrator, OrchestratorAdapter};
-pub use detection::detect_orchestrator;
+pub use adapter::{AdapterOutput, McpCommand, OrchestratorAdapter, detect_adapter};
+pub use detection::{detect_compose_command, detect_orchestrator};
+pub use docker_compose_adapter::DockerComposeAdapter;
 pub use k8s_adapter::K8sAdapter;
+pub use nomad_adapter::NomadAdapter;
+pub use service::{Orchestrator, OrchestratorLoadResult, ServiceState};
@@ -0,0 +1,360 @@
+// Nomad adapter: Translates b00t datums to Nomad HCL job specs + `nomad job run` invocations
+// Uses the local nomad CLI (NOMAD_ADDR / NOMAD_TOKEN from the environment)
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::process::Command;
+
+use super::adapter::{
+    AdapterMetadata, AdapterOutput, McpCommand, Orchestrator, OrchestratorAdapter,
+};
+use crate::datum_stack::{JobDatum, StackDatum};
+use crate::{BootDatum, DatumType};
+
+pub struct NomadAdapter {
+    datacenter: String,
+}
+
+/// A single Nomad task (docker driver)
+struct NomadTask<'a> {
+    name: &'a str,
+    image: &'a str,
+    command: Option<&'a str>,
+    args: Vec<String>,
+    env: BTreeMap<&'a String, &'a String>,
+}
+
+impl NomadAdapter {
+    pub fn new() -> Self {
+        Self {
+            datacenter: "dc1".to_string(),
+        }
+    }
+
+    pub fn with_datacenter(mut self, datacenter: String) -> Self {
+        self.datacenter = datacenter;
+        self
+    }
+
+    /// Translate an MCP tool call into a batch job running `<image> <tool> <args...>`
+    pub fn mcp_job_hcl(&self, command: &McpCommand, image: &str) -> String {
+        let task = NomadTask {
+            name: &command.server,
+            image,
+            command: None,
+            args: command.cli_args(),
+            env: BTreeMap::new(),
+        };
+        self.job_hcl(&command.server, "batch", &[task])
+    }
+
+    /// Translate an MCP tool call into an adapter output carrying the HCL job spec
+    pub fn translate_mcp_command(&self, command: &McpCommand, image: &str) -> AdapterOutput {
+        AdapterOutput {
+            orchestrator: Orchestrator::Nomad,
+            manifests: vec![self.mcp_job_hcl(command, image)],
+            mcp_commands: vec![command.clone()],
+            metadata: AdapterMetadata::default(),
+        }
+    }
+
+    /// Write the HCL to a tempfile and submit it with `nomad job run -detach`
+    pub fn run_job(&self, job_name: &str, hcl: &str) -> Result<String> {
+        let job_file = Self::write_job_file(job_name, hcl)?;
+
+        let output = Command::new("nomad")
+            .args(["job", "run", "-detach"])
+            .arg(&job_file)
+            .output()
+            .context("Failed to run nomad job run");
+        let _ = std::fs::remove_file(&job_file);
+        let output = output?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "nomad job run {} failed: {}",
+                job_name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Status of a job via `nomad status <job>` (None when the job is unknown)
+    pub fn job_status(&self, job_name: &str) -> Result<Option<String>> {
+        let output = Command::new("nomad")
+            .args(["status", job_name])
+            .output()
+            .context("Failed to run nomad status")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("No job(s) with prefix or id") || stdout.contains("No job(s)") {
+                return Ok(None);
+            }
+            anyhow::bail!("nomad status {} failed: {}", job_name, stderr.trim());
+        }
+
+        Ok(Self::parse_status_output(&stdout))
+    }
+
+    /// Extract `Status = <value>` from `nomad status <job>` output
+    fn parse_status_output(stdout: &str) -> Option<String> {
+        stdout.lines().find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == "Status").then(|| value.trim().to_string())
+        })
+    }
+
+    fn write_job_file(job_name: &str, hcl: &str) -> Result<PathBuf> {
+        let path = std::env::temp_dir().join(format!(
+            "b00t-{}-{}.nomad.hcl",
+            job_name,
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::write(&path, hcl)
+            .with_context(|| format!("Failed to write Nomad job file {}", path.display()))?;
+        Ok(path)
+    }
+
+    fn job_hcl(&self, job_name: &str, job_type: &str, tasks: &[NomadTask]) -> String {
+        let mut hcl = format!(
+            "job {} {{\n  datacenters = [{}]\n  type        = \"{}\"\n\n  group {} {{\n",
+            hcl_string(job_name),
+            hcl_string(&self.datacenter),
+            job_type,
+            hcl_string(job_name)
+        );
+
+        if job_type == "batch" {
+            hcl.push_str("    restart {\n      attempts = 0\n      mode     = \"fail\"\n    }\n");
+        }
+
+        for task in tasks {
+            hcl.push_str(&format!(
+                "\n    task {} {{\n      driver = \"docker\"\n\n      config {{\n        image = {}\n",
+                hcl_string(task.name),
+                hcl_string(task.image)
+            ));
+            if let Some(command) = task.command {
+                hcl.push_str(&format!("        command = {}\n", hcl_string(command)));
+            }
+            if !task.args.is_empty() {
+                let args: Vec<String> = task.args.iter().map(|a| hcl_string(a)).collect();
+                hcl.push_str(&format!("        args = [{}]\n", args.join(", ")));
+            }
+            hcl.push_str("      }\n");
+
+            if !task.env.is_empty() {
+                hcl.push_str("\n      env {\n");
+                for (key, value) in &task.env {
+                    hcl.push_str(&format!("        {} = {}\n", key, hcl_string(value)));
+                }
+                hcl.push_str("      }\n");
+            }
+            hcl.push_str("    }\n");
+        }
+
+        hcl.push_str("  }\n}\n");
+        hcl
+    }
+
+    fn sorted_env(env: &Option<HashMap<String, String>>) -> BTreeMap<&String, &String> {
+        env.iter().flatten().collect()
+    }
+
+    fn docker_members<'a>(
+        stack: &StackDatum,
+        datums: &'a HashMap<String, BootDatum>,
+    ) -> Result<Vec<&'a BootDatum>> {
+        let mut members = Vec::new();
+        for member_id in stack.get_members() {
+            let datum = datums
+                .get(&member_id)
+                .with_context(|| format!("Member {} not found", member_id))?;
+            if datum.datum_type == Some(DatumType::Docker) {
+                members.push(datum);
+            }
+        }
+        Ok(members)
+    }
+}
+
+/// Quote a value as an HCL string (escaping `${` interpolation)
+fn hcl_string(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace("${", "$${")
+    )
+}
+
+impl Default for NomadAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrchestratorAdapter for NomadAdapter {
+    fn translate_job(&self, job: &JobDatum) -> Result<AdapterOutput> {
+        let image = job
+            .datum
+            .image
+            .as_ref()
+            .context("Job datum missing image field")?;
+
+        let task = NomadTask {
+            name: &job.datum.name,
+            image,
+            command: job.datum.command.as_deref(),
+            args: job.datum.args.clone().unwrap_or_default(),
+            env: Self::sorted_env(&job.datum.env),
+        };
+        let hcl = self.job_hcl(&job.datum.name, "batch", &[task]);
+
+        let mut metadata = AdapterMetadata::default();
+        if let Some(orch) = &job.datum.orchestration {
+            if orch.resource_requirements.is_some() || orch.gpu_requirements.is_some() {
+                metadata.warnings.push(
+                    "Resource/GPU requirements are not translated for nomad (add a resources block manually)"
+                        .to_string(),
+                );
+            }
+            if let Some(stacks) = &orch.requires_stacks {
+                metadata.dependencies.extend(stacks.iter().cloned());
+            }
+        }
+
+        Ok(AdapterOutput {
+            orchestrator: Orchestrator::Nomad,
+            manifests: vec![hcl],
+            mcp_commands: vec![],
+            metadata,
+        })
+    }
+
+    fn translate_stack(&self, stack: &StackDatum) -> Result<AdapterOutput> {
+        // Members are resolved against the datums next to the stack file
+        let b00t_dir = stack
+            .stack_path
+            .parent()
+            .context("Stack path has no parent directory")?;
+        let orchestrator =
+            crate::orchestrator::service::Orchestrator::new(&b00t_dir.to_string_lossy())?;
+
+        let members = Self::docker_members(stack, orchestrator.datums())?;
+        let mut tasks = Vec::new();
+        for datum in &members {
+            let image = datum
+                .image
+                .as_ref()
+                .with_context(|| format!("Docker datum {} has no image", datum.name))?;
+            // Stack env overrides a member's own value for the same key
+            let mut env = Self::sorted_env(&datum.env);
+            env.extend(Self::sorted_env(&stack.datum.env));
+            tasks.push(NomadTask {
+                name: &datum.name,
+                image,
+                command: None,
+                args: Vec::new(),
+                env,
+            });
+        }
+
+        let mut metadata = AdapterMetadata {
+            dependencies: stack.get_members(),
+            ..Default::default()
+        };
+        if members.iter().any(|d| d.docker_args.is_some()) {
+            metadata
+                .warnings
+                .push("docker_args (ports/volumes) are not translated for nomad tasks".to_string());
+        }
+
+        Ok(AdapterOutput {
+            orchestrator: Orchestrator::Nomad,
+            manifests: vec![self.job_hcl(&stack.datum.name, "service", &tasks)],
+            mcp_commands: vec![],
+            metadata,
+        })
+    }
+
+    fn orchestrator(&self) -> Orchestrator {
+        Orchestrator::Nomad
+    }
+
+    fn is_available(&self) -> bool {
+        Command::new("nomad")
+            .arg("version")
+            .output()
+            .map(|out| out.status.success())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_mcp_job_hcl() {
+        let command = McpCommand {
+            server: "grok-guru".to_string(),
+            tool: "ask".to_string(),
+            arguments: json!({"query": "what is \"b00t\" ${HOME}", "verbose": true}),
+        };
+
+        let hcl = NomadAdapter::new().mcp_job_hcl(&command, "ghcr.io/elasticdotventures/grok");
+        assert!(hcl.starts_with("job \"grok-guru\" {\n  datacenters = [\"dc1\"]\n"));
+        assert!(hcl.contains("type        = \"batch\""));
+        assert!(hcl.contains("image = \"ghcr.io/elasticdotventures/grok\""));
+        assert!(
+            hcl.contains(r#"args = ["ask", "--query", "what is \"b00t\" $${HOME}", "--verbose"]"#)
+        );
+    }
+
+    #[test]
+    fn test_stack_hcl_env_overrides_member_env() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("qdrant.docker.toml"),
+            "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"Vector DB\"\nimage = \"qdrant/qdrant\"\n\n[b00t.env]\nQDRANT_PORT = \"6333\"\nLOG_LEVEL = \"info\"\n",
+        )
+        .unwrap();
+        let stack_path = dir.path().join("vecdb.stack.toml");
+        std::fs::write(
+            &stack_path,
+            "[b00t]\nname = \"vec\\\"db\"\ntype = \"stack\"\nhint = \"Vector DB\"\nmembers = [\"qdrant.docker\"]\n\n[b00t.env]\nQDRANT_PORT = \"6334\"\n",
+        )
+        .unwrap();
+
+        let stack = StackDatum::from_file(&stack_path).unwrap();
+        let hcl = &NomadAdapter::new()
+            .translate_stack(&stack)
+            .unwrap()
+            .manifests[0];
+        assert!(hcl.starts_with("job \"vec\\\"db\" {\n"));
+        assert!(hcl.contains("group \"vec\\\"db\" {"));
+        assert!(hcl.contains("task \"qdrant\" {"));
+        assert_eq!(hcl.matches("QDRANT_PORT").count(), 1);
+        assert!(hcl.contains("QDRANT_PORT = \"6334\""));
+        assert!(hcl.contains("LOG_LEVEL = \"info\""));
+    }
+
+    #[test]
+    fn test_parse_status_output() {
+        let stdout = "ID            = grok-guru\nName          = grok-guru\nType          = batch\nStatus        = running\n";
+        assert_eq!(
+            NomadAdapter::parse_status_output(stdout),
+            Some("running".to_string())
+        );
+        assert_eq!(NomadAdapter::parse_status_output(""), None);
+    }
+}
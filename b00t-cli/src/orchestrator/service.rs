@@ -0,0 +1,1885 @@
+// Service orchestrator: silently starts the services a datum depends on
+// 🤓 Walks `depends_on` and capability `requires` recursively, starting docker
+// datums (qdrant, ollama, ...) before the MCP server/agent that needs them
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::process::Command;
+use std::time::Duration;
+
+use super::detection::detect_compose_command;
+use crate::{BootDatum, CapabilityRequirement, DatumType, UnifiedConfig, get_expanded_path};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Datum files that failed to load, with the reason
+pub type LoadWarnings = Vec<(PathBuf, anyhow::Error)>;
+
+/// Container state of a docker datum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceState {
+    Running,
+    /// Container exists but is not running
+    Stopped,
+    /// No container was ever created
+    Missing,
+}
+
+impl std::fmt::Display for ServiceState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            ServiceState::Running => "running",
+            ServiceState::Stopped => "stopped",
+            ServiceState::Missing => "missing",
+        })
+    }
+}
+
+/// How long a started container may take to show up as running (30 × 200ms)
+const DEFAULT_START_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// Restart policies accepted in a datum's `restart` key
+const RESTART_POLICIES: [&str; 4] = ["no", "always", "unless-stopped", "on-failure"];
+const DEFAULT_RESTART_POLICY: &str = "unless-stopped";
+
+/// Datum-driven service orchestrator
+pub struct Orchestrator {
+    /// Loaded datums keyed by `<name>.<type>` (e.g. `qdrant.docker`)
+    datums: HashMap<String, BootDatum>,
+    /// Global cap on per-datum start/health timeouts
+    max_start_timeout: Option<Duration>,
+    /// `default_container_runtime` from `~/.b00t/config.toml`, read once at load
+    configured_runtime: Option<String>,
+    /// Directory the datums were loaded from; `compose_file` is relative to it
+    datum_dir: PathBuf,
+}
+
+/// State shared across one `ensure_dependencies` call
+#[derive(Default)]
+struct EnsureRun {
+    /// Keys started so far, in start order
+    started: Vec<String>,
+    /// Container run-state cache (container name → running), so a container shared by
+    /// many datums is only checked once; entries are dropped once the container is started
+    running: BTreeMap<String, bool>,
+}
+
+/// Orchestrator plus the datum files that could not be loaded
+pub struct OrchestratorLoadResult {
+    pub orchestrator: Orchestrator,
+    /// Unreadable/unparseable datum files with the reason they were skipped
+    pub load_warnings: LoadWarnings,
+}
+
+impl Orchestrator {
+    /// Load every datum found in the `_b00t_` directory at `path`
+    /// Files that fail to load are skipped (logged at debug level)
+    pub fn new(path: &str) -> Result<Self> {
+        let result = Self::load(path)?;
+        for (file_path, e) in &result.load_warnings {
+            tracing::debug!(file = %file_path.display(), error = format!("{:#}", e), "Skipping datum");
+        }
+        Ok(result.orchestrator)
+    }
+
+    /// Load every datum at `path`, returning load failures instead of dropping them
+    pub fn load(path: &str) -> Result<OrchestratorLoadResult> {
+        let (datums, load_warnings) = Self::load_all_datums(path)?;
+        let configured_runtime = match crate::commands::config::CliConfig::load() {
+            Ok(config) => config.default_container_runtime,
+            Err(e) => {
+                tracing::debug!(error = format!("{:#}", e), "Ignoring b00t config");
+                None
+            }
+        };
+        Ok(OrchestratorLoadResult {
+            orchestrator: Self {
+                datums,
+                max_start_timeout: None,
+                configured_runtime,
+                datum_dir: get_expanded_path(path)?,
+            },
+            load_warnings,
+        })
+    }
+
+    /// Cap every datum's `start_timeout_secs`/`health_timeout_secs` at `secs`
+    pub fn with_max_start_timeout(mut self, secs: u64) -> Self {
+        self.max_start_timeout = Some(Duration::from_secs(secs));
+        self
+    }
+
+    /// Loaded datums keyed by `<name>.<type>`
+    pub fn datums(&self) -> &HashMap<String, BootDatum> {
+        &self.datums
+    }
+
+    /// Every loaded datum as pretty JSON keyed by `<name>.<type>`
+    ///
+    /// 🤓 Keys are sorted so two snapshots diff cleanly
+    pub fn snapshot(&self) -> Result<String> {
+        let sorted: BTreeMap<&String, &BootDatum> = self.datums.iter().collect();
+        serde_json::to_string_pretty(&sorted).context("Failed to serialize orchestrator state")
+    }
+
+    fn load_all_datums(path: &str) -> Result<(HashMap<String, BootDatum>, LoadWarnings)> {
+        let dir = get_expanded_path(path)?;
+        let mut datums = HashMap::new();
+        let mut warnings = Vec::new();
+
+        for entry in std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read datum directory {}", dir.display()))?
+        {
+            let file_path = entry?.path();
+            let Some(file_name) = file_path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if Self::extract_datum_name(file_name).is_none() {
+                continue;
+            }
+
+            // 🤓 metadata() follows symlinks, so a dangling link errors here instead of
+            // looking like "not a file" and vanishing from the datum set
+            match std::fs::metadata(&file_path) {
+                Ok(meta) if meta.is_file() => {}
+                Ok(_) => continue,
+                Err(e) => {
+                    let error = match std::fs::read_link(&file_path) {
+                        Ok(target) => anyhow::anyhow!(
+                            "Broken symlink: target {} does not exist",
+                            target.display()
+                        ),
+                        Err(_) => anyhow::Error::new(e).context("Failed to stat datum"),
+                    };
+                    warnings.push((file_path, error));
+                    continue;
+                }
+            }
+
+            let content = match std::fs::read_to_string(&file_path) {
+                Ok(content) => content,
+                Err(e) => {
+                    warnings.push((
+                        file_path,
+                        anyhow::Error::new(e).context("Failed to read datum"),
+                    ));
+                    continue;
+                }
+            };
+
+            let mut datum = match toml::from_str::<UnifiedConfig>(&content) {
+                Ok(config) => config.b00t,
+                Err(e) => {
+                    warnings.push((
+                        file_path,
+                        anyhow::Error::new(e).context("Failed to parse datum"),
+                    ));
+                    continue;
+                }
+            };
+
+            // Fall back to the filename suffix when the datum omits `type`
+            if datum.datum_type.is_none() {
+                datum.datum_type = match file_name.trim_end_matches(".toml").rsplit('.').next() {
+                    Some("docker") => Some(DatumType::Docker),
+                    Some("mcp") => Some(DatumType::Mcp),
+                    Some("cli") => Some(DatumType::Cli),
+                    Some("bash") => Some(DatumType::Bash),
+                    Some("vscode") => Some(DatumType::Vscode),
+                    Some("k8s") => Some(DatumType::K8s),
+                    Some("apt") => Some(DatumType::Apt),
+                    Some("nix") => Some(DatumType::Nix),
+                    Some("ai") => Some(DatumType::Ai),
+                    Some("ai_model") => Some(DatumType::AiModel),
+                    Some("api") => Some(DatumType::Api),
+                    Some("stack") => Some(DatumType::Stack),
+                    Some("agent") => Some(DatumType::Agent),
+                    Some("job") => Some(DatumType::Job),
+                    _ => None,
+                };
+            }
+
+            let key = Self::make_key(&datum.name, datum.datum_type.as_ref());
+            tracing::debug!(datum_key = %key, file = %file_path.display(), "Loaded datum");
+            datums.insert(key, datum);
+        }
+
+        Ok((datums, warnings))
+    }
+
+    /// Strip the `.<type>.toml` suffix from a datum filename
+    /// (`qdrant.docker.toml` -> `qdrant`); `None` for unknown types or an empty name
+    pub fn extract_datum_name(filename: &str) -> Option<String> {
+        let stem = filename.strip_suffix(".toml")?;
+        let types = [
+            "docker", "mcp", "cli", "bash", "vscode", "k8s", "apt", "nix", "ai", "ai_model", "api",
+            "stack", "agent", "job",
+        ];
+
+        types
+            .iter()
+            .find_map(|t| stem.strip_suffix(&format!(".{}", t)))
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+
+    fn make_key(name: &str, datum_type: Option<&DatumType>) -> String {
+        match datum_type {
+            Some(t) => format!("{}.{}", name, t.type_slug()),
+            None => name.to_string(),
+        }
+    }
+
+    /// Resolve a bare datum name (`rag-api`) or full key (`rag-api.api`) to a loaded key.
+    /// When several types share the name (`qdrant.docker`, `qdrant.mcp`) the docker
+    /// datum wins, as the only type the orchestrator runs, then the first key by name.
+    fn find_key(&self, name: &str) -> Option<String> {
+        if self.datums.contains_key(name) {
+            return Some(name.to_string());
+        }
+        self.datums
+            .iter()
+            .filter(|(_, datum)| datum.name == name)
+            .min_by_key(|(key, datum)| (datum.datum_type != Some(DatumType::Docker), key.as_str()))
+            .map(|(key, _)| key.clone())
+    }
+
+    /// Providers for `capability`: preferred implementations first, then any other provider
+    fn capability_candidates(
+        &self,
+        capability: &str,
+        requirement: &CapabilityRequirement,
+    ) -> Vec<String> {
+        let mut candidates: Vec<String> = requirement
+            .prefer
+            .iter()
+            .flatten()
+            .filter_map(|name| self.find_key(name))
+            .collect();
+        let mut providers: Vec<&String> = self
+            .datums
+            .iter()
+            .filter(|(_, d)| {
+                d.provides.as_ref().and_then(|p| p.capability.as_deref()) == Some(capability)
+            })
+            .map(|(key, _)| key)
+            .collect();
+        providers.sort();
+        for key in providers {
+            if !candidates.contains(key) {
+                candidates.push(key.clone());
+            }
+        }
+        candidates
+    }
+
+    /// Render the loaded datums as a Graphviz DOT digraph
+    ///
+    /// `depends_on` (and stack `members`) become plain edges; capability
+    /// requirements become edges to each candidate provider labeled with the
+    /// capability name. Unresolvable capabilities point at a dashed placeholder.
+    pub fn dependency_graph(&self) -> String {
+        let mut keys: Vec<&String> = self.datums.keys().collect();
+        keys.sort();
+
+        let mut dot = String::from("digraph b00t {\n    rankdir=LR;\n");
+        for key in &keys {
+            dot.push_str(&format!("    \"{}\";\n", key));
+        }
+
+        for key in &keys {
+            let datum = &self.datums[*key];
+
+            for dep in datum
+                .depends_on
+                .iter()
+                .flatten()
+                .chain(datum.members.iter().flatten())
+            {
+                dot.push_str(&format!("    \"{}\" -> \"{}\";\n", key, dep));
+            }
+
+            let Some(requires) = &datum.requires else {
+                continue;
+            };
+            let mut requirements: Vec<_> = requires.iter().collect();
+            requirements.sort_by(|a, b| a.0.cmp(b.0));
+
+            for (requirement_name, requirement) in requirements {
+                let capability = requirement
+                    .capability
+                    .as_deref()
+                    .unwrap_or(requirement_name);
+                let candidates = self.capability_candidates(capability, requirement);
+
+                if candidates.is_empty() {
+                    let missing = requirement.fallback.as_deref().unwrap_or(capability);
+                    dot.push_str(&format!(
+                        "    \"{}\" [style=dashed];\n    \"{}\" -> \"{}\" [label=\"{}\", style=dashed];\n",
+                        missing, key, missing, capability
+                    ));
+                }
+                for candidate in candidates {
+                    dot.push_str(&format!(
+                        "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                        key, candidate, capability
+                    ));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Start everything `datum_key` depends on, returning the keys that were started
+    pub async fn ensure_dependencies(&self, datum_key: &str) -> Result<Vec<String>> {
+        let mut run = EnsureRun::default();
+        self.ensure_dependencies_inner(datum_key, HashSet::new(), Vec::new(), &mut run)
+            .await?;
+        Ok(run.started)
+    }
+
+    /// Record `datum_key` on the current path, failing if it is already on it
+    fn enter(
+        datum_key: &str,
+        mut visited: HashSet<String>,
+        mut chain: Vec<String>,
+    ) -> Result<(HashSet<String>, Vec<String>)> {
+        chain.push(datum_key.to_string());
+        if !visited.insert(datum_key.to_string()) {
+            anyhow::bail!("Dependency cycle detected: {}", chain.join(" -> "));
+        }
+        Ok((visited, chain))
+    }
+
+    fn ensure_dependencies_inner<'a>(
+        &'a self,
+        datum_key: &'a str,
+        visited: HashSet<String>,
+        chain: Vec<String>,
+        run: &'a mut EnsureRun,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let (visited, chain) = Self::enter(datum_key, visited, chain)?;
+
+            let datum = self
+                .datums
+                .get(datum_key)
+                .with_context(|| format!("Datum '{}' not found", datum_key))?;
+
+            // Stacks start their members the same way other datums start dependencies
+            let deps = datum
+                .depends_on
+                .iter()
+                .flatten()
+                .chain(datum.members.iter().flatten());
+
+            for dep_key in deps {
+                if !self.datums.contains_key(dep_key) {
+                    tracing::debug!(datum_key, dependency = %dep_key, "Skipping unknown dependency");
+                    continue;
+                }
+
+                // Dependencies of dependencies come up first
+                self.ensure_dependencies_inner(dep_key, visited.clone(), chain.clone(), run)
+                    .await?;
+                self.start_if_needed(dep_key, run).await?;
+            }
+
+            self.ensure_api_dependencies(datum, &visited, &chain, run)
+                .await
+        })
+    }
+
+    fn ensure_api_dependencies<'a>(
+        &'a self,
+        datum: &'a BootDatum,
+        visited: &'a HashSet<String>,
+        chain: &'a [String],
+        run: &'a mut EnsureRun,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let Some(requires) = &datum.requires else {
+                return Ok(());
+            };
+
+            for (requirement_name, requirement) in requires {
+                self.resolve_capability(requirement_name, requirement, visited, chain, run)
+                    .await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Satisfy a capability requirement using the first provider that comes up.
+    /// The fallback is only tried once every candidate has failed (or there are
+    /// none); if it fails too, the error lists every attempt.
+    fn resolve_capability<'a>(
+        &'a self,
+        requirement_name: &'a str,
+        requirement: &'a CapabilityRequirement,
+        visited: &'a HashSet<String>,
+        chain: &'a [String],
+        run: &'a mut EnsureRun,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let capability = requirement
+                .capability
+                .as_deref()
+                .unwrap_or(requirement_name);
+
+            let candidates = self.capability_candidates(capability, requirement);
+            let mut errors = Vec::new();
+
+            for candidate in &candidates {
+                match self
+                    .ensure_dependencies_inner(candidate, visited.clone(), chain.to_vec(), run)
+                    .await
+                {
+                    Ok(()) => {
+                        tracing::debug!(capability, provider = %candidate, "Capability provided");
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            capability,
+                            provider = %candidate,
+                            error = %e,
+                            "Capability provider unavailable"
+                        );
+                        errors.push(format!("{}: {:#}", candidate, e));
+                    }
+                }
+            }
+
+            if let Some(fallback) = &requirement.fallback {
+                match self.find_key(fallback) {
+                    Some(fallback_key) => match self
+                        .ensure_dependencies_inner(
+                            &fallback_key,
+                            visited.clone(),
+                            chain.to_vec(),
+                            run,
+                        )
+                        .await
+                    {
+                        Ok(()) => return Ok(()),
+                        Err(e) => errors.push(format!("fallback {}: {:#}", fallback_key, e)),
+                    },
+                    None => errors.push(format!("fallback '{}' not found", fallback)),
+                }
+            }
+
+            if errors.is_empty() {
+                anyhow::bail!("No provider available for capability '{}'", capability)
+            }
+            anyhow::bail!(
+                "No provider available for capability '{}':\n  - {}",
+                capability,
+                errors.join("\n  - ")
+            )
+        })
+    }
+
+    async fn start_if_needed(&self, datum_key: &str, run: &mut EnsureRun) -> Result<()> {
+        let Some(datum) = self.datums.get(datum_key) else {
+            return Ok(());
+        };
+
+        if run.started.iter().any(|k| k == datum_key) || !self.needs_start(datum, run)? {
+            return Ok(());
+        }
+
+        // The cached state is stale from here on, whether or not the start succeeds
+        run.running.remove(&datum.name);
+        self.start_service(datum).await?;
+        run.started.push(datum_key.to_string());
+        Ok(())
+    }
+
+    /// Only docker datums are long-running services the orchestrator owns
+    fn needs_start(&self, datum: &BootDatum, run: &mut EnsureRun) -> Result<bool> {
+        match datum.datum_type {
+            Some(DatumType::Docker) => Ok(!self.is_docker_running_cached(&datum.name, run)?),
+            _ => Ok(false),
+        }
+    }
+
+    fn is_docker_running_cached(&self, name: &str, run: &mut EnsureRun) -> Result<bool> {
+        if let Some(&running) = run.running.get(name) {
+            return Ok(running);
+        }
+        let running = self.is_docker_running(name)?;
+        run.running.insert(name.to_string(), running);
+        Ok(running)
+    }
+
+    async fn start_service(&self, datum: &BootDatum) -> Result<()> {
+        match datum.datum_type {
+            Some(DatumType::Docker) => self.start_docker_service(datum).await,
+            _ => Ok(()),
+        }
+    }
+
+    async fn start_docker_service(&self, datum: &BootDatum) -> Result<()> {
+        let runtime = self.get_container_runtime()?;
+
+        let output = if datum.use_compose == Some(true) {
+            // 🤓 The compose service should set `container_name` to the datum name
+            // so the readiness check can find it
+            let args = Self::compose_up_args(self.get_compose_command()?, &self.datum_dir, datum);
+
+            tracing::debug!(datum_name = %datum.name, command = %args.join(" "), "Starting compose service");
+            // 🤓 Run from the datum directory so a default docker-compose.yml there is
+            // picked up regardless of the caller's cwd
+            Command::new(&args[0])
+                .args(&args[1..])
+                .current_dir(&self.datum_dir)
+                .output()?
+        } else if self.docker_container_exists(&datum.name)? {
+            tracing::debug!(datum_name = %datum.name, runtime = %runtime, "Restarting existing container");
+            Command::new(&runtime)
+                .args(["start", &datum.name])
+                .output()?
+        } else {
+            let image = datum
+                .image
+                .as_ref()
+                .with_context(|| format!("Docker datum '{}' has no image", datum.name))?;
+
+            let restart_policy = Self::restart_policy(datum)?;
+            self.ensure_named_volumes(&runtime, datum)?;
+
+            let mut args = vec![
+                "run".to_string(),
+                "-d".to_string(),
+                "--name".to_string(),
+                datum.name.clone(),
+                "--restart".to_string(),
+                restart_policy.to_string(),
+            ];
+            let docker_args = datum.docker_args.clone().unwrap_or_default();
+            match &datum.pod_name {
+                Some(pod) if runtime == "podman" => {
+                    // 🤓 Containers in a pod share its network namespace, so ports
+                    // can only be published on the pod itself
+                    let (ports, rest) = Self::split_published_ports(&docker_args);
+                    self.ensure_pod(pod, &ports)?;
+                    args.extend(["--pod".to_string(), pod.clone()]);
+                    args.extend(rest);
+                    if let Some(network) = &datum.network {
+                        tracing::warn!(
+                            datum_name = %datum.name,
+                            network = %network,
+                            pod_name = %pod,
+                            "Containers in a pod use the pod's network, ignoring network"
+                        );
+                    }
+                }
+                Some(pod) => {
+                    tracing::warn!(
+                        datum_name = %datum.name,
+                        pod_name = %pod,
+                        runtime = %runtime,
+                        "pod_name needs podman, ignoring it"
+                    );
+                    args.extend(docker_args);
+                }
+                None => args.extend(docker_args),
+            }
+            if let Some(network) = datum
+                .network
+                .as_ref()
+                .filter(|_| !(runtime == "podman" && datum.pod_name.is_some()))
+            {
+                self.ensure_network(&runtime, network)?;
+                args.extend(["--network".to_string(), network.clone()]);
+            }
+            args.push(image.clone());
+
+            tracing::debug!(
+                datum_name = %datum.name,
+                command = %format!("{} {}", runtime, args.join(" ")),
+                "Running container"
+            );
+            Command::new(&runtime).args(&args).output()?
+        };
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to start {}: {}",
+                datum.name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        self.wait_for_ready(datum).await
+    }
+
+    /// Stop the container behind a docker datum
+    pub async fn stop_service(&self, datum_key: &str) -> Result<()> {
+        let datum = self
+            .datums
+            .get(datum_key)
+            .with_context(|| format!("Datum '{}' not found", datum_key))?;
+
+        if !matches!(datum.datum_type, Some(DatumType::Docker)) {
+            anyhow::bail!("Datum '{}' is not a docker service", datum_key);
+        }
+
+        let runtime = self.get_container_runtime()?;
+        let output = Command::new(&runtime)
+            .args(["stop", &datum.name])
+            .output()
+            .with_context(|| format!("Failed to run {} stop", runtime))?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to stop {}: {}",
+                datum.name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    /// Stop every running docker datum, returning the keys that were stopped
+    ///
+    /// Individual failures are reported but only fail the call when nothing
+    /// could be stopped at all.
+    pub async fn stop_all(&self) -> Result<Vec<String>> {
+        let mut keys: Vec<&String> = self
+            .datums
+            .iter()
+            .filter(|(_, d)| matches!(d.datum_type, Some(DatumType::Docker)))
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort();
+
+        let mut stopped = Vec::new();
+        let mut errors: Vec<(String, anyhow::Error)> = Vec::new();
+
+        for key in keys {
+            let datum = &self.datums[key];
+            match self.is_docker_running(&datum.name) {
+                Ok(false) => continue,
+                Ok(true) => {}
+                Err(e) => {
+                    errors.push((key.clone(), e));
+                    continue;
+                }
+            }
+
+            match self.stop_service(key).await {
+                Ok(()) => stopped.push(key.clone()),
+                Err(e) => errors.push((key.clone(), e)),
+            }
+        }
+
+        if stopped.is_empty() && !errors.is_empty() {
+            let details: Vec<String> = errors
+                .iter()
+                .map(|(key, e)| format!("{}: {}", key, e))
+                .collect();
+            anyhow::bail!("Failed to stop services: {}", details.join("; "));
+        }
+
+        for (key, e) in &errors {
+            tracing::warn!(datum_key = %key, error = %e, "Failed to stop service");
+        }
+
+        Ok(stopped)
+    }
+
+    /// Everything `datum_key` depends on (`depends_on` + stack members), dependencies first
+    pub fn start_order(&self, datum_key: &str) -> Result<Vec<String>> {
+        let mut order = Vec::new();
+        self.collect_start_order(datum_key, &mut Vec::new(), &mut order)?;
+        order.retain(|key| key != datum_key);
+        Ok(order)
+    }
+
+    fn collect_start_order(
+        &self,
+        datum_key: &str,
+        chain: &mut Vec<String>,
+        order: &mut Vec<String>,
+    ) -> Result<()> {
+        if chain.iter().any(|k| k == datum_key) {
+            chain.push(datum_key.to_string());
+            anyhow::bail!("Dependency cycle detected: {}", chain.join(" -> "));
+        }
+        if order.iter().any(|k| k == datum_key) {
+            return Ok(());
+        }
+
+        let datum = self
+            .datums
+            .get(datum_key)
+            .with_context(|| format!("Datum '{}' not found", datum_key))?;
+
+        chain.push(datum_key.to_string());
+        for dep_key in datum
+            .depends_on
+            .iter()
+            .flatten()
+            .chain(datum.members.iter().flatten())
+        {
+            if self.datums.contains_key(dep_key) {
+                self.collect_start_order(dep_key, chain, order)?;
+            }
+        }
+        chain.pop();
+
+        order.push(datum_key.to_string());
+        Ok(())
+    }
+
+    /// Stop the running docker services behind `datum_key` in reverse start order
+    pub async fn stop_dependencies(&self, datum_key: &str) -> Result<Vec<String>> {
+        let mut stopped = Vec::new();
+
+        for key in self.start_order(datum_key)?.iter().rev() {
+            let datum = &self.datums[key];
+            if !matches!(datum.datum_type, Some(DatumType::Docker))
+                || !self.is_docker_running(&datum.name)?
+            {
+                continue;
+            }
+
+            match self.stop_service(key).await {
+                Ok(()) => stopped.push(key.clone()),
+                Err(e) => tracing::warn!(datum_key = %key, error = %e, "Failed to stop service"),
+            }
+        }
+
+        Ok(stopped)
+    }
+
+    /// Apply the global `max_start_timeout` cap to a per-datum timeout
+    fn capped(&self, timeout: Duration) -> Duration {
+        self.max_start_timeout
+            .map_or(timeout, |max| timeout.min(max))
+    }
+
+    /// Time allowed for the container to show up as running
+    fn start_timeout(&self, datum: &BootDatum) -> Duration {
+        self.capped(
+            datum
+                .start_timeout_secs
+                .map_or(DEFAULT_START_TIMEOUT, Duration::from_secs),
+        )
+    }
+
+    /// Wait for the container to run, then for `health_url` (if any) to answer 2xx
+    async fn wait_for_ready(&self, datum: &BootDatum) -> Result<()> {
+        let timeout = self.start_timeout(datum);
+        let deadline = tokio::time::Instant::now() + timeout;
+        while !self.is_docker_running(&datum.name)? {
+            if tokio::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "Service {} did not become ready within {}s",
+                    datum.name,
+                    timeout.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+
+        match &datum.health_url {
+            Some(url) => {
+                let timeout =
+                    self.capped(Duration::from_secs(datum.health_timeout_secs.unwrap_or(30)));
+                Self::wait_for_health(&datum.name, url, timeout).await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Poll `url` until it returns a 2xx status or `timeout` elapses
+    async fn wait_for_health(name: &str, url: &str, timeout: Duration) -> Result<()> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .build()?;
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            match client.get(url).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => {
+                    tracing::debug!(datum_name = name, url, status = %response.status(), "Health check not ready");
+                }
+                Err(e) => {
+                    tracing::debug!(datum_name = name, url, error = %e, "Health check failed");
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "Service {} did not pass health check {} within {}s",
+                    name,
+                    url,
+                    timeout.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+
+    /// `restart` policy for `docker run`, defaulting to `unless-stopped`
+    fn restart_policy(datum: &BootDatum) -> Result<&str> {
+        let policy = datum
+            .restart_policy
+            .as_deref()
+            .unwrap_or(DEFAULT_RESTART_POLICY);
+        if !RESTART_POLICIES.contains(&policy) {
+            anyhow::bail!(
+                "Invalid restart policy '{}' for {} (expected one of: {})",
+                policy,
+                datum.name,
+                RESTART_POLICIES.join(", ")
+            );
+        }
+        Ok(policy)
+    }
+
+    /// `docker_args` split into published port mappings and everything else
+    pub(crate) fn split_published_ports(docker_args: &[String]) -> (Vec<String>, Vec<String>) {
+        let (mut ports, mut rest) = (Vec::new(), Vec::new());
+        let mut args = docker_args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-p" | "--publish" => ports.extend(args.next().cloned()),
+                other => match other
+                    .strip_prefix("--publish=")
+                    .or_else(|| other.strip_prefix("-p="))
+                {
+                    Some(port) => ports.push(port.to_string()),
+                    None => rest.push(arg.clone()),
+                },
+            }
+        }
+        (ports, rest)
+    }
+
+    /// Create podman pod `pod` (publishing `ports`) unless it already exists
+    fn ensure_pod(&self, pod: &str, ports: &[String]) -> Result<()> {
+        let exists = Command::new("podman")
+            .args(["pod", "exists", pod])
+            .status()
+            .context("Failed to run podman pod exists")?
+            .success();
+        if exists {
+            if !ports.is_empty() {
+                tracing::warn!(
+                    pod_name = pod,
+                    ports = %ports.join(", "),
+                    "Pod already exists; ports are not added to it"
+                );
+            }
+            return Ok(());
+        }
+
+        let mut args = vec![
+            "pod".to_string(),
+            "create".to_string(),
+            "--name".to_string(),
+            pod.to_string(),
+        ];
+        for port in ports {
+            args.extend(["-p".to_string(), port.clone()]);
+        }
+        tracing::debug!(pod_name = pod, command = %format!("podman {}", args.join(" ")), "Creating pod");
+        let output = Command::new("podman")
+            .args(&args)
+            .output()
+            .context("Failed to run podman pod create")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to create pod {}: {}",
+                pod,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    /// Named volumes mounted by `-v`/`--volume` in `docker_args`
+    ///
+    /// 🤓 Bind mounts (`/host:/ctr`, `./dir:/ctr`, `~/x:/ctr`) and anonymous volumes
+    /// (`-v /data`) are skipped: only `name:/path` creates a docker-managed volume
+    fn named_volumes(docker_args: &[String]) -> Vec<String> {
+        let mut specs = Vec::new();
+        let mut args = docker_args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-v" | "--volume" => specs.extend(args.next().map(String::as_str)),
+                other => specs.extend(
+                    other
+                        .strip_prefix("--volume=")
+                        .or_else(|| other.strip_prefix("-v=")),
+                ),
+            }
+        }
+
+        let mut volumes: Vec<String> = Vec::new();
+        for spec in specs {
+            let Some((source, _)) = spec.split_once(':') else {
+                continue;
+            };
+            let is_name = source
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric())
+                && source
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "_.-".contains(c));
+            if is_name && !volumes.iter().any(|v| v == source) {
+                volumes.push(source.to_string());
+            }
+        }
+        volumes
+    }
+
+    /// Create the datum's named volumes that don't exist yet, so `docker run` can mount them
+    fn ensure_named_volumes(&self, runtime: &str, datum: &BootDatum) -> Result<()> {
+        let volumes = Self::named_volumes(datum.docker_args.as_deref().unwrap_or_default());
+        for volume in volumes {
+            if Self::runtime_object_exists(runtime, "volume", &volume)? {
+                continue;
+            }
+
+            tracing::debug!(datum_name = %datum.name, runtime, volume = %volume, "Creating volume");
+            let output = Command::new(runtime)
+                .args(["volume", "create", &volume])
+                .output()
+                .with_context(|| format!("Failed to run {} volume create", runtime))?;
+            if !output.status.success() {
+                anyhow::bail!(
+                    "Failed to create volume {} for {}: {}",
+                    volume,
+                    datum.name,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Create the datum's network if it doesn't exist yet, so `docker run --network` can join it
+    fn ensure_network(&self, runtime: &str, network: &str) -> Result<()> {
+        if Self::runtime_object_exists(runtime, "network", network)? {
+            return Ok(());
+        }
+
+        tracing::debug!(runtime, network, "Creating network");
+        let output = Command::new(runtime)
+            .args(["network", "create", network])
+            .output()
+            .with_context(|| format!("Failed to run {} network create", runtime))?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Failed to create network {}: {}",
+                network,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether `<runtime> <kind> ls` lists a volume/network called exactly `name`
+    fn runtime_object_exists(runtime: &str, kind: &str, name: &str) -> Result<bool> {
+        let output = Command::new(runtime)
+            .args([
+                kind,
+                "ls",
+                "--filter",
+                &format!("name={}", name),
+                "--format",
+                "{{.Name}}",
+            ])
+            .output()
+            .with_context(|| format!("Failed to run {} {} ls", runtime, kind))?;
+        Ok(Self::lists_name(
+            &String::from_utf8_lossy(&output.stdout),
+            name,
+        ))
+    }
+
+    /// 🤓 `name=` filters match substrings, so compare the listed names exactly
+    fn lists_name(ls_output: &str, name: &str) -> bool {
+        ls_output.lines().any(|line| line.trim() == name)
+    }
+
+    /// `default_container_runtime` from `~/.b00t/config.toml` if installed,
+    /// otherwise prefer docker and fall back to podman
+    pub fn get_container_runtime(&self) -> Result<String> {
+        if let Some(runtime) = &self.configured_runtime {
+            if Self::is_command_available(runtime) {
+                return Ok(runtime.clone());
+            }
+            tracing::warn!(
+                runtime = %runtime,
+                "Configured container runtime not found, auto-detecting"
+            );
+        }
+
+        if Self::is_command_available("docker") {
+            Ok("docker".to_string())
+        } else if Self::is_command_available("podman") {
+            Ok("podman".to_string())
+        } else {
+            anyhow::bail!("No container runtime found (install docker or podman)")
+        }
+    }
+
+    /// `<compose> [-f <compose_file>] up -d <name>` for a `use_compose` datum
+    fn compose_up_args(
+        mut compose: Vec<String>,
+        datum_dir: &std::path::Path,
+        datum: &BootDatum,
+    ) -> Vec<String> {
+        if let Some(file) = &datum.compose_file {
+            let file = shellexpand::tilde(file).to_string();
+            compose.extend([
+                "-f".to_string(),
+                datum_dir.join(file).to_string_lossy().to_string(),
+            ]);
+        }
+        compose.extend(["up".to_string(), "-d".to_string(), datum.name.clone()]);
+        compose
+    }
+
+    /// Compose front-end for `use_compose` datums: docker compose > podman-compose > podman compose
+    pub fn get_compose_command(&self) -> Result<Vec<String>> {
+        detect_compose_command().context(
+            "No compose command found (install docker compose, docker-compose or podman-compose)",
+        )
+    }
+
+    fn is_command_available(cmd: &str) -> bool {
+        Command::new("which")
+            .arg(cmd)
+            .output()
+            .map(|out| out.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Whether a container named `name` is running
+    pub fn is_docker_running(&self, name: &str) -> Result<bool> {
+        self.container_listed(name, &["ps"])
+    }
+
+    /// `logs` arguments for `container` (`--tail` before `--follow`, like `docker logs -n 50 -f`)
+    pub fn logs_args(container: &str, follow: bool, tail: Option<u32>) -> Vec<String> {
+        let mut args = vec!["logs".to_string()];
+        if let Some(tail) = tail {
+            args.extend(["--tail".to_string(), tail.to_string()]);
+        }
+        if follow {
+            args.push("--follow".to_string());
+        }
+        args.push(container.to_string());
+        args
+    }
+
+    /// Stream the container logs of docker datum `datum_key` (`qdrant` or `qdrant.docker`)
+    /// to this process's stdout/stderr
+    pub fn stream_logs(&self, datum_key: &str, follow: bool, tail: Option<u32>) -> Result<()> {
+        let key = self
+            .find_key(datum_key)
+            .with_context(|| format!("Datum '{}' not found", datum_key))?;
+        let datum = &self.datums[&key];
+        if datum.datum_type != Some(DatumType::Docker) {
+            anyhow::bail!("Datum '{}' is not a docker service", key);
+        }
+
+        let runtime = self.get_container_runtime()?;
+        if !self.is_docker_running(&datum.name)? {
+            let state = if self.docker_container_exists(&datum.name)? {
+                "stopped"
+            } else {
+                "never created"
+            };
+            anyhow::bail!(
+                "Container '{}' for {} is not running ({}); see `b00t orchestrator status`, or `{} logs {}` for a stopped container's last output",
+                datum.name,
+                key,
+                state,
+                runtime,
+                datum.name
+            );
+        }
+
+        let status = Command::new(&runtime)
+            .args(Self::logs_args(&datum.name, follow, tail))
+            .status()
+            .with_context(|| format!("Failed to run {} logs", runtime))?;
+        if !status.success() {
+            anyhow::bail!("{} logs {} exited with {}", runtime, datum.name, status);
+        }
+        Ok(())
+    }
+
+    /// Whether a container named `name` exists (running or stopped)
+    pub fn docker_container_exists(&self, name: &str) -> Result<bool> {
+        self.container_listed(name, &["ps", "-a"])
+    }
+
+    /// Keys of docker datums whose container is running, sorted by name
+    pub async fn list_running(&self) -> Result<Vec<String>> {
+        self.docker_keys_where(|state| state == ServiceState::Running)
+    }
+
+    /// Keys of docker datums whose container is not running (stopped or missing), sorted by name
+    pub async fn list_stopped(&self) -> Result<Vec<String>> {
+        self.docker_keys_where(|state| state != ServiceState::Running)
+    }
+
+    fn docker_keys_where(&self, wanted: impl Fn(ServiceState) -> bool) -> Result<Vec<String>> {
+        Ok(self
+            .docker_states()?
+            .into_iter()
+            .filter(|(_, state)| wanted(*state))
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+
+    /// Container state of every docker datum (by key), sorted by datum name
+    ///
+    /// 🤓 Runs `ps` subprocesses per datum; callers on an async runtime should
+    /// wrap it in `spawn_blocking`
+    pub fn docker_states(&self) -> Result<Vec<(&String, ServiceState)>> {
+        self.docker_states_with(|name| self.container_state(name))
+    }
+
+    fn docker_states_with(
+        &self,
+        lookup: impl Fn(&str) -> Result<ServiceState>,
+    ) -> Result<Vec<(&String, ServiceState)>> {
+        let mut docker_datums: Vec<(&String, &BootDatum)> = self
+            .datums
+            .iter()
+            .filter(|(_, datum)| datum.datum_type == Some(DatumType::Docker))
+            .collect();
+        docker_datums.sort_by(|(_, a), (_, b)| a.name.cmp(&b.name));
+
+        docker_datums
+            .into_iter()
+            .map(|(key, datum)| Ok((key, lookup(&datum.name)?)))
+            .collect()
+    }
+
+    /// Whether the container named `name` is running, stopped or was never created
+    pub fn container_state(&self, name: &str) -> Result<ServiceState> {
+        Ok(if self.is_docker_running(name)? {
+            ServiceState::Running
+        } else if self.docker_container_exists(name)? {
+            ServiceState::Stopped
+        } else {
+            ServiceState::Missing
+        })
+    }
+
+    fn container_listed(&self, name: &str, ps_args: &[&str]) -> Result<bool> {
+        let runtime = self.get_container_runtime()?;
+        let output = Command::new(&runtime)
+            .args(ps_args)
+            .args([
+                "--filter",
+                &format!("name=^{}$", name),
+                "--format",
+                "{{.Names}}",
+            ])
+            .output()
+            .with_context(|| format!("Failed to run {} ps", runtime))?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.trim() == name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datum(toml_src: &str) -> BootDatum {
+        toml::from_str::<UnifiedConfig>(toml_src).unwrap().b00t
+    }
+
+    fn orchestrator(datums: HashMap<String, BootDatum>) -> Orchestrator {
+        Orchestrator {
+            datums,
+            max_start_timeout: None,
+            configured_runtime: None,
+            datum_dir: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn test_restart_policy_default_and_validation() {
+        let mut qdrant = datum(
+            r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector DB"
+image = "qdrant/qdrant"
+"#,
+        );
+        assert_eq!(
+            Orchestrator::restart_policy(&qdrant).unwrap(),
+            "unless-stopped"
+        );
+
+        qdrant.restart_policy = Some("always".to_string());
+        assert_eq!(Orchestrator::restart_policy(&qdrant).unwrap(), "always");
+
+        qdrant.restart_policy = Some("sometimes".to_string());
+        let err = Orchestrator::restart_policy(&qdrant).unwrap_err();
+        assert!(err.to_string().contains("sometimes"));
+
+        let parsed = datum(
+            r#"
+[b00t]
+name = "redis"
+type = "docker"
+hint = "Cache"
+restart = "on-failure"
+"#,
+        );
+        assert_eq!(parsed.restart_policy.as_deref(), Some("on-failure"));
+    }
+
+    #[test]
+    fn test_snapshot_is_sorted_json() {
+        let mut datums = HashMap::new();
+        for name in ["redis", "qdrant"] {
+            datums.insert(
+                format!("{}.docker", name),
+                datum(&format!(
+                    "[b00t]\nname = \"{}\"\ntype = \"docker\"\nhint = \"h\"\nimage = \"{}:latest\"",
+                    name, name
+                )),
+            );
+        }
+        let orchestrator = orchestrator(datums);
+
+        let snapshot = orchestrator.snapshot().unwrap();
+        assert!(snapshot.find("qdrant.docker").unwrap() < snapshot.find("redis.docker").unwrap());
+        let value: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(value["redis.docker"]["image"], "redis:latest");
+    }
+
+    #[test]
+    fn test_split_published_ports() {
+        let args: Vec<String> = [
+            "-p",
+            "6333:6333",
+            "-v",
+            "qdrant_storage:/qdrant/storage",
+            "--publish=6334:6334",
+            "-e",
+            "A=1",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let (ports, rest) = Orchestrator::split_published_ports(&args);
+        assert_eq!(ports, vec!["6333:6333", "6334:6334"]);
+        assert_eq!(
+            rest,
+            vec!["-v", "qdrant_storage:/qdrant/storage", "-e", "A=1"]
+        );
+    }
+
+    #[test]
+    fn test_named_volumes_skip_bind_mounts() {
+        let args: Vec<String> = [
+            "-v",
+            "qdrant_storage:/qdrant/storage",
+            "--volume=/etc/hosts:/etc/hosts:ro",
+            "-v",
+            "./config:/config",
+            "--volume",
+            "~/models:/models",
+            "-v",
+            "/anonymous",
+            "-v=model-cache.v1:/cache:rw",
+            "-p",
+            "6333:6333",
+            "-v",
+            "qdrant_storage:/backup",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            Orchestrator::named_volumes(&args),
+            vec!["qdrant_storage", "model-cache.v1"]
+        );
+        assert!(Orchestrator::named_volumes(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_network_field_and_exact_ls_match() {
+        let with_network = datum(
+            r#"
+[b00t]
+name = "qdrant"
+type = "docker"
+hint = "Vector database"
+image = "qdrant/qdrant"
+network = "b00t"
+"#,
+        );
+        assert_eq!(with_network.network.as_deref(), Some("b00t"));
+
+        assert!(Orchestrator::lists_name("bridge\nb00t\nhost\n", "b00t"));
+        assert!(!Orchestrator::lists_name("b00t-net\nmy-b00t\n", "b00t"));
+        assert!(!Orchestrator::lists_name("", "b00t"));
+    }
+
+    #[test]
+    fn test_make_key_uses_type_slug() {
+        assert_eq!(
+            Orchestrator::make_key("qdrant", Some(&DatumType::Docker)),
+            "qdrant.docker"
+        );
+        // Same spelling as the file suffix, not the Debug name ("aimodel")
+        assert_eq!(
+            Orchestrator::make_key("claude-3-5-sonnet", Some(&DatumType::AiModel)),
+            "claude-3-5-sonnet.ai_model"
+        );
+        assert_eq!(Orchestrator::make_key("bare", None), "bare");
+
+        for datum_type in [
+            DatumType::Unknown,
+            DatumType::Agent,
+            DatumType::Mcp,
+            DatumType::Bash,
+            DatumType::Vscode,
+            DatumType::Docker,
+            DatumType::K8s,
+            DatumType::Apt,
+            DatumType::Nix,
+            DatumType::Ai,
+            DatumType::AiModel,
+            DatumType::Api,
+            DatumType::Cli,
+            DatumType::Stack,
+            DatumType::Config,
+            DatumType::Job,
+        ] {
+            assert_eq!(
+                serde_json::to_value(&datum_type).unwrap(),
+                datum_type.type_slug()
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_datum_name_ai_model() {
+        assert_eq!(
+            Orchestrator::extract_datum_name("claude-3-5-sonnet.ai_model.toml"),
+            Some("claude-3-5-sonnet".to_string())
+        );
+        assert_eq!(
+            Orchestrator::extract_datum_name("openrouter.ai.toml"),
+            Some("openrouter".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_datum_name_known_suffixes() {
+        for suffix in [
+            "docker", "mcp", "cli", "bash", "vscode", "k8s", "apt", "ai", "api", "stack",
+            "ai_model",
+        ] {
+            assert_eq!(
+                Orchestrator::extract_datum_name(&format!("qdrant.{}.toml", suffix)).as_deref(),
+                Some("qdrant"),
+                "suffix .{}",
+                suffix
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_datum_name_edge_cases() {
+        assert_eq!(
+            Orchestrator::extract_datum_name("my.fancy.service.docker.toml").as_deref(),
+            Some("my.fancy.service")
+        );
+        // No (known) type suffix
+        assert_eq!(Orchestrator::extract_datum_name("qdrant.toml"), None);
+        assert_eq!(
+            Orchestrator::extract_datum_name("qdrant.unknown.toml"),
+            None
+        );
+        assert_eq!(Orchestrator::extract_datum_name("_b00t_.toml"), None);
+        // Suffix only, or no name before it
+        assert_eq!(Orchestrator::extract_datum_name(".toml"), None);
+        assert_eq!(Orchestrator::extract_datum_name(".docker.toml"), None);
+        // Not a TOML file
+        assert_eq!(Orchestrator::extract_datum_name("qdrant.docker"), None);
+        assert_eq!(
+            Orchestrator::extract_datum_name("qdrant.docker.toml.bak"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_key_prefers_docker_then_sorted_key() {
+        let mut datums = HashMap::new();
+        for (name, datum_type) in [
+            ("qdrant", "mcp"),
+            ("qdrant", "docker"),
+            ("jq", "mcp"),
+            ("jq", "cli"),
+        ] {
+            datums.insert(
+                format!("{}.{}", name, datum_type),
+                datum(&format!(
+                    "[b00t]\nname = \"{}\"\ntype = \"{}\"\nhint = \"h\"\n",
+                    name, datum_type
+                )),
+            );
+        }
+        let orchestrator = orchestrator(datums);
+
+        assert_eq!(
+            orchestrator.find_key("qdrant").as_deref(),
+            Some("qdrant.docker")
+        );
+        assert_eq!(
+            orchestrator.find_key("qdrant.mcp").as_deref(),
+            Some("qdrant.mcp")
+        );
+        assert_eq!(orchestrator.find_key("jq").as_deref(), Some("jq.cli"));
+        assert_eq!(orchestrator.find_key("redis"), None);
+    }
+
+    #[test]
+    fn test_start_order_puts_dependencies_first() {
+        let mut datums = HashMap::new();
+        datums.insert(
+            "rag.stack".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "rag"
+                type = "stack"
+                hint = "rag stack"
+                services = ["api.docker", "qdrant.docker"]
+                "#,
+            ),
+        );
+        datums.insert(
+            "api.docker".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "api"
+                type = "docker"
+                hint = "api"
+                depends_on = ["qdrant.docker"]
+                "#,
+            ),
+        );
+        datums.insert(
+            "qdrant.docker".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "qdrant"
+                type = "docker"
+                hint = "vector db"
+                "#,
+            ),
+        );
+
+        let orchestrator = orchestrator(datums);
+        assert_eq!(
+            orchestrator.start_order("rag.stack").unwrap(),
+            vec!["qdrant.docker", "api.docker"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dependency_cycle_detected() {
+        let mut datums = HashMap::new();
+        datums.insert(
+            "qdrant.docker".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "qdrant"
+                type = "docker"
+                hint = "vector db"
+                depends_on = ["embedding.api"]
+                "#,
+            ),
+        );
+        datums.insert(
+            "embedding.api".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "embedding"
+                type = "api"
+                hint = "embeddings"
+                depends_on = ["qdrant.docker"]
+                "#,
+            ),
+        );
+        let orchestrator = orchestrator(datums);
+
+        let err = orchestrator
+            .ensure_dependencies("qdrant.docker")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Dependency cycle detected: qdrant.docker -> embedding.api -> qdrant.docker"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_capability_reports_every_failure() {
+        let mut datums = HashMap::new();
+        datums.insert(
+            "grok-guru.mcp".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "grok-guru"
+                type = "mcp"
+                hint = "rag"
+
+                [b00t.requires.vector_db]
+                capability = "vector-db"
+                fallback = "missing-db"
+                "#,
+            ),
+        );
+        // 🤓 Depending back on its consumer makes this provider fail without docker
+        datums.insert(
+            "qdrant-api.api".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "qdrant-api"
+                type = "api"
+                hint = "vector api"
+                depends_on = ["grok-guru.mcp"]
+
+                [b00t.provides]
+                capability = "vector-db"
+                "#,
+            ),
+        );
+        let orchestrator = orchestrator(datums);
+
+        let err = orchestrator
+            .ensure_dependencies("grok-guru.mcp")
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("No provider available for capability 'vector-db':"));
+        assert!(err.contains("qdrant-api.api: Dependency cycle detected"));
+        assert!(err.contains("fallback 'missing-db' not found"));
+    }
+
+    #[test]
+    fn test_dependency_graph_dot() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("qdrant.docker.toml"),
+            "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"vector db\"\nimage = \"qdrant/qdrant\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("qdrant-api.api.toml"),
+            "[b00t]\nname = \"qdrant-api\"\ntype = \"api\"\nhint = \"vector api\"\ndepends_on = [\"qdrant.docker\"]\n\n[b00t.provides]\ncapability = \"vector-db\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("grok-guru.mcp.toml"),
+            "[b00t]\nname = \"grok-guru\"\ntype = \"mcp\"\nhint = \"rag\"\n\n[b00t.requires.vector_db]\ncapability = \"vector-db\"\n",
+        )
+        .unwrap();
+
+        let orchestrator = Orchestrator::new(dir.path().to_str().unwrap()).unwrap();
+        let dot = orchestrator.dependency_graph();
+
+        assert!(dot.starts_with("digraph"));
+        assert!(dot.contains("\"qdrant.docker\";"));
+        assert!(dot.contains("\"qdrant-api.api\";"));
+        assert!(dot.contains("\"grok-guru.mcp\";"));
+        assert!(dot.contains("\"qdrant-api.api\" -> \"qdrant.docker\";"));
+        assert!(dot.contains("\"grok-guru.mcp\" -> \"qdrant-api.api\" [label=\"vector-db\"];"));
+    }
+
+    #[tokio::test]
+    async fn test_list_running_ignores_non_docker_datums() {
+        let mut datums = HashMap::new();
+        datums.insert(
+            "embedding.api".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "embedding"
+                type = "api"
+                hint = "embeddings"
+                "#,
+            ),
+        );
+        let orchestrator = orchestrator(datums);
+
+        // 🤓 No docker datums means no container runtime calls at all
+        assert!(orchestrator.list_running().await.unwrap().is_empty());
+        assert!(orchestrator.list_stopped().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_docker_states_mixed_running_stopped_missing() {
+        let mut datums = HashMap::new();
+        for (name, datum_type) in [
+            ("redis", "docker"),
+            ("postgres-enhanced", "docker"),
+            ("postgres", "docker"),
+            ("embedding", "api"),
+        ] {
+            datums.insert(
+                format!("{}.{}", name, datum_type),
+                datum(&format!(
+                    "[b00t]\nname = \"{}\"\ntype = \"{}\"\nhint = \"test\"\n",
+                    name, datum_type
+                )),
+            );
+        }
+        let orchestrator = orchestrator(datums);
+
+        let states = orchestrator
+            .docker_states_with(|name| {
+                Ok(match name {
+                    "postgres" => ServiceState::Running,
+                    "redis" => ServiceState::Stopped,
+                    "embedding" => panic!("non-docker datum looked up"),
+                    _ => ServiceState::Missing,
+                })
+            })
+            .unwrap();
+
+        // 🤓 Sorted by datum name, not key: "postgres" < "postgres-enhanced"
+        assert_eq!(
+            states
+                .iter()
+                .map(|(key, state)| (key.as_str(), *state))
+                .collect::<Vec<_>>(),
+            [
+                ("postgres.docker", ServiceState::Running),
+                ("postgres-enhanced.docker", ServiceState::Missing),
+                ("redis.docker", ServiceState::Stopped),
+            ]
+        );
+        assert!(
+            orchestrator
+                .docker_states_with(|_| anyhow::bail!("no runtime"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_needs_start_uses_run_state_cache() {
+        let orchestrator = orchestrator(HashMap::new());
+        let shared_db = datum("[b00t]\nname = \"postgres\"\ntype = \"docker\"\nhint = \"db\"\n");
+
+        // 🤓 Cached entries answer without spawning a container runtime
+        let mut run = EnsureRun::default();
+        run.running.insert("postgres".to_string(), true);
+        assert!(!orchestrator.needs_start(&shared_db, &mut run).unwrap());
+
+        run.running.insert("postgres".to_string(), false);
+        assert!(orchestrator.needs_start(&shared_db, &mut run).unwrap());
+    }
+
+    #[test]
+    fn test_start_timeout_override_and_cap() {
+        let default = datum("[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"db\"\n");
+        let slow = datum(
+            "[b00t]\nname = \"vllm\"\ntype = \"docker\"\nhint = \"llm\"\nstart_timeout_secs = 600\n",
+        );
+
+        let orchestrator = orchestrator(HashMap::new());
+        assert_eq!(orchestrator.start_timeout(&default), DEFAULT_START_TIMEOUT);
+        assert_eq!(orchestrator.start_timeout(&slow), Duration::from_secs(600));
+
+        let orchestrator = orchestrator.with_max_start_timeout(120);
+        assert_eq!(orchestrator.start_timeout(&default), DEFAULT_START_TIMEOUT);
+        assert_eq!(orchestrator.start_timeout(&slow), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_compose_up_args_resolve_compose_file_against_datum_dir() {
+        let compose = vec!["docker".to_string(), "compose".to_string()];
+        let datum_dir = std::path::Path::new("/home/me/_b00t_");
+        let mut n8n = datum(
+            r#"
+[b00t]
+name = "n8n"
+type = "docker"
+hint = "Workflows"
+use_compose = true
+"#,
+        );
+
+        assert_eq!(
+            Orchestrator::compose_up_args(compose.clone(), datum_dir, &n8n),
+            ["docker", "compose", "up", "-d", "n8n"]
+        );
+
+        n8n.compose_file = Some("compose/n8n.yml".to_string());
+        assert_eq!(
+            Orchestrator::compose_up_args(compose.clone(), datum_dir, &n8n),
+            [
+                "docker",
+                "compose",
+                "-f",
+                "/home/me/_b00t_/compose/n8n.yml",
+                "up",
+                "-d",
+                "n8n"
+            ]
+        );
+
+        n8n.compose_file = Some("/srv/n8n/compose.yml".to_string());
+        assert_eq!(
+            Orchestrator::compose_up_args(compose, datum_dir, &n8n)[3],
+            "/srv/n8n/compose.yml"
+        );
+    }
+
+    #[test]
+    fn test_load_reports_unparseable_datums() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("qdrant.docker.toml"),
+            "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"vector db\"\n",
+        )
+        .unwrap();
+        let broken = dir.path().join("broken.mcp.toml");
+        std::fs::write(&broken, "[b00t\nname = ").unwrap();
+        std::fs::write(dir.path().join("README.md"), "not a datum").unwrap();
+
+        let result = Orchestrator::load(dir.path().to_str().unwrap()).unwrap();
+
+        assert!(result.orchestrator.datums().contains_key("qdrant.docker"));
+        assert_eq!(result.load_warnings.len(), 1);
+        assert_eq!(result.load_warnings[0].0, broken);
+        assert!(
+            result.load_warnings[0]
+                .1
+                .to_string()
+                .contains("Failed to parse datum")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_follows_symlinks_and_reports_broken_ones() {
+        let elsewhere = tempfile::tempdir().unwrap();
+        let target = elsewhere.path().join("qdrant.docker.toml");
+        std::fs::write(
+            &target,
+            "[b00t]\nname = \"qdrant\"\ntype = \"docker\"\nhint = \"vector db\"\n",
+        )
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        std::os::unix::fs::symlink(&target, dir.path().join("qdrant.docker.toml")).unwrap();
+        let dangling = dir.path().join("gone.mcp.toml");
+        std::os::unix::fs::symlink(elsewhere.path().join("missing.toml"), &dangling).unwrap();
+
+        let result = Orchestrator::load(dir.path().to_str().unwrap()).unwrap();
+
+        assert!(result.orchestrator.datums().contains_key("qdrant.docker"));
+        assert_eq!(result.load_warnings.len(), 1);
+        assert_eq!(result.load_warnings[0].0, dangling);
+        let message = result.load_warnings[0].1.to_string();
+        assert!(message.contains("Broken symlink") && message.contains("missing.toml"));
+    }
+
+    #[tokio::test]
+    async fn test_stop_service_rejects_non_docker() {
+        let mut datums = HashMap::new();
+        datums.insert(
+            "grok-guru.mcp".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "grok-guru"
+                type = "mcp"
+                hint = "rag"
+                "#,
+            ),
+        );
+        let orchestrator = orchestrator(datums);
+
+        assert!(orchestrator.stop_service("grok-guru.mcp").await.is_err());
+        assert!(orchestrator.stop_service("missing.docker").await.is_err());
+    }
+
+    #[test]
+    fn test_logs_args_and_non_docker_datum() {
+        assert_eq!(
+            Orchestrator::logs_args("qdrant", true, Some(50)),
+            ["logs", "--tail", "50", "--follow", "qdrant"]
+        );
+        assert_eq!(
+            Orchestrator::logs_args("qdrant", false, None),
+            ["logs", "qdrant"]
+        );
+
+        let mut datums = HashMap::new();
+        datums.insert(
+            "grok-guru.mcp".to_string(),
+            datum(
+                r#"
+                [b00t]
+                name = "grok-guru"
+                type = "mcp"
+                hint = "rag"
+                "#,
+            ),
+        );
+        let orchestrator = orchestrator(datums);
+        let err = orchestrator
+            .stream_logs("grok-guru", false, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("not a docker service"), "{}", err);
+        let err = orchestrator.stream_logs("qdrant", false, None).unwrap_err();
+        assert!(err.to_string().contains("not found"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_health_times_out() {
+        // Nothing listens on the discard port, so the check must give up
+        let err = Orchestrator::wait_for_health(
+            "nothing",
+            "http://127.0.0.1:9/health",
+            Duration::from_secs(0),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("did not pass health check"));
+    }
+}
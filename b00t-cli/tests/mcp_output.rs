@@ -22,7 +22,7 @@ hint = "a test server"
 "#
     )?;
 
-    let mut cmd = Command::cargo_bin("b00t-cli")?;
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("b00t-cli"));
     cmd.arg("--path")
         .arg(b00t_path.to_str().unwrap())
         .arg("mcp")
@@ -46,3 +46,147 @@ hint = "a test server"
 
     Ok(())
 }
+
+#[test]
+fn test_mcp_output_unknown_server_suggests_names() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let b00t_path = dir.path();
+
+    let mut file = File::create(b00t_path.join("qdrant.mcp.toml"))?;
+    writeln!(
+        file,
+        r#"[b00t]
+name = "qdrant"
+command = "echo"
+hint = "a test server"
+"#
+    )?;
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("b00t-cli"))
+        .arg("--path")
+        .arg(b00t_path.to_str().unwrap())
+        .args(["mcp", "output", "qdrant,qdrnat,typo-server-xyz"])
+        .output()?;
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("qdrnat (did you mean: qdrant?)"));
+    assert!(stderr.contains("typo-server-xyz"));
+
+    Ok(())
+}
+
+#[test]
+fn test_mcp_output_cursor_format() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let b00t_path = dir.path();
+
+    let mut file = File::create(b00t_path.join("test-server.mcp.toml"))?;
+    writeln!(
+        file,
+        r#"[b00t]
+name = "test-server"
+command = "echo"
+args = ["hello"]
+hint = "a test server"
+"#
+    )?;
+    let mut file = File::create(b00t_path.join("remote.mcp.toml"))?;
+    writeln!(
+        file,
+        r#"[b00t]
+name = "remote"
+type = "mcp"
+hint = "an http-only server"
+
+[b00t.mcp.httpstream]
+url = "https://example.com/mcp"
+"#
+    )?;
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("b00t-cli"))
+        .arg("--path")
+        .arg(b00t_path.to_str().unwrap())
+        .args(["mcp", "output", "--format", "cursor", "test-server,remote"])
+        .output()?;
+
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "mcpServers": {
+                "test-server": {
+                    "type": "stdio",
+                    "command": "echo",
+                    "args": ["hello"]
+                },
+                "remote": {
+                    "url": "https://example.com/mcp"
+                }
+            }
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_mcp_output_prefix_namespaces_servers() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let b00t_path = dir.path();
+
+    let mut file = File::create(b00t_path.join("qdrant.mcp.toml"))?;
+    writeln!(
+        file,
+        r#"[b00t]
+name = "qdrant"
+command = "echo"
+hint = "a test server"
+"#
+    )?;
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("b00t-cli"))
+        .arg("--path")
+        .arg(b00t_path.to_str().unwrap())
+        .args(["mcp", "output", "--json", "--prefix", "work", "qdrant"])
+        .output()?;
+
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let servers = value.as_object().unwrap();
+    assert_eq!(servers.keys().collect::<Vec<_>>(), vec!["work_qdrant"]);
+    assert_eq!(servers["work_qdrant"]["command"], "echo");
+
+    Ok(())
+}
+
+#[test]
+fn test_mcp_remove_finds_servers_in_fallback_dirs() -> Result<(), Box<dyn std::error::Error>> {
+    let home = tempdir()?;
+    let fallback = home.path().join(".b00t");
+    std::fs::create_dir_all(&fallback)?;
+    let datum = fallback.join("fallback-only.mcp.toml");
+    let mut file = File::create(&datum)?;
+    writeln!(
+        file,
+        r#"[b00t]
+name = "fallback-only"
+command = "echo"
+hint = "only in ~/.b00t"
+"#
+    )?;
+    let empty = tempdir()?;
+
+    Command::new(assert_cmd::cargo::cargo_bin!("b00t-cli"))
+        .env("HOME", home.path())
+        .arg("--path")
+        .arg(empty.path().to_str().unwrap())
+        .args(["mcp", "remove", "fallback-only", "--force"])
+        .assert()
+        .success();
+    assert!(!datum.exists());
+
+    Ok(())
+}
@@ -7,38 +7,82 @@ with a fluent, chainable Python API.
 
 from typing import List, Dict, Any, Optional, Union
 import json
+import warnings
 try:
-    import b00t_py as _core
+    # 🤓 maturin installs the native module inside this package (b00t_py/b00t_py.*.so);
+    # a plain `import b00t_py` would return this half-initialized package instead
+    from . import b00t_py as _core
 except ImportError:
     # Fallback for development/testing without compiled module
     _core = None
 
-from .exceptions import B00tError
+# The native module raises its own exception classes; export those so
+# `except b00t_py.B00tNotFoundError` catches what the Rust code raises.
+# The pure-Python classes only stand in when the native module is missing.
+if _core is not None:
+    B00tError = _core.B00tError
+    B00tNotFoundError = _core.B00tNotFoundError
+    B00tParseError = _core.B00tParseError
+    B00tEnvError = _core.B00tEnvError
+    B00tIOError = _core.B00tIOError
+else:
+    from .exceptions import B00tError, B00tNotFoundError, B00tParseError, B00tEnvError, B00tIOError
+
+# Native classes and functions, re-exported so callers use `b00t_py.<name>`
+# rather than reaching into the compiled `b00t_py.b00t_py` submodule.
+if _core is not None:
+    from .b00t_py import (
+        # Classes and enums
+        AiModelConfig, AiModelDatum, ModelIterator, ProviderEnv,
+        ModelCapability, ModelProvider, ModelSize,
+        # AI models and providers
+        load_ai_model, load_ai_model_datum, load_ai_model_datum_dict, clear_ai_model_cache,
+        load_all_ai_models, iter_ai_models, list_ai_models, list_ai_providers,
+        check_context_budget, check_provider_env, check_all_providers, provider_env,
+        # Other datums
+        validate_datum, list_mcp_servers, list_cli_tools, load_cli_datum, list_docker_datums,
+        # Async variants
+        check_all_providers_async, list_ai_providers_async, list_ai_models_async,
+        list_docker_datums_async,
+        # Local models
+        model_list_py, model_info_py, model_env_py, model_download_py, model_remove_py,
+        model_activate_py, model_serve_py, model_stop_py,
+        # Utilities
+        version, expand_path, list_capabilities, list_providers, list_sizes,
+    )
 
 # Version info
 __version__ = _core.version() if _core else "dev"
 
 # Direct functional exports
-def mcp_list(path: str = "~/.dotfiles/_b00t_", json_output: bool = False) -> str:
-    """List all MCP servers available in the b00t configuration."""
+def mcp_list(path: Optional[str] = None, json_output: bool = False, servers: Optional[List[str]] = None) -> Union[List[Dict[str, Any]], str]:
+    """List MCP servers (dicts with name/command/args/env), or a JSON string; servers limits it to those names."""
     if _core is None:
         raise B00tError("Native b00t_py module not available. Install with: pip install b00t-py")
-    return _core.mcp_list_py(path, json_output)
+    return _core.mcp_list_py(path, json_output, servers)
 
-def mcp_output(servers: str, path: str = "~/.dotfiles/_b00t_", json_format: bool = False) -> str:
-    """Get MCP server output in specified format."""
+def mcp_output(servers: str, path: Optional[str] = None, json_format: Optional[bool] = None, *, format: str = "wrapper", prefix: Optional[str] = None) -> str:
+    """MCP server config JSON as "wrapper" (mcpServers), "json" or "cursor"; prefix names servers "<prefix>_<name>". json_format is deprecated."""
     if _core is None:
         raise B00tError("Native b00t_py module not available. Install with: pip install b00t-py")
-    return _core.mcp_output_py(servers, path, json_format)
+    if json_format is not None:
+        # 🤓 FutureWarning, not DeprecationWarning: the default filters only show the latter in __main__
+        warnings.warn(
+            'mcp_output(json_format=...) is deprecated; use format="json" or format="wrapper"',
+            FutureWarning,
+            stacklevel=2,
+        )
+        format = "json" if json_format else "wrapper"
+    return _core.mcp_output_py(servers, path, format=format, prefix=prefix)
 
 # Fluent interface classes
 class McpQuery:
     """Fluent interface for MCP operations."""
     
-    def __init__(self, path: str = "~/.dotfiles/_b00t_"):
+    def __init__(self, path: Optional[str] = None):
         self.path = path
         self._servers: Optional[List[str]] = None
-        self._json_format = False
+        self._format = "wrapper"
     
     def servers(self, server_list: List[str]) -> 'McpQuery':
         """Filter to specific servers."""
@@ -47,12 +91,17 @@ class McpQuery:
     
     def json(self) -> 'McpQuery':
         """Use JSON format output."""
-        self._json_format = True
+        self._format = "json"
+        return self
+
+    def cursor(self) -> 'McpQuery':
+        """Use Cursor's mcp.json format for output()."""
+        self._format = "cursor"
         return self
     
-    def list(self) -> str:
-        """Execute list operation."""
-        return mcp_list(self.path, self._json_format)
+    def list(self) -> Union[List[Dict[str, Any]], str]:
+        """Execute list operation, limited to .servers() when given."""
+        return mcp_list(self.path, self._format == "json", self._servers)
     
     def output(self) -> str:
         """Execute output operation."""
@@ -60,12 +109,12 @@ class McpQuery:
             raise B00tError("No servers specified. Use .servers() first.")
         
         server_str = ",".join(self._servers)
-        return mcp_output(server_str, self.path, self._json_format)
+        return mcp_output(server_str, self.path, format=self._format)
 
 class AiQuery:
     """Fluent interface for AI operations (placeholder for future implementation)."""
     
-    def __init__(self, path: str = "~/.dotfiles/_b00t_"):
+    def __init__(self, path: Optional[str] = None):
         self.path = path
     
     def list(self) -> List[Dict[str, Any]]:
@@ -80,7 +129,7 @@ class AiQuery:
 class CliQuery:
     """Fluent interface for CLI operations (placeholder for future implementation)."""
     
-    def __init__(self, path: str = "~/.dotfiles/_b00t_"):
+    def __init__(self, path: Optional[str] = None):
         self.path = path
     
     def detect(self, tool: str) -> str:
@@ -89,15 +138,15 @@ class CliQuery:
         return f"Tool {tool} detection not yet implemented"
 
 # Factory functions for fluent interface
-def mcp(path: str = "~/.dotfiles/_b00t_") -> McpQuery:
+def mcp(path: Optional[str] = None) -> McpQuery:
     """Create MCP query builder."""
     return McpQuery(path)
 
-def ai(path: str = "~/.dotfiles/_b00t_") -> AiQuery:
+def ai(path: Optional[str] = None) -> AiQuery:
     """Create AI query builder."""
     return AiQuery(path)
 
-def cli(path: str = "~/.dotfiles/_b00t_") -> CliQuery:
+def cli(path: Optional[str] = None) -> CliQuery:
     """Create CLI query builder."""
     return CliQuery(path)
 
@@ -106,5 +155,22 @@ __all__ = [
     'mcp_list', 'mcp_output', 
     'mcp', 'ai', 'cli',
     'McpQuery', 'AiQuery', 'CliQuery',
-    'B00tError', '__version__'
-]
\ No newline at end of file
+    'B00tError', 'B00tNotFoundError', 'B00tParseError', 'B00tEnvError', 'B00tIOError',
+    '__version__'
+]
+
+# Only advertise the native names when the compiled module loaded
+if _core is not None:
+    __all__ += [
+        'AiModelConfig', 'AiModelDatum', 'ModelIterator', 'ProviderEnv',
+        'ModelCapability', 'ModelProvider', 'ModelSize',
+        'load_ai_model', 'load_ai_model_datum', 'load_ai_model_datum_dict', 'clear_ai_model_cache',
+        'load_all_ai_models', 'iter_ai_models', 'list_ai_models', 'list_ai_providers',
+        'check_context_budget', 'check_provider_env', 'check_all_providers', 'provider_env',
+        'validate_datum', 'list_mcp_servers', 'list_cli_tools', 'load_cli_datum', 'list_docker_datums',
+        'check_all_providers_async', 'list_ai_providers_async', 'list_ai_models_async',
+        'list_docker_datums_async',
+        'model_list_py', 'model_info_py', 'model_env_py', 'model_download_py', 'model_remove_py',
+        'model_activate_py', 'model_serve_py', 'model_stop_py',
+        'version', 'expand_path', 'list_capabilities', 'list_providers', 'list_sizes',
+    ]
\ No newline at end of file
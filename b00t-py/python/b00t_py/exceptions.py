@@ -4,4 +4,24 @@ Exception classes for b00t-py
 
 class B00tError(Exception):
     """Base exception for all b00t operations."""
-    pass
\ No newline at end of file
+    pass
+
+
+class B00tNotFoundError(B00tError):
+    """A datum file does not exist."""
+    pass
+
+
+class B00tParseError(B00tError):
+    """A datum file is not valid TOML or misses required fields."""
+    pass
+
+
+class B00tEnvError(B00tError):
+    """A required environment variable is not set."""
+    pass
+
+
+class B00tIOError(B00tError):
+    """A file or directory could not be read or written."""
+    pass
@@ -5,103 +5,222 @@
 //! This module provides high-performance Python bindings for the b00t ecosystem,
 //! offering 10-100x performance improvements over subprocess-based approaches.
 
+use pyo3::IntoPyObjectExt;
 use pyo3::create_exception;
+use pyo3::exceptions::{PyDeprecationWarning, PyFutureWarning, PyUserWarning};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyCFunction, PyDict, PyList};
 
 use serde_json;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::SystemTime;
 
 // Import b00t-cli functions
 use b00t_cli::model_manager::{self, ServeOptions};
 
 // Import b00t-cli functions
-use b00t_cli::{get_expanded_path, mcp_list, mcp_output};
+use b00t_cli::{
+    McpOutputFormat, check_mcp_servers_exist, get_expanded_path, mcp_list_structured,
+    mcp_output_string,
+};
 
 // Import datum types
+use b00t_c0re_lib::datum_ai_model::{AiModelDatum, ModelCapability, ModelProvider, ModelSize};
+use b00t_cli::commands::config::default_datum_path;
+use b00t_cli::orchestrator::{Orchestrator, ServiceState};
 
 // Python exception for b00t errors
 create_exception!(b00t_py, B00tError, pyo3::exceptions::PyException);
+// Subclasses by category, so callers can catch just the failures they handle
+create_exception!(
+    b00t_py,
+    B00tNotFoundError,
+    B00tError,
+    "A datum file does not exist."
+);
+create_exception!(
+    b00t_py,
+    B00tParseError,
+    B00tError,
+    "A datum file is not valid TOML or misses required fields."
+);
+create_exception!(
+    b00t_py,
+    B00tEnvError,
+    B00tError,
+    "A required environment variable is not set."
+);
+create_exception!(
+    b00t_py,
+    B00tIOError,
+    B00tError,
+    "A file or directory could not be read or written."
+);
 
+/// Raise the `B00tError` subclass matching the root cause of `err`
 fn to_py_err(prefix: &str, err: anyhow::Error) -> PyErr {
-    B00tError::new_err(format!("{}: {}", prefix, err))
+    let message = format!("{}: {}", prefix, err);
+    for cause in err.chain() {
+        if let Some(io) = cause.downcast_ref::<std::io::Error>() {
+            return match io.kind() {
+                std::io::ErrorKind::NotFound => B00tNotFoundError::new_err(message),
+                _ => B00tIOError::new_err(message),
+            };
+        }
+        if cause.is::<toml::de::Error>() {
+            return B00tParseError::new_err(message);
+        }
+        if cause.is::<std::env::VarError>() {
+            return B00tEnvError::new_err(message);
+        }
+    }
+    B00tError::new_err(message)
 }
 
 fn to_py_err_serde(prefix: &str, err: serde_json::Error) -> PyErr {
     B00tError::new_err(format!("{}: {}", prefix, err))
 }
 
+/// Datum directory for a `path=None` argument: `datum_path` from
+/// ~/.b00t/config.toml, else "~/.dotfiles/_b00t_" (same as the CLI's `--path`)
+fn datum_path(path: Option<&str>) -> String {
+    path.map_or_else(default_datum_path, str::to_string)
+}
+
 /// List all MCP servers available in the b00t configuration
 ///
 /// Args:
 ///     path (str, optional): Path to b00t configuration directory.
-///                          Defaults to "~/.dotfiles/_b00t_"
-///     json_output (bool, optional): Return structured JSON output. Defaults to False.
+///                          Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///     json_output (bool, optional): Return a JSON string instead of a list. Defaults to False.
+///     servers (list[str], optional): Only these server names. None or [] returns all servers.
 ///
 /// Returns:
-///     list: List of MCP server configurations
+///     list: One dict per server with name, command, args, env, hint and error
+///           (or a JSON string of the same when json_output is True)
 ///
 /// Raises:
+///     B00tNotFoundError: If a requested server has no datum
 ///     B00tError: If b00t configuration cannot be read
 ///
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_", json_output = false))]
-fn mcp_list_py(path: &str, json_output: bool) -> PyResult<String> {
-    // Call the b00t-cli function and capture output
-    match mcp_list(path, json_output) {
-        Ok(()) => Ok("MCP servers listed successfully".to_string()),
-        Err(e) => Err(B00tError::new_err(format!(
-            "Failed to list MCP servers: {}",
-            e
-        ))),
+#[pyo3(signature = (path = None, json_output = false, servers = None))]
+fn mcp_list_py(
+    py: Python<'_>,
+    path: Option<&str>,
+    json_output: bool,
+    servers: Option<Vec<String>>,
+) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
+    let only: Vec<&str> = servers.iter().flatten().map(String::as_str).collect();
+    check_mcp_servers_exist(path, &only).map_err(|e| B00tNotFoundError::new_err(e.to_string()))?;
+    let servers =
+        mcp_list_structured(path, &only).map_err(|e| to_py_err("Failed to list MCP servers", e))?;
+
+    if json_output {
+        let json = serde_json::to_string_pretty(&servers)
+            .map_err(|e| to_py_err_serde("Failed to serialize MCP servers", e))?;
+        return Ok(json.into_pyobject(py)?.into_any().unbind());
+    }
+
+    let list = PyList::empty(py);
+    for server in servers {
+        let dict = PyDict::new(py);
+        dict.set_item("name", server.name)?;
+        dict.set_item("command", server.command)?;
+        dict.set_item("args", server.args)?;
+        dict.set_item("env", server.env)?;
+        dict.set_item("hint", server.hint)?;
+        dict.set_item("error", server.error)?;
+        list.append(dict)?;
     }
+
+    Ok(list.into_any().unbind())
 }
 
 /// Get MCP server output in specified format
 ///
 /// Args:
-///     path (str, optional): Path to b00t configuration directory
 ///     servers (str): Comma-separated list of server names
-///     json_format (bool, optional): Use raw JSON format. Defaults to False.
+///     path (str, optional): Path to b00t configuration directory
+///     json_format (bool, optional): Deprecated, use `format="json"`.
+///     format (str, keyword-only): "wrapper" (mcpServers), "json" (bare map) or "cursor"
+///                                 (Cursor's mcp.json). Defaults to "wrapper".
+///     prefix (str, keyword-only, optional): Namespace prepended as "<prefix>_" to every
+///                                           server name, e.g. "work" gives "work_qdrant"
 ///
 /// Returns:
-///     str: MCP server configuration output
+///     str: MCP server configuration as pretty-printed JSON
 ///
 /// Raises:
-///     B00tError: If servers cannot be found or output fails
+///     B00tError: If servers cannot be found, the format is unknown or output fails
 ///
 #[pyfunction]
-#[pyo3(signature = (servers, path = "~/.dotfiles/_b00t_", json_format = false))]
-fn mcp_output_py(servers: &str, path: &str, json_format: bool) -> PyResult<String> {
-    let use_mcp_servers_wrapper = !json_format;
+#[pyo3(signature = (servers, path = None, json_format = None, *, format = "wrapper", prefix = None))]
+fn mcp_output_py(
+    py: Python<'_>,
+    servers: &str,
+    path: Option<&str>,
+    json_format: Option<bool>,
+    format: &str,
+    prefix: Option<&str>,
+) -> PyResult<String> {
+    let path = &datum_path(path);
+    let format = match json_format {
+        Some(json_format) => {
+            PyErr::warn(
+                py,
+                &py.get_type::<PyFutureWarning>(),
+                c"mcp_output_py(json_format=...) is deprecated; use format=\"json\" or format=\"wrapper\"",
+                1,
+            )?;
+            if json_format {
+                McpOutputFormat::Json
+            } else {
+                McpOutputFormat::Wrapper
+            }
+        }
+        None => match format {
+            "wrapper" => McpOutputFormat::Wrapper,
+            "json" => McpOutputFormat::Json,
+            "cursor" => McpOutputFormat::Cursor,
+            _ => {
+                return Err(B00tError::new_err(format!(
+                    "Unknown MCP output format '{}' (expected wrapper, json or cursor)",
+                    format
+                )));
+            }
+        },
+    };
 
-    match mcp_output(path, use_mcp_servers_wrapper, servers) {
-        Ok(()) => Ok("MCP output generated successfully".to_string()),
-        Err(e) => Err(B00tError::new_err(format!(
-            "Failed to generate MCP output: {}",
-            e
-        ))),
-    }
+    mcp_output_string(path, format, servers, prefix)
+        .map_err(|e| to_py_err("Failed to generate MCP output", e))
 }
 
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_"))]
-fn model_list_py(path: &str) -> PyResult<String> {
-    let models =
-        model_manager::list_models(path).map_err(|e| to_py_err("Failed to list models", e))?;
+#[pyo3(signature = (path = None))]
+fn model_list_py(path: Option<&str>) -> PyResult<String> {
+    let path = &datum_path(path);
+    let models = model_manager::list_models(path, None)
+        .map_err(|e| to_py_err("Failed to list models", e))?;
     serde_json::to_string(&models).map_err(|e| to_py_err_serde("Failed to serialise model list", e))
 }
 
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_", name = None))]
-fn model_info_py(path: &str, name: Option<&str>) -> PyResult<String> {
+#[pyo3(signature = (path = None, name = None))]
+fn model_info_py(path: Option<&str>, name: Option<&str>) -> PyResult<String> {
+    let path = &datum_path(path);
     let record = model_manager::describe_model(path, name)
         .map_err(|e| to_py_err("Failed to load model datum", e))?;
     serde_json::to_string(&record).map_err(|e| to_py_err_serde("Failed to serialise model info", e))
 }
 
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_", name = None))]
-fn model_env_py(py: Python<'_>, path: &str, name: Option<&str>) -> PyResult<Py<PyAny>> {
+#[pyo3(signature = (path = None, name = None))]
+fn model_env_py(py: Python<'_>, path: Option<&str>, name: Option<&str>) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
     let envs = model_manager::export_model_env(path, name)
         .map_err(|e| to_py_err("Failed to export model env", e))?;
     let dict = PyDict::new(py);
@@ -112,8 +231,14 @@ fn model_env_py(py: Python<'_>, path: &str, name: Option<&str>) -> PyResult<Py<P
 }
 
 #[pyfunction]
-#[pyo3(signature = (name, path = "~/.dotfiles/_b00t_", force = false, activate = true))]
-fn model_download_py(name: &str, path: &str, force: bool, activate: bool) -> PyResult<String> {
+#[pyo3(signature = (name, path = None, force = false, activate = true))]
+fn model_download_py(
+    name: &str,
+    path: Option<&str>,
+    force: bool,
+    activate: bool,
+) -> PyResult<String> {
+    let path = &datum_path(path);
     let op = model_manager::download_model(path, name, force, activate)
         .map_err(|e| to_py_err("Failed to download model", e))?;
     serde_json::to_string(&op)
@@ -121,21 +246,23 @@ fn model_download_py(name: &str, path: &str, force: bool, activate: bool) -> PyR
 }
 
 #[pyfunction]
-#[pyo3(signature = (name, path = "~/.dotfiles/_b00t_"))]
-fn model_remove_py(name: &str, path: &str) -> PyResult<Option<String>> {
+#[pyo3(signature = (name, path = None))]
+fn model_remove_py(name: &str, path: Option<&str>) -> PyResult<Option<String>> {
+    let path = &datum_path(path);
     model_manager::remove_model(path, name).map_err(|e| to_py_err("Failed to remove model", e))
 }
 
 #[pyfunction]
-#[pyo3(signature = (name, path = "~/.dotfiles/_b00t_"))]
-fn model_activate_py(name: &str, path: &str) -> PyResult<()> {
+#[pyo3(signature = (name, path = None))]
+fn model_activate_py(name: &str, path: Option<&str>) -> PyResult<()> {
+    let path = &datum_path(path);
     model_manager::activate_model(path, name).map_err(|e| to_py_err("Failed to activate model", e))
 }
 
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_", name = None, port = None, dtype = None, image = None, container = None, tensor_parallel_size = 1, extra_args = None, use_gpu = true, replace_existing = true))]
+#[pyo3(signature = (path = None, name = None, port = None, dtype = None, image = None, container = None, tensor_parallel_size = 1, extra_args = None, use_gpu = true, replace_existing = true))]
 fn model_serve_py(
-    path: &str,
+    path: Option<&str>,
     name: Option<&str>,
     port: Option<u16>,
     dtype: Option<&str>,
@@ -146,6 +273,7 @@ fn model_serve_py(
     use_gpu: bool,
     replace_existing: bool,
 ) -> PyResult<String> {
+    let path = &datum_path(path);
     let mut options = ServeOptions::default();
     options.port = port;
     options.dtype = dtype.map(|s| s.to_string());
@@ -163,8 +291,9 @@ fn model_serve_py(
 }
 
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_", container = None))]
-fn model_stop_py(path: &str, container: Option<&str>) -> PyResult<()> {
+#[pyo3(signature = (path = None, container = None))]
+fn model_stop_py(path: Option<&str>, container: Option<&str>) -> PyResult<()> {
+    let path = &datum_path(path);
     model_manager::stop_model(path, container)
         .map_err(|e| to_py_err("Failed to stop model server", e))
 }
@@ -175,47 +304,690 @@ fn version() -> &'static str {
     b00t_c0re_lib::version::VERSION
 }
 
-/// Load an AI model datum from TOML file
+/// Expand a datum path exactly as the Rust core does (`~` becomes the home directory)
 ///
 /// Args:
-///     model_name (str): Name of the model (e.g., "claude-3-5-sonnet", "qwen-2.5-72b")
-///     path (str, optional): Path to datum directory. Defaults to "~/.dotfiles/_b00t_"
+///     path (str): Path such as "~/.dotfiles/_b00t_"
 ///
 /// Returns:
-///     dict: Model configuration with provider, capabilities, env vars, etc.
+///     str: The expanded path (not required to exist)
 ///
 /// Raises:
-///     B00tError: If model datum cannot be loaded
+///     B00tIOError: If the expanded path is not valid UTF-8
+///
+#[pyfunction]
+fn expand_path(path: &str) -> PyResult<String> {
+    let expanded = get_expanded_path(path).map_err(|e| to_py_err("Invalid path", e))?;
+    expanded
+        .into_os_string()
+        .into_string()
+        .map_err(|raw| B00tIOError::new_err(format!("Path is not valid UTF-8: {:?}", raw)))
+}
+
+/// Typed AI model configuration loaded from a `*.ai_model.toml` datum
+///
+/// Attributes:
+///     provider (str): Provider name (e.g., "openrouter", "anthropic")
+///     size (str): Model size ("small", "medium", "large")
+///     litellm_model (str): LiteLLM model identifier
+///     api_base (str | None): Override for the provider API base URL
+///     api_key_env (str | None): Environment variable holding the API key
+///     context_window (int): Context window in tokens (0 when unknown)
+///     input_price_per_1m (float | None): USD per 1M prompt tokens
+///     output_price_per_1m (float | None): USD per 1M completion tokens
+///     capabilities (list[str]): Capabilities (e.g., "chat", "tools")
+///     parameters (dict): Default request parameters
+///
+#[pyclass(name = "AiModelConfig", module = "b00t_py")]
+struct AiModelConfig {
+    #[pyo3(get)]
+    provider: String,
+    #[pyo3(get)]
+    size: String,
+    #[pyo3(get)]
+    litellm_model: String,
+    #[pyo3(get)]
+    api_base: Option<String>,
+    #[pyo3(get)]
+    api_key_env: Option<String>,
+    #[pyo3(get)]
+    context_window: i64,
+    #[pyo3(get)]
+    input_price_per_1m: Option<f64>,
+    #[pyo3(get)]
+    output_price_per_1m: Option<f64>,
+    #[pyo3(get)]
+    capabilities: Vec<String>,
+    #[pyo3(get)]
+    parameters: Py<PyDict>,
+}
+
+#[pymethods]
+impl AiModelConfig {
+    /// Build the kwargs for `litellm.completion(**kwargs)`
+    ///
+    /// Starts from the datum's default `parameters`, then sets `model`,
+    /// `api_base` (when configured) and `api_key` read from `api_key_env`.
+    ///
+    /// Returns:
+    ///     dict: Keyword arguments for litellm
+    ///
+    /// Raises:
+    ///     B00tEnvError: If `api_key_env` is set but missing from the environment
+    ///
+    fn build_litellm_kwargs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let kwargs = self.parameters.bind(py).copy()?;
+        kwargs.set_item("model", &self.litellm_model)?;
+
+        if let Some(api_base) = &self.api_base {
+            kwargs.set_item("api_base", api_base)?;
+        }
+
+        if let Some(env_var) = &self.api_key_env {
+            let api_key = std::env::var(env_var).map_err(|_| {
+                B00tEnvError::new_err(format!(
+                    "API key env var {} is not set for {}",
+                    env_var, self.litellm_model
+                ))
+            })?;
+            kwargs.set_item("api_key", api_key)?;
+        }
+
+        Ok(kwargs)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AiModelConfig(provider={:?}, size={:?}, litellm_model={:?}, context_window={})",
+            self.provider, self.size, self.litellm_model, self.context_window
+        )
+    }
+}
+
+/// A named `*.ai_model.toml` datum
+///
+/// Two datums are equal when they share provider and name.
+///
+/// Attributes:
+///     name (str): Datum name (file stem, e.g. "claude-3-5-sonnet")
+///     provider (str): Provider name (e.g., "anthropic")
+///     size (str): Model size ("small", "medium", "large")
+///     litellm_model (str): LiteLLM model identifier
+///     api_key_env (str | None): Environment variable holding the API key
+///     context_window (int | None): Context window in tokens
+///     input_price_per_1m (float | None): USD per 1M prompt tokens
+///     output_price_per_1m (float | None): USD per 1M completion tokens
+///     capabilities (list[str]): Capabilities (e.g., "chat", "tools")
+///     enabled (bool): Whether the model is enabled
+///
+#[pyclass(name = "AiModelDatum", module = "b00t_py", frozen)]
+struct PyAiModelDatum {
+    #[pyo3(get)]
+    name: String,
+    datum: AiModelDatum,
+}
+
+impl PyAiModelDatum {
+    fn provider_name(&self) -> String {
+        serde_slug(&self.datum.provider)
+    }
+}
+
+/// Serialized form of a unit-like serde enum (e.g. `ModelSize::Large` -> "large")
+fn serde_slug<T: serde::Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(other) => other.to_string(),
+        Err(_) => "unknown".to_string(),
+    }
+}
+
+#[pymethods]
+impl PyAiModelDatum {
+    #[getter]
+    fn provider(&self) -> String {
+        self.provider_name()
+    }
+
+    #[getter]
+    fn size(&self) -> String {
+        serde_slug(&self.datum.size)
+    }
+
+    #[getter]
+    fn litellm_model(&self) -> &str {
+        &self.datum.litellm_model
+    }
+
+    #[getter]
+    fn api_key_env(&self) -> Option<&str> {
+        self.datum.api_key_env.as_deref()
+    }
+
+    #[getter]
+    fn context_window(&self) -> Option<u32> {
+        self.datum.context_window
+    }
+
+    #[getter]
+    fn input_price_per_1m(&self) -> Option<f64> {
+        self.datum.input_price_per_1m
+    }
+
+    #[getter]
+    fn output_price_per_1m(&self) -> Option<f64> {
+        self.datum.output_price_per_1m
+    }
+
+    #[getter]
+    fn capabilities(&self) -> Vec<String> {
+        self.datum.capabilities.iter().map(serde_slug).collect()
+    }
+
+    #[getter]
+    fn enabled(&self) -> bool {
+        self.datum.enabled
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AiModelDatum(name='{}', provider='{}', size='{}')",
+            self.name,
+            self.provider_name(),
+            self.size()
+        )
+    }
+
+    fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        other
+            .cast::<PyAiModelDatum>()
+            .map(|other| {
+                let other = other.get();
+                self.name == other.name && self.datum.provider == other.datum.provider
+            })
+            .unwrap_or(false)
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.datum.provider.hash(&mut hasher);
+        self.name.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Mirror a unit-variant Rust enum as a Python enum class
+///
+/// 🤓 The Rust enums live in b00t-c0re-lib, which b00t-cli also links without
+/// libpython, so the `#[pyclass]` copies are kept here and converted with `From`.
+macro_rules! py_enum_mirror {
+    ($(#[$doc:meta])* $py_name:literal, $py_enum:ident => $rust_enum:ident { $($variant:ident),+ $(,)? }) => {
+        $(#[$doc])*
+        #[pyclass(name = $py_name, module = "b00t_py", eq, eq_int, hash, frozen)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum $py_enum {
+            $($variant),+
+        }
+
+        impl $py_enum {
+            const ALL: &'static [$py_enum] = &[$($py_enum::$variant),+];
+        }
+
+        impl From<$py_enum> for $rust_enum {
+            fn from(value: $py_enum) -> Self {
+                match value {
+                    $($py_enum::$variant => $rust_enum::$variant),+
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $py_enum {
+            /// Datum/TOML spelling (e.g. "json_mode")
+            #[getter]
+            fn value(&self) -> String {
+                serde_slug(&$rust_enum::from(*self))
+            }
+
+            fn __str__(&self) -> String {
+                self.value()
+            }
+        }
+    };
+}
+
+py_enum_mirror!(
+    /// Model size classification (`ModelSize.Large.value == "large"`)
+    "ModelSize",
+    PyModelSize => ModelSize { Small, Medium, Large }
+);
+
+py_enum_mirror!(
+    /// Model capability used for routing (`ModelCapability.Code.value == "code"`)
+    "ModelCapability",
+    PyModelCapability => ModelCapability {
+        Chat, Embeddings, Rerank, Code, Vision, Tools, JsonMode, WebSearch, Reasoning, Batch,
+    }
+);
+
+py_enum_mirror!(
+    /// Known LLM providers (`ModelProvider.OpenRouter.value == "openrouter"`)
+    "ModelProvider",
+    PyModelProvider => ModelProvider {
+        OpenAI, Anthropic, AzureOpenAI, VertexAI, GoogleAI, Bedrock, Cohere, HuggingFace,
+        FireworksAI, Groq, Replicate, Ollama, XAI, OpenRouter, LiteLLM, OpenAICompatible,
+    }
+);
+
+/// All model capabilities
+///
+/// Returns:
+///     list[ModelCapability]: Every capability, in declaration order
 ///
 #[pyfunction]
-#[pyo3(signature = (model_name, path = "~/.dotfiles/_b00t_"))]
-fn load_ai_model_datum(py: Python<'_>, model_name: &str, path: &str) -> PyResult<Py<PyAny>> {
+fn list_capabilities() -> Vec<PyModelCapability> {
+    PyModelCapability::ALL.to_vec()
+}
+
+/// All model sizes
+///
+/// Returns:
+///     list[ModelSize]: Small, Medium, Large
+///
+#[pyfunction]
+fn list_sizes() -> Vec<PyModelSize> {
+    PyModelSize::ALL.to_vec()
+}
+
+/// All known model providers
+///
+/// Returns:
+///     list[ModelProvider]: Every built-in provider (custom provider strings are not included)
+///
+#[pyfunction]
+fn list_providers() -> Vec<PyModelProvider> {
+    PyModelProvider::ALL.to_vec()
+}
+
+/// `<path>/<model_name>.ai_model.toml`, which must exist
+fn ai_model_datum_path(model_name: &str, path: &str) -> PyResult<PathBuf> {
     // Expand path
-    let mut datum_path =
-        get_expanded_path(path).map_err(|e| B00tError::new_err(format!("Invalid path: {}", e)))?;
+    let mut datum_path = get_expanded_path(path)
+        .map_err(|e| B00tIOError::new_err(format!("Invalid path: {}", e)))?;
     datum_path.push(format!("{}.ai_model.toml", model_name));
 
     // Check if file exists
     if !datum_path.exists() {
-        return Err(B00tError::new_err(format!(
+        return Err(B00tNotFoundError::new_err(format!(
             "Model datum '{}' not found at {}",
             model_name,
             datum_path.display()
         )));
     }
+    Ok(datum_path)
+}
+
+/// Read `<path>/<model_name>.ai_model.toml` and return its `[ai_model]` section
+fn read_ai_model_section(model_name: &str, path: &str) -> PyResult<toml::Value> {
+    let datum_path = ai_model_datum_path(model_name, path)?;
 
     // Read and parse TOML
     let content = std::fs::read_to_string(&datum_path)
-        .map_err(|e| B00tError::new_err(format!("Failed to read datum: {}", e)))?;
+        .map_err(|e| B00tIOError::new_err(format!("Failed to read datum: {}", e)))?;
 
     // Parse into structured format
-    let toml_value: toml::Value = toml::from_str(&content)
-        .map_err(|e| B00tError::new_err(format!("Failed to parse TOML: {}", e)))?;
+    let mut toml_value: toml::Value = toml::from_str(&content)
+        .map_err(|e| B00tParseError::new_err(format!("Failed to parse TOML: {}", e)))?;
 
     // Extract ai_model section
-    let ai_model = toml_value
-        .get("ai_model")
-        .ok_or_else(|| B00tError::new_err("Missing [ai_model] section"))?;
+    toml_value
+        .as_table_mut()
+        .and_then(|table| table.remove("ai_model"))
+        .ok_or_else(|| B00tParseError::new_err("Missing [ai_model] section"))
+}
+
+/// Convert an `[ai_model.parameters]` table into a Python dict
+fn parameters_to_dict<'py>(
+    py: Python<'py>,
+    params_table: &toml::map::Map<String, toml::Value>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let params_dict = PyDict::new(py);
+    for (key, value) in params_table {
+        match value {
+            toml::Value::String(s) => {
+                params_dict.set_item(key, s)?;
+            }
+            toml::Value::Integer(i) => {
+                params_dict.set_item(key, i)?;
+            }
+            toml::Value::Float(f) => {
+                params_dict.set_item(key, f)?;
+            }
+            toml::Value::Boolean(b) => {
+                params_dict.set_item(key, b)?;
+            }
+            _ => {
+                params_dict.set_item(key, value.to_string())?;
+            }
+        }
+    }
+    Ok(params_dict)
+}
+
+fn toml_str(ai_model: &toml::Value, key: &str) -> Option<String> {
+    ai_model
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+}
+
+/// A number field, accepting integers written without a decimal point (`price = 3`)
+fn toml_f64(ai_model: &toml::Value, key: &str) -> Option<f64> {
+    match ai_model.get(key)? {
+        toml::Value::Float(f) => Some(*f),
+        toml::Value::Integer(i) => Some(*i as f64),
+        _ => None,
+    }
+}
+
+/// Most datum files `load_ai_model_datum` keeps parsed (same default as `functools.lru_cache`)
+const AI_MODEL_CACHE_SIZE: usize = 128;
+
+struct CachedAiModel {
+    modified: SystemTime,
+    last_used: u64,
+    /// Parsed `[ai_model]` section; each load builds a fresh `AiModelConfig` from it
+    ai_model: toml::Value,
+}
+
+#[derive(Default)]
+struct AiModelCache {
+    entries: HashMap<PathBuf, CachedAiModel>,
+    clock: u64,
+}
+
+static AI_MODEL_CACHE: LazyLock<Mutex<AiModelCache>> = LazyLock::new(Default::default);
+
+fn ai_model_cache() -> std::sync::MutexGuard<'static, AiModelCache> {
+    // 🤓 A panic while holding the lock can't leave the map half-updated, so poisoning is safe to ignore
+    AI_MODEL_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Load an AI model datum from TOML file
+///
+/// Parsed datums are cached per file and reused until the file's modification
+/// time changes. Every call still returns a new AiModelConfig with its own
+/// `parameters` dict, so mutating one never affects later loads.
+///
+/// Args:
+///     model_name (str): Name of the model (e.g., "claude-3-5-sonnet", "qwen-2.5-72b")
+///     path (str, optional): Path to datum directory. Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///
+/// Returns:
+///     AiModelConfig: Typed model configuration
+///
+/// Raises:
+///     B00tNotFoundError: If the model datum file does not exist
+///     B00tParseError: If the datum is not valid TOML, has no [ai_model] section,
+///                     or has an empty `litellm_model` or `provider`
+///     B00tIOError: If the datum cannot be read
+///
+#[pyfunction]
+#[pyo3(signature = (model_name, path = None))]
+fn load_ai_model_datum(
+    py: Python<'_>,
+    model_name: &str,
+    path: Option<&str>,
+) -> PyResult<Py<AiModelConfig>> {
+    let path = &datum_path(path);
+    let datum_path = ai_model_datum_path(model_name, path)?;
+    let modified = std::fs::metadata(&datum_path)
+        .and_then(|meta| meta.modified())
+        .map_err(|e| B00tIOError::new_err(format!("Failed to read datum: {}", e)))?;
+
+    let cached = {
+        let mut cache = ai_model_cache();
+        cache.clock += 1;
+        let now = cache.clock;
+        match cache.entries.get_mut(&datum_path) {
+            Some(entry) if entry.modified == modified => {
+                entry.last_used = now;
+                Some(entry.ai_model.clone())
+            }
+            _ => None,
+        }
+    };
+    if let Some(ai_model) = cached {
+        return Py::new(py, ai_model_config(py, model_name, &ai_model)?);
+    }
+
+    let ai_model = read_ai_model_section(model_name, path)?;
+    // 🤓 Build before caching so a datum that fails validation is never cached
+    let config = ai_model_config(py, model_name, &ai_model)?;
+
+    let mut cache = ai_model_cache();
+    if cache.entries.len() >= AI_MODEL_CACHE_SIZE
+        && !cache.entries.contains_key(&datum_path)
+        && let Some(oldest) = cache
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone())
+    {
+        cache.entries.remove(&oldest);
+    }
+    let last_used = cache.clock;
+    cache.entries.insert(
+        datum_path,
+        CachedAiModel {
+            modified,
+            last_used,
+            ai_model,
+        },
+    );
+    drop(cache);
+    Py::new(py, config)
+}
+
+/// Forget every datum cached by `load_ai_model_datum`
+#[pyfunction]
+fn clear_ai_model_cache() {
+    ai_model_cache().entries.clear();
+}
+
+/// Validate a parsed `[ai_model]` section and build a new `AiModelConfig` from it
+fn ai_model_config(
+    py: Python<'_>,
+    model_name: &str,
+    ai_model: &toml::Value,
+) -> PyResult<AiModelConfig> {
+    let capabilities = ai_model
+        .get("capabilities")
+        .and_then(|v| v.as_array())
+        .map(|caps| {
+            caps.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let parameters = match ai_model.get("parameters").and_then(|v| v.as_table()) {
+        Some(params_table) => parameters_to_dict(py, params_table)?,
+        None => PyDict::new(py),
+    };
+
+    let litellm_model = toml_str(&ai_model, "litellm_model")
+        .filter(|model| !model.trim().is_empty())
+        .ok_or_else(|| {
+            B00tParseError::new_err(format!(
+                "AI model datum '{}' has no litellm_model; it is required to call the model",
+                model_name
+            ))
+        })?;
+    let provider = toml_str(&ai_model, "provider")
+        .filter(|provider| !provider.trim().is_empty())
+        .ok_or_else(|| {
+            B00tParseError::new_err(format!("AI model datum '{}' has no provider", model_name))
+        })?;
+    // 🤓 Custom providers are `ModelProvider::Other` in the core schema, so they load
+    // everywhere else; only point out a likely typo
+    if !PyModelProvider::ALL
+        .iter()
+        .any(|known| serde_slug(&ModelProvider::from(*known)) == provider)
+    {
+        let message = format!(
+            "AI model datum '{}' uses custom provider {:?} (not a built-in ModelProvider)",
+            model_name, provider
+        );
+        PyErr::warn(
+            py,
+            &py.get_type::<PyUserWarning>(),
+            &std::ffi::CString::new(message)?,
+            1,
+        )?;
+    }
+
+    Ok(AiModelConfig {
+        provider,
+        size: toml_str(&ai_model, "size").unwrap_or_else(|| "unknown".to_string()),
+        litellm_model,
+        api_base: toml_str(&ai_model, "api_base"),
+        api_key_env: toml_str(&ai_model, "api_key_env"),
+        context_window: ai_model
+            .get("context_window")
+            .and_then(|v| v.as_integer())
+            .unwrap_or(0),
+        input_price_per_1m: toml_f64(&ai_model, "input_price_per_1m"),
+        output_price_per_1m: toml_f64(&ai_model, "output_price_per_1m"),
+        capabilities,
+        parameters: parameters.unbind(),
+    })
+}
+
+/// Check a datum file for semantic problems the TOML schema doesn't catch
+///
+/// Covers e.g. a docker datum without an image, an MCP datum with neither a
+/// command nor a url, and `depends_on`/`members` entries that name no datum
+/// file in the same directory.
+///
+/// Args:
+///     file (str): Path to a `<name>.<type>.toml` datum
+///
+/// Returns:
+///     list[dict]: One `{"field": str, "message": str}` per problem; empty when valid
+///
+/// Raises:
+///     B00tError: If the file cannot be read or does not parse
+///
+#[pyfunction]
+fn validate_datum<'py>(py: Python<'py>, file: &str) -> PyResult<Bound<'py, PyList>> {
+    let file = get_expanded_path(file).map_err(|e| to_py_err("Invalid path", e))?;
+    let errors = b00t_cli::commands::datum::validate_datum_file(&file)
+        .map_err(|e| to_py_err("Failed to validate datum", e))?;
+
+    let list = PyList::empty(py);
+    for error in errors {
+        let dict = PyDict::new(py);
+        dict.set_item("field", error.field)?;
+        dict.set_item("message", error.message)?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
+
+/// Load an AI model datum as an `AiModelDatum` object
+///
+/// Args:
+///     model_name (str): Name of the model (e.g., "claude-3-5-sonnet")
+///     path (str, optional): Path to datum directory. Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///
+/// Returns:
+///     AiModelDatum: The parsed datum
+///
+/// Raises:
+///     B00tError: If the datum is missing or does not match the ai_model schema
+///
+#[pyfunction]
+#[pyo3(signature = (model_name, path = None))]
+fn load_ai_model(model_name: &str, path: Option<&str>) -> PyResult<PyAiModelDatum> {
+    let path = &datum_path(path);
+    let datum: AiModelDatum = read_ai_model_section(model_name, path)?
+        .try_into()
+        .map_err(|e| {
+            B00tParseError::new_err(format!("Invalid ai_model datum {}: {}", model_name, e))
+        })?;
+
+    Ok(PyAiModelDatum {
+        name: model_name.to_string(),
+        datum,
+    })
+}
+
+/// Check whether a prompt of `tokens` fits a model's context window
+///
+/// Args:
+///     model_name (str): Name of the model (e.g., "claude-3-5-sonnet")
+///     tokens (int): Tokens already in the prompt/context
+///     path (str, optional): Path to datum directory. Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///     reserve (int, optional): Tokens to keep free, e.g. for the completion. Defaults to 0
+///
+/// Returns:
+///     bool: True when tokens < context_window - reserve
+///
+/// Raises:
+///     B00tParseError: If the datum is invalid or has no context_window
+///
+#[pyfunction]
+#[pyo3(signature = (model_name, tokens, path = None, reserve = 0))]
+fn check_context_budget(
+    model_name: &str,
+    tokens: u64,
+    path: Option<&str>,
+    reserve: u64,
+) -> PyResult<bool> {
+    let path = &datum_path(path);
+    let model = load_ai_model(model_name, Some(path))?;
+    model.datum.fits_context(tokens, reserve).ok_or_else(|| {
+        B00tParseError::new_err(format!(
+            "AI model datum '{}' has no context_window",
+            model_name
+        ))
+    })
+}
+
+/// Load an AI model datum from TOML file as a plain dict
+///
+/// Deprecated: use `load_ai_model_datum`, which returns a typed `AiModelConfig`.
+///
+/// Args:
+///     model_name (str): Name of the model (e.g., "claude-3-5-sonnet", "qwen-2.5-72b")
+///     path (str, optional): Path to datum directory. Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///
+/// Returns:
+///     dict: Model configuration with provider, capabilities, env vars, etc.
+///
+/// Raises:
+///     B00tError: If model datum cannot be loaded
+///
+#[pyfunction]
+#[pyo3(signature = (model_name, path = None))]
+fn load_ai_model_datum_dict(
+    py: Python<'_>,
+    model_name: &str,
+    path: Option<&str>,
+) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
+    PyErr::warn(
+        py,
+        &py.get_type::<PyDeprecationWarning>(),
+        c"load_ai_model_datum_dict is deprecated; use load_ai_model_datum (returns AiModelConfig)",
+        1,
+    )?;
+
+    let ai_model = read_ai_model_section(model_name, path)?;
 
     // Convert to Python dict
     let py_dict = PyDict::new(py);
@@ -254,30 +1026,8 @@ fn load_ai_model_datum(py: Python<'_>, model_name: &str, path: &str) -> PyResult
     }
 
     // Add parameters if present
-    if let Some(parameters) = ai_model.get("parameters") {
-        if let Some(params_table) = parameters.as_table() {
-            let params_dict = PyDict::new(py);
-            for (key, value) in params_table {
-                match value {
-                    toml::Value::String(s) => {
-                        params_dict.set_item(key, s)?;
-                    }
-                    toml::Value::Integer(i) => {
-                        params_dict.set_item(key, i)?;
-                    }
-                    toml::Value::Float(f) => {
-                        params_dict.set_item(key, f)?;
-                    }
-                    toml::Value::Boolean(b) => {
-                        params_dict.set_item(key, b)?;
-                    }
-                    _ => {
-                        params_dict.set_item(key, value.to_string())?;
-                    }
-                }
-            }
-            py_dict.set_item("parameters", params_dict)?;
-        }
+    if let Some(params_table) = ai_model.get("parameters").and_then(|v| v.as_table()) {
+        py_dict.set_item("parameters", parameters_to_dict(py, params_table)?)?;
     }
 
     Ok(py_dict.unbind().into_any())
@@ -288,21 +1038,67 @@ fn load_ai_model_datum(py: Python<'_>, model_name: &str, path: &str) -> PyResult
 /// Args:
 ///     provider_name (str): Provider name (e.g., "openrouter", "anthropic", "huggingface")
 ///     path (str, optional): Path to datum directory
+///     resolve_vars (bool, optional): Expand `$VAR` / `${VAR}` references in the datum's
+///                                    env values and check those instead of the var names.
+///                                    Defaults to False.
 ///
 /// Returns:
-///     dict: {"available": bool, "missing_env_vars": list}
+///     dict: {"available": bool, "empty_vars": list, "missing_env_vars": list}
+///           "available" requires at least one var set to a non-empty value;
+///           "empty_vars" are set but empty (a common misconfiguration).
+///           With resolve_vars only values referencing a `$VAR` are classified, so a
+///           literal api base URL never makes a provider available; also
+///           "resolved_values": {var: str | None}, None when a referenced variable is unset.
 ///
 #[pyfunction]
-#[pyo3(signature = (provider_name, path = "~/.dotfiles/_b00t_"))]
-fn check_provider_env(py: Python<'_>, provider_name: &str, path: &str) -> PyResult<Py<PyAny>> {
+#[pyo3(signature = (provider_name, path = None, resolve_vars = false))]
+fn check_provider_env(
+    py: Python<'_>,
+    provider_name: &str,
+    path: Option<&str>,
+    resolve_vars: bool,
+) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
+    let status = provider_env_status(provider_name, path, resolve_vars)?;
+
+    let result = PyDict::new(py);
+    status.fill_dict(&result)?;
+
+    Ok(result.unbind().into_any())
+}
+
+/// Environment state of a provider's `[env]` vars
+#[derive(Default)]
+struct ProviderEnvStatus {
+    available: bool,
+    empty_vars: Vec<String>,
+    missing_vars: Vec<String>,
+    /// Expanded datum values, only filled when resolving `$VAR` references
+    resolved_values: Option<std::collections::BTreeMap<String, Option<String>>>,
+}
+
+impl ProviderEnvStatus {
+    fn fill_dict(&self, dict: &Bound<'_, PyDict>) -> PyResult<()> {
+        dict.set_item("available", self.available)?;
+        dict.set_item("empty_vars", &self.empty_vars)?;
+        dict.set_item("missing_env_vars", &self.missing_vars)?;
+        if let Some(resolved_values) = &self.resolved_values {
+            dict.set_item("resolved_values", resolved_values)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read and parse `<provider>.ai.toml` from the datum directory
+fn read_provider_datum(provider_name: &str, path: &str) -> PyResult<toml::Value> {
     // Expand path
-    let mut datum_path =
-        get_expanded_path(path).map_err(|e| B00tError::new_err(format!("Invalid path: {}", e)))?;
+    let mut datum_path = get_expanded_path(path)
+        .map_err(|e| B00tIOError::new_err(format!("Invalid path: {}", e)))?;
     datum_path.push(format!("{}.ai.toml", provider_name));
 
     // Check if file exists
     if !datum_path.exists() {
-        return Err(B00tError::new_err(format!(
+        return Err(B00tNotFoundError::new_err(format!(
             "Provider datum '{}' not found",
             provider_name
         )));
@@ -310,100 +1106,591 @@ fn check_provider_env(py: Python<'_>, provider_name: &str, path: &str) -> PyResu
 
     // Read and parse TOML
     let content = std::fs::read_to_string(&datum_path)
-        .map_err(|e| B00tError::new_err(format!("Failed to read datum: {}", e)))?;
+        .map_err(|e| B00tIOError::new_err(format!("Failed to read datum: {}", e)))?;
 
-    let toml_value: toml::Value = toml::from_str(&content)
-        .map_err(|e| B00tError::new_err(format!("Failed to parse TOML: {}", e)))?;
+    toml::from_str(&content)
+        .map_err(|e| B00tParseError::new_err(format!("Failed to parse TOML: {}", e)))
+}
+
+/// Read a provider's `[env]` table and classify each var as set, empty or missing
+///
+/// With `resolve_vars` the datum's value (`"$OPENAI_API_KEY_FROM_VAULT"`) is expanded
+/// from the environment and classified instead of the process variable itself;
+/// only values that reference a variable are classified, literals are just reported.
+fn provider_env_status(
+    provider_name: &str,
+    path: &str,
+    resolve_vars: bool,
+) -> PyResult<ProviderEnvStatus> {
+    let toml_value = read_provider_datum(provider_name, path)?;
 
     // Extract env section
-    let mut missing_vars = Vec::new();
-    let mut has_any = false;
+    let mut status = ProviderEnvStatus::default();
+    let mut resolved_values = std::collections::BTreeMap::new();
 
     if let Some(env_section) = toml_value.get("env") {
         if let Some(env_table) = env_section.as_table() {
-            for (key, _) in env_table {
-                if std::env::var(key).is_ok() {
-                    has_any = true;
+            for (key, value) in env_table {
+                let current = if resolve_vars {
+                    let raw = value.as_str();
+                    let resolved = raw
+                        .and_then(|value| shellexpand::env(value).ok())
+                        .map(|expanded| expanded.into_owned());
+                    resolved_values.insert(key.clone(), resolved.clone());
+                    // 🤓 A literal (e.g. an API base URL) says nothing about whether the key is set
+                    if !raw.is_some_and(|raw| raw.contains('$')) {
+                        continue;
+                    }
+                    resolved.map(std::ffi::OsString::from)
                 } else {
-                    missing_vars.push(key.clone());
+                    std::env::var_os(key)
+                };
+                match current {
+                    Some(value) if !value.is_empty() => status.available = true,
+                    Some(_) => status.empty_vars.push(key.clone()),
+                    None => status.missing_vars.push(key.clone()),
                 }
             }
         }
     }
 
-    let result = PyDict::new(py);
-    result.set_item("available", has_any)?;
-    result.set_item("missing_env_vars", missing_vars)?;
+    if resolve_vars {
+        status.resolved_values = Some(resolved_values);
+    }
+    Ok(status)
+}
 
-    Ok(result.unbind().into_any())
+/// Context manager that exports a provider's `[env]` vars for the duration of a `with` block
+///
+/// Values are expanded against the current environment (`${ANTHROPIC_API_KEY}`
+/// picks up the caller's key); a value referencing an unset variable is not
+/// exported. Every touched variable is restored, or removed, on exit.
+///
+#[pyclass(name = "ProviderEnv", module = "b00t_py")]
+struct ProviderEnv {
+    provider_name: String,
+    path: String,
+    saved: Vec<(String, Option<String>)>,
 }
 
-/// List all available AI providers
+#[pymethods]
+impl ProviderEnv {
+    fn __enter__(&mut self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let toml_value = read_provider_datum(&self.provider_name, &self.path)?;
+        let env_table = toml_value
+            .get("env")
+            .and_then(|v| v.as_table())
+            .cloned()
+            .unwrap_or_default();
+
+        // 🤓 os.environ writes go through putenv/unsetenv, so the process env follows
+        // without mutating it from Rust while run_blocking workers may be reading it
+        let environ = py.import("os")?.getattr("environ")?;
+        for (key, value) in &env_table {
+            let Some(value) = value.as_str() else {
+                continue;
+            };
+            let Ok(expanded) = shellexpand::env(value) else {
+                continue;
+            };
+            let previous = environ.call_method1("get", (key,))?.extract()?;
+            self.saved.push((key.clone(), previous));
+            environ.set_item(key, expanded.as_ref())?;
+        }
+
+        check_provider_env(py, &self.provider_name, Some(&self.path), false)
+    }
+
+    #[pyo3(signature = (*_exc_info))]
+    fn __exit__(
+        &mut self,
+        py: Python<'_>,
+        _exc_info: &Bound<'_, pyo3::types::PyTuple>,
+    ) -> PyResult<bool> {
+        let environ = py.import("os")?.getattr("environ")?;
+        for (key, previous) in self.saved.drain(..).rev() {
+            match previous {
+                Some(value) => environ.set_item(&key, value)?,
+                None => {
+                    if environ.contains(&key)? {
+                        environ.del_item(&key)?;
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Temporarily export an AI provider's environment variables
 ///
 /// Args:
+///     provider_name (str): Provider name (e.g., "openrouter", "anthropic")
 ///     path (str, optional): Path to datum directory
 ///
 /// Returns:
-///     list: List of provider names
+///     ProviderEnv: Context manager; `with` binds the check_provider_env() dict
+///                  taken after the variables are exported
+///
+/// Raises:
+///     B00tNotFoundError: On enter, if the provider datum does not exist
+///
+/// Example:
+///     with b00t_py.provider_env("anthropic") as status:
+///         assert status["available"]
+///
+#[pyfunction]
+#[pyo3(signature = (provider_name, path = None))]
+fn provider_env(provider_name: &str, path: Option<&str>) -> ProviderEnv {
+    let path = &datum_path(path);
+    ProviderEnv {
+        provider_name: provider_name.to_string(),
+        path: path.to_string(),
+        saved: Vec::new(),
+    }
+}
+
+/// Check environment variables for every AI provider at once
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     dict: {provider_name: {"available": bool, "empty_vars": list, "missing_env_vars": list}}
+///           Providers whose datum cannot be read also carry an "error" key.
+///           Empty dict when no *.ai.toml files exist.
 ///
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_"))]
-fn list_ai_providers(path: &str) -> PyResult<Vec<String>> {
-    let datum_path =
-        get_expanded_path(path).map_err(|e| B00tError::new_err(format!("Invalid path: {}", e)))?;
+#[pyo3(signature = (path = None))]
+fn check_all_providers(py: Python<'_>, path: Option<&str>) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
+    let statuses = provider_statuses(path)?;
+    provider_summary_dict(py, statuses)
+}
 
-    let mut providers = Vec::new();
+/// Env status (or load error) of every provider datum
+fn provider_statuses(path: &str) -> PyResult<Vec<(String, Result<ProviderEnvStatus, String>)>> {
+    Ok(list_ai_providers(Some(path))?
+        .into_iter()
+        .map(|provider_name| {
+            let status =
+                provider_env_status(&provider_name, path, false).map_err(|e| e.to_string());
+            (provider_name, status)
+        })
+        .collect())
+}
 
-    // Read directory and find *.ai.toml files
-    if let Ok(entries) = std::fs::read_dir(datum_path) {
-        for entry in entries.flatten() {
-            let file_name = entry.file_name();
-            let name_str = file_name.to_string_lossy();
+fn provider_summary_dict(
+    py: Python<'_>,
+    statuses: Vec<(String, Result<ProviderEnvStatus, String>)>,
+) -> PyResult<Py<PyAny>> {
+    let summary = PyDict::new(py);
 
-            if name_str.ends_with(".ai.toml") {
-                let provider_name = name_str.trim_end_matches(".ai.toml").to_string();
-                providers.push(provider_name);
+    for (provider_name, env_status) in statuses {
+        let status = PyDict::new(py);
+        match env_status {
+            Ok(env_status) => env_status.fill_dict(&status)?,
+            Err(e) => {
+                ProviderEnvStatus::default().fill_dict(&status)?;
+                status.set_item("error", e)?;
             }
         }
+        summary.set_item(provider_name, status)?;
     }
 
-    providers.sort();
-    Ok(providers)
+    Ok(summary.unbind().into_any())
+}
+
+/// Await blocking datum/filesystem work from Python
+///
+/// The work runs on a worker thread without the GIL, so the calling event
+/// loop and other Python threads keep running. Under asyncio this is a tokio
+/// `spawn_blocking` future; pyo3-async-runtimes cannot drive a trio loop, so
+/// under trio the work is handed to `trio.to_thread.run_sync` instead.
+fn run_blocking<'py, T, F>(py: Python<'py>, work: F) -> PyResult<Bound<'py, PyAny>>
+where
+    T: for<'a> IntoPyObject<'a> + Send + 'static,
+    F: FnOnce() -> PyResult<T> + Send + 'static,
+{
+    if running_under_trio(py) {
+        let work = Mutex::new(Some(work));
+        let run = PyCFunction::new_closure(py, None, None, move |args, _kwargs| {
+            let py = args.py();
+            let work = work
+                .lock()
+                .ok()
+                .and_then(|mut work| work.take())
+                .ok_or_else(|| B00tError::new_err("Background task already ran"))?;
+            py.detach(work)?.into_py_any(py)
+        })?;
+        return py
+            .import("trio")?
+            .getattr("to_thread")?
+            .call_method1("run_sync", (run,));
+    }
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        tokio::task::spawn_blocking(work)
+            .await
+            .map_err(|e| B00tError::new_err(format!("Background task failed: {}", e)))?
+    })
+}
+
+/// Whether the caller is inside a trio event loop (per sniffio, which trio depends on)
+fn running_under_trio(py: Python<'_>) -> bool {
+    py.import("sniffio")
+        .and_then(|sniffio| sniffio.call_method0("current_async_library"))
+        .and_then(|library| library.extract::<String>())
+        .is_ok_and(|library| library == "trio")
+}
+
+/// Async variant of `check_all_providers`
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     awaitable[dict]: Same shape as `check_all_providers`
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn check_all_providers_async(py: Python<'_>, path: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+    run_blocking(py, move || {
+        let statuses = provider_statuses(&datum_path(path.as_deref()))?;
+        Python::attach(|py| provider_summary_dict(py, statuses))
+    })
+}
+
+/// Async variant of `list_ai_providers`
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_ai_providers_async(py: Python<'_>, path: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+    run_blocking(py, move || list_ai_providers(path.as_deref()))
+}
+
+/// Async variant of `list_ai_models`
+#[pyfunction]
+#[pyo3(signature = (path = None, provider = None))]
+fn list_ai_models_async(
+    py: Python<'_>,
+    path: Option<String>,
+    provider: Option<String>,
+) -> PyResult<Bound<'_, PyAny>> {
+    run_blocking(py, move || {
+        list_ai_models(path.as_deref(), provider.as_deref())
+    })
+}
+
+/// Async variant of `list_docker_datums`
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_docker_datums_async(py: Python<'_>, path: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+    run_blocking(py, move || {
+        let statuses = docker_datum_statuses(&datum_path(path.as_deref()))?;
+        Python::attach(|py| docker_status_list(py, statuses))
+    })
+}
+
+/// List all available AI providers
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     list: List of provider names
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_ai_providers(path: Option<&str>) -> PyResult<Vec<String>> {
+    datum_names_with_suffix(&datum_path(path), ".ai.toml")
 }
 
 /// List all available AI models
 ///
 /// Args:
 ///     path (str, optional): Path to datum directory
+///     provider (str, optional): Only include models whose `[ai_model] provider` matches
 ///
 /// Returns:
-///     list: List of model names
+///     list: List of model names (empty when nothing matches)
 ///
 #[pyfunction]
-#[pyo3(signature = (path = "~/.dotfiles/_b00t_"))]
-fn list_ai_models(path: &str) -> PyResult<Vec<String>> {
-    let datum_path =
-        get_expanded_path(path).map_err(|e| B00tError::new_err(format!("Invalid path: {}", e)))?;
+#[pyo3(signature = (path = None, provider = None))]
+fn list_ai_models(path: Option<&str>, provider: Option<&str>) -> PyResult<Vec<String>> {
+    let path = &datum_path(path);
+    let mut models = datum_names_with_suffix(path, ".ai_model.toml")?;
 
-    let mut models = Vec::new();
+    if let Some(wanted) = provider {
+        let datum_dir = get_expanded_path(path)
+            .map_err(|e| B00tIOError::new_err(format!("Invalid path: {}", e)))?;
+        models.retain(|name| {
+            model_has_provider(&datum_dir.join(format!("{}.ai_model.toml", name)), wanted)
+        });
+    }
 
-    // Read directory and find *.ai_model.toml files
-    if let Ok(entries) = std::fs::read_dir(datum_path) {
-        for entry in entries.flatten() {
-            let file_name = entry.file_name();
-            let name_str = file_name.to_string_lossy();
+    Ok(models)
+}
 
-            if name_str.ends_with(".ai_model.toml") {
-                let model_name = name_str.trim_end_matches(".ai_model.toml").to_string();
-                models.push(model_name);
-            }
-        }
+/// Lazy iterator over AI model datums, returned by `iter_ai_models`
+///
+/// Only the file names are read up front; each datum is loaded (through the
+/// `load_ai_model_datum` cache) when Python asks for the next item.
+///
+#[pyclass(name = "ModelIterator", module = "b00t_py")]
+struct ModelIterator {
+    path: String,
+    names: std::vec::IntoIter<String>,
+}
+
+#[pymethods]
+impl ModelIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
-    models.sort();
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<AiModelConfig>>> {
+        self.names
+            .next()
+            .map(|name| load_ai_model_datum(py, &name, Some(&self.path)))
+            .transpose()
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.names.len()
+    }
+}
+
+/// Iterate AI model datums, loading each `*.ai_model.toml` file on demand
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///     provider (str, optional): Only models whose `[ai_model] provider` matches
+///
+/// Returns:
+///     ModelIterator: Yields AiModelConfig objects in model-name order
+///
+/// Raises:
+///     B00tError: While iterating, if a datum cannot be loaded
+///
+#[pyfunction]
+#[pyo3(signature = (path = None, provider = None))]
+fn iter_ai_models(path: Option<String>, provider: Option<&str>) -> PyResult<ModelIterator> {
+    let path = datum_path(path.as_deref());
+    let names = list_ai_models(Some(&path), provider)?;
+    Ok(ModelIterator {
+        path,
+        names: names.into_iter(),
+    })
+}
+
+/// Load every AI model datum in one call
+///
+/// The directory is listed once and each `*.ai_model.toml` file is parsed
+/// through the `load_ai_model_datum` cache.
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     dict[str, AiModelConfig]: Model name -> config, in model-name order
+///
+/// Raises:
+///     B00tError: If any datum cannot be loaded
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn load_all_ai_models<'py>(py: Python<'py>, path: Option<&str>) -> PyResult<Bound<'py, PyDict>> {
+    let path = &datum_path(path);
+    let models = PyDict::new(py);
+    for name in list_ai_models(Some(path), None)? {
+        let config = load_ai_model_datum(py, &name, Some(path))?;
+        models.set_item(name, config)?;
+    }
     Ok(models)
 }
 
+/// Whether a model datum's `[ai_model] provider` matches (unreadable datums never match)
+fn model_has_provider(datum_path: &std::path::Path, provider: &str) -> bool {
+    std::fs::read_to_string(datum_path)
+        .ok()
+        .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+        .and_then(|value| {
+            value
+                .get("ai_model")?
+                .get("provider")?
+                .as_str()
+                .map(|p| p.eq_ignore_ascii_case(provider))
+        })
+        .unwrap_or(false)
+}
+
+/// List all available MCP servers
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     list: List of MCP server names
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_mcp_servers(path: Option<&str>) -> PyResult<Vec<String>> {
+    datum_names_with_suffix(&datum_path(path), ".mcp.toml")
+}
+
+/// List all available CLI tool datums
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     list: List of CLI tool names
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_cli_tools(path: Option<&str>) -> PyResult<Vec<String>> {
+    datum_names_with_suffix(&datum_path(path), ".cli.toml")
+}
+
+/// Sorted names of the `<name><suffix>` files in the datum directory at `path`
+/// (an unreadable directory lists nothing)
+fn datum_names_with_suffix(path: &str, suffix: &str) -> PyResult<Vec<String>> {
+    let datum_dir = get_expanded_path(path)
+        .map_err(|e| B00tIOError::new_err(format!("Invalid path: {}", e)))?;
+
+    let mut names: Vec<String> = std::fs::read_dir(datum_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let file_name = entry.file_name();
+                    let name = file_name
+                        .to_string_lossy()
+                        .strip_suffix(suffix)?
+                        .to_string();
+                    Some(name)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort();
+    Ok(names)
+}
+
+/// Load a CLI tool datum (`<name>.cli.toml`) as a dict
+///
+/// Args:
+///     name (str): Name of the CLI tool (e.g., "argo-cli")
+///     path (str, optional): Path to datum directory. Defaults to `datum_path` in ~/.b00t/config.toml, else "~/.dotfiles/_b00t_"
+///
+/// Returns:
+///     dict: {"name", "command", "description", "desires", "version",
+///         "version_regex", "install", "update"}; optional fields are None when unset
+///
+/// Raises:
+///     B00tNotFoundError: If the CLI datum file does not exist
+///     B00tParseError: If the datum is not valid TOML
+///
+#[pyfunction]
+#[pyo3(signature = (name, path = None))]
+fn load_cli_datum<'py>(
+    py: Python<'py>,
+    name: &str,
+    path: Option<&str>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let path = &datum_path(path);
+    let mut datum_path = get_expanded_path(path)
+        .map_err(|e| B00tIOError::new_err(format!("Invalid path: {}", e)))?;
+    datum_path.push(format!("{}.cli.toml", name));
+    if !datum_path.exists() {
+        return Err(B00tNotFoundError::new_err(format!(
+            "CLI datum '{}' not found at {}",
+            name,
+            datum_path.display()
+        )));
+    }
+
+    let content = std::fs::read_to_string(&datum_path)
+        .map_err(|e| B00tIOError::new_err(format!("Failed to read datum: {}", e)))?;
+    let datum = toml::from_str::<b00t_cli::UnifiedConfig>(&content)
+        .map_err(|e| B00tParseError::new_err(format!("Failed to parse TOML: {}", e)))?
+        .b00t;
+
+    let py_dict = PyDict::new(py);
+    py_dict.set_item("name", &datum.name)?;
+    // 🤓 CLI datums name the binary after the datum unless `command` says otherwise
+    py_dict.set_item("command", datum.command.as_deref().unwrap_or(&datum.name))?;
+    py_dict.set_item("description", &datum.hint)?;
+    py_dict.set_item("desires", &datum.desires)?;
+    py_dict.set_item("version", &datum.version)?;
+    py_dict.set_item("version_regex", &datum.version_regex)?;
+    py_dict.set_item("install", &datum.install)?;
+    py_dict.set_item("update", &datum.update)?;
+    Ok(py_dict)
+}
+
+/// List docker datums with the state of their container
+///
+/// Args:
+///     path (str, optional): Path to datum directory
+///
+/// Returns:
+///     list: [{"name": str, "status": "running" | "stopped" | "not_created", "image": str | None}]
+///
+/// Raises:
+///     B00tError: If datums cannot be loaded or no container runtime is available
+///
+#[pyfunction]
+#[pyo3(signature = (path = None))]
+fn list_docker_datums(py: Python<'_>, path: Option<&str>) -> PyResult<Py<PyAny>> {
+    let path = &datum_path(path);
+    let statuses = py.detach(|| docker_datum_statuses(path))?;
+    docker_status_list(py, statuses)
+}
+
+/// Docker datum status row: (name, status, image)
+type DockerDatumStatus = (String, ServiceState, Option<String>);
+
+/// Query every docker datum's container state; runs subprocesses, so call it
+/// without the GIL held
+fn docker_datum_statuses(path: &str) -> PyResult<Vec<DockerDatumStatus>> {
+    let orchestrator =
+        Orchestrator::new(path).map_err(|e| to_py_err("Failed to load datums", e))?;
+    orchestrator
+        .get_container_runtime()
+        .map_err(|e| to_py_err("No container runtime", e))?;
+
+    Ok(orchestrator
+        .docker_states()
+        .map_err(|e| to_py_err("Failed to query container", e))?
+        .into_iter()
+        .map(|(key, state)| {
+            let datum = &orchestrator.datums()[key];
+            (datum.name.clone(), state, datum.image.clone())
+        })
+        .collect())
+}
+
+fn docker_status_list(py: Python<'_>, statuses: Vec<DockerDatumStatus>) -> PyResult<Py<PyAny>> {
+    let list = PyList::empty(py);
+    for (name, status, image) in statuses {
+        let dict = PyDict::new(py);
+        dict.set_item("name", name)?;
+        // 🤓 b00t orchestrator status calls a never-created container "missing";
+        // Python callers get "not_created", which says why there is no container
+        let status = match status {
+            ServiceState::Missing => "not_created".to_string(),
+            state => state.to_string(),
+        };
+        dict.set_item("status", status)?;
+        dict.set_item("image", image)?;
+        list.append(dict)?;
+    }
+
+    Ok(list.into_any().unbind())
+}
+
 /// Python module for b00t-cli bindings
 #[pymodule]
 fn b00t_py(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -420,16 +1707,53 @@ fn b00t_py(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(model_stop_py, m)?)?;
 
     // Datum functions
+    m.add_function(wrap_pyfunction!(load_ai_model, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_datum, m)?)?;
     m.add_function(wrap_pyfunction!(load_ai_model_datum, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_ai_model_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(load_ai_model_datum_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(check_context_budget, m)?)?;
     m.add_function(wrap_pyfunction!(check_provider_env, m)?)?;
+    m.add_function(wrap_pyfunction!(check_all_providers, m)?)?;
+    m.add_function(wrap_pyfunction!(provider_env, m)?)?;
     m.add_function(wrap_pyfunction!(list_ai_providers, m)?)?;
     m.add_function(wrap_pyfunction!(list_ai_models, m)?)?;
+    m.add_function(wrap_pyfunction!(iter_ai_models, m)?)?;
+    m.add_function(wrap_pyfunction!(load_all_ai_models, m)?)?;
+    m.add_function(wrap_pyfunction!(list_mcp_servers, m)?)?;
+    m.add_function(wrap_pyfunction!(list_cli_tools, m)?)?;
+    m.add_function(wrap_pyfunction!(load_cli_datum, m)?)?;
+    m.add_function(wrap_pyfunction!(list_docker_datums, m)?)?;
+
+    // Async variants (awaitables for asyncio/trio)
+    m.add_function(wrap_pyfunction!(check_all_providers_async, m)?)?;
+    m.add_function(wrap_pyfunction!(list_ai_providers_async, m)?)?;
+    m.add_function(wrap_pyfunction!(list_ai_models_async, m)?)?;
+    m.add_function(wrap_pyfunction!(list_docker_datums_async, m)?)?;
 
     // Utilities
     m.add_function(wrap_pyfunction!(version, m)?)?;
+    m.add("__version__", b00t_c0re_lib::version::VERSION)?;
+    m.add_function(wrap_pyfunction!(expand_path, m)?)?;
+    m.add_function(wrap_pyfunction!(list_capabilities, m)?)?;
+    m.add_function(wrap_pyfunction!(list_providers, m)?)?;
+    m.add_function(wrap_pyfunction!(list_sizes, m)?)?;
+
+    // Classes
+    m.add_class::<AiModelConfig>()?;
+    m.add_class::<PyAiModelDatum>()?;
+    m.add_class::<ModelIterator>()?;
+    m.add_class::<ProviderEnv>()?;
+    m.add_class::<PyModelSize>()?;
+    m.add_class::<PyModelCapability>()?;
+    m.add_class::<PyModelProvider>()?;
 
     // Exceptions
     m.add("B00tError", py.get_type::<B00tError>())?;
+    m.add("B00tNotFoundError", py.get_type::<B00tNotFoundError>())?;
+    m.add("B00tParseError", py.get_type::<B00tParseError>())?;
+    m.add("B00tEnvError", py.get_type::<B00tEnvError>())?;
+    m.add("B00tIOError", py.get_type::<B00tIOError>())?;
 
     Ok(())
 }
@@ -0,0 +1,55 @@
+"""Shared fixtures for the b00t_py tests.
+
+The tests need the compiled extension (`maturin develop`); without it every
+module is skipped via pytest.importorskip.
+"""
+
+import pytest
+
+
+@pytest.fixture
+def datum_dir(tmp_path):
+    """A datum directory with two AI providers and two AI models."""
+    (tmp_path / "testprov.ai.toml").write_text(
+        '[b00t]\nname = "testprov"\ntype = "ai"\nhint = "test provider"\n\n'
+        "[env]\n"
+        'B00T_TEST_PROVIDER_KEY = "${B00T_TEST_SOURCE_KEY}"\n'
+        'B00T_TEST_PROVIDER_BASE = "https://example.invalid/v1"\n'
+    )
+    (tmp_path / "otherprov.ai.toml").write_text(
+        '[b00t]\nname = "otherprov"\ntype = "ai"\nhint = "other provider"\n\n'
+        '[env]\nB00T_TEST_OTHER_KEY = ""\n'
+    )
+    for name, provider in [("small-model", "testprov"), ("big-model", "otherprov")]:
+        (tmp_path / f"{name}.ai_model.toml").write_text(
+            f'[b00t]\nname = "{name}"\ntype = "ai_model"\nhint = "test model"\n\n'
+            f'[ai_model]\nprovider = "{provider}"\nsize = "small"\n'
+            f'capabilities = ["chat"]\nlitellm_model = "{provider}/{name}"\n'
+        )
+    return tmp_path
+
+
+@pytest.fixture
+def model_dir(tmp_path):
+    """A datum directory with two AI models from a known provider."""
+    for name, size in [("sonnet", "large"), ("haiku", "small")]:
+        (tmp_path / f"{name}.ai_model.toml").write_text(
+            f'[b00t]\nname = "{name}"\ntype = "ai_model"\nhint = "test model"\n\n'
+            f'[ai_model]\nprovider = "anthropic"\nsize = "{size}"\n'
+            f'capabilities = ["chat", "code"]\nlitellm_model = "anthropic/{name}"\n'
+            f'api_key_env = "B00T_TEST_ANTHROPIC_KEY"\ncontext_window = 200000\n'
+            f"input_price_per_1m = 3.0\noutput_price_per_1m = 15.0\n\n"
+            f"[ai_model.parameters]\nmax_tokens = 1024\ntemperature = 0.5\n"
+        )
+    return tmp_path
+
+
+@pytest.fixture
+def mcp_dir(tmp_path):
+    """A datum directory with two stdio MCP servers."""
+    for name in ["b00t-test-alpha", "b00t-test-beta"]:
+        (tmp_path / f"{name}.mcp.toml").write_text(
+            f'[b00t]\nname = "{name}"\ntype = "mcp"\nhint = "test server"\n'
+            'command = "npx"\nargs = ["-y", "server"]\n'
+        )
+    return tmp_path
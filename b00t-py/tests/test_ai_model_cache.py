@@ -0,0 +1,80 @@
+"""load_ai_model_datum caches parsed datums by file mtime, least recently used first out."""
+
+import os
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+# AI_MODEL_CACHE_SIZE in src/lib.rs
+CACHE_SIZE = 128
+
+
+def write_model(directory, name, litellm_model, mtime=None):
+    """Write `<name>.ai_model.toml`, optionally pinning its modification time."""
+    path = directory / f"{name}.ai_model.toml"
+    path.write_text(
+        f'[b00t]\nname = "{name}"\ntype = "ai_model"\nhint = "test model"\n\n'
+        f'[ai_model]\nprovider = "anthropic"\nsize = "small"\nlitellm_model = "{litellm_model}"\n'
+    )
+    if mtime is not None:
+        os.utime(path, ns=(mtime, mtime))
+    return path
+
+
+@pytest.fixture(autouse=True)
+def empty_cache():
+    b00t_py.clear_ai_model_cache()
+    yield
+    b00t_py.clear_ai_model_cache()
+
+
+def test_unchanged_mtime_is_served_from_cache(tmp_path):
+    path = write_model(tmp_path, "cached", "anthropic/v1")
+    mtime = path.stat().st_mtime_ns
+    assert b00t_py.load_ai_model_datum("cached", str(tmp_path)).litellm_model == "anthropic/v1"
+
+    # Same mtime, so the edit is not seen until the cache is cleared
+    write_model(tmp_path, "cached", "anthropic/v2", mtime=mtime)
+    assert b00t_py.load_ai_model_datum("cached", str(tmp_path)).litellm_model == "anthropic/v1"
+
+    b00t_py.clear_ai_model_cache()
+    assert b00t_py.load_ai_model_datum("cached", str(tmp_path)).litellm_model == "anthropic/v2"
+
+
+def test_changed_mtime_invalidates_entry(tmp_path):
+    path = write_model(tmp_path, "edited", "anthropic/v1")
+    mtime = path.stat().st_mtime_ns
+    assert b00t_py.load_ai_model_datum("edited", str(tmp_path)).litellm_model == "anthropic/v1"
+
+    write_model(tmp_path, "edited", "anthropic/v2", mtime=mtime + 1_000_000_000)
+    assert b00t_py.load_ai_model_datum("edited", str(tmp_path)).litellm_model == "anthropic/v2"
+
+
+def test_cached_config_parameters_are_not_shared(tmp_path):
+    write_model(tmp_path, "params", "anthropic/v1")
+    first = b00t_py.load_ai_model_datum("params", str(tmp_path))
+    first.parameters["temperature"] = 2.0
+
+    assert "temperature" not in b00t_py.load_ai_model_datum("params", str(tmp_path)).parameters
+
+
+def test_least_recently_used_entry_is_evicted(tmp_path):
+    mtimes = {}
+    for i in range(CACHE_SIZE):
+        path = write_model(tmp_path, f"model-{i}", "anthropic/old")
+        mtimes[i] = path.stat().st_mtime_ns
+        b00t_py.load_ai_model_datum(f"model-{i}", str(tmp_path))
+
+    # Touch model-0 so model-1 becomes the least recently used, then overflow the cache
+    b00t_py.load_ai_model_datum("model-0", str(tmp_path))
+    write_model(tmp_path, "overflow", "anthropic/old")
+    b00t_py.load_ai_model_datum("overflow", str(tmp_path))
+
+    # Rewrite both without changing their mtime: only the evicted one is re-read
+    for i in (0, 1):
+        write_model(tmp_path, f"model-{i}", "anthropic/new", mtime=mtimes[i])
+    assert b00t_py.load_ai_model_datum("model-0", str(tmp_path)).litellm_model == "anthropic/old"
+    assert b00t_py.load_ai_model_datum("model-1", str(tmp_path)).litellm_model == "anthropic/new"
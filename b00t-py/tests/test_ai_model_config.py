@@ -0,0 +1,91 @@
+"""load_ai_model_datum's typed AiModelConfig, litellm kwargs, validation and iter_ai_models."""
+
+import operator
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_load_ai_model_datum_returns_typed_config(model_dir):
+    config = b00t_py.load_ai_model_datum("sonnet", str(model_dir))
+
+    assert isinstance(config, b00t_py.AiModelConfig)
+    assert config.provider == "anthropic"
+    assert config.size == "large"
+    assert config.litellm_model == "anthropic/sonnet"
+    assert config.api_base is None
+    assert config.context_window == 200000
+    assert config.input_price_per_1m == 3.0
+    assert config.capabilities == ["chat", "code"]
+    assert config.parameters == {"max_tokens": 1024, "temperature": 0.5}
+
+
+def test_build_litellm_kwargs(model_dir, monkeypatch):
+    monkeypatch.setenv("B00T_TEST_ANTHROPIC_KEY", "sk-test")
+    config = b00t_py.load_ai_model_datum("haiku", str(model_dir))
+
+    assert config.build_litellm_kwargs() == {
+        "max_tokens": 1024,
+        "temperature": 0.5,
+        "model": "anthropic/haiku",
+        "api_key": "sk-test",
+    }
+    # The datum's parameters are copied, not updated in place
+    assert "model" not in config.parameters
+
+
+def test_build_litellm_kwargs_missing_key(model_dir, monkeypatch):
+    monkeypatch.delenv("B00T_TEST_ANTHROPIC_KEY", raising=False)
+    config = b00t_py.load_ai_model_datum("haiku", str(model_dir))
+
+    with pytest.raises(b00t_py.B00tEnvError, match="B00T_TEST_ANTHROPIC_KEY"):
+        config.build_litellm_kwargs()
+
+
+@pytest.mark.parametrize(
+    "ai_model, match",
+    [
+        ('provider = "anthropic"\nlitellm_model = "  "\n', "litellm_model"),
+        ('litellm_model = "nope/model"\n', "no provider"),
+    ],
+)
+def test_load_ai_model_datum_rejects_invalid_datum(tmp_path, ai_model, match):
+    (tmp_path / "bad.ai_model.toml").write_text(
+        '[b00t]\nname = "bad"\ntype = "ai_model"\nhint = "bad"\n\n[ai_model]\n' + ai_model
+    )
+
+    with pytest.raises(b00t_py.B00tParseError, match=match):
+        b00t_py.load_ai_model_datum("bad", str(tmp_path))
+
+
+def test_load_ai_model_datum_accepts_custom_provider(tmp_path):
+    (tmp_path / "local.ai_model.toml").write_text(
+        '[b00t]\nname = "local"\ntype = "ai_model"\nhint = "local"\n\n'
+        '[ai_model]\nprovider = "my-gateway"\nsize = "small"\nlitellm_model = "my-gateway/local"\n'
+    )
+
+    with pytest.warns(UserWarning, match="custom provider"):
+        config = b00t_py.load_ai_model_datum("local", str(tmp_path))
+    assert config.provider == "my-gateway"
+
+
+def test_iter_ai_models_is_lazy(model_dir):
+    models = b00t_py.iter_ai_models(str(model_dir))
+    assert isinstance(models, b00t_py.ModelIterator)
+    assert operator.length_hint(models) == 2
+
+    # A datum broken after the listing only fails when the iterator reaches it
+    (model_dir / "sonnet.ai_model.toml").write_text("not = [valid")
+    first = next(models)
+    assert first.litellm_model == "anthropic/haiku"
+    with pytest.raises(b00t_py.B00tParseError):
+        next(models)
+
+
+def test_iter_ai_models_provider_filter(model_dir):
+    path = str(model_dir)
+    assert [config.size for config in b00t_py.iter_ai_models(path, "anthropic")] == ["small", "large"]
+    assert list(b00t_py.iter_ai_models(path, "openai")) == []
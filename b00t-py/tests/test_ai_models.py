@@ -0,0 +1,49 @@
+"""AiModelDatum, the model enums and batch loading, reached through the b00t_py package."""
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+from b00t_py import AiModelDatum, ModelCapability, ModelProvider, ModelSize
+
+
+def test_load_ai_model_returns_ai_model_datum(model_dir):
+    model = b00t_py.load_ai_model("sonnet", str(model_dir))
+
+    assert isinstance(model, AiModelDatum)
+    assert model.name == "sonnet"
+    assert model.provider == "anthropic"
+    assert model.capabilities == ["chat", "code"]
+    assert model.context_window == 200000
+    assert repr(model) == "AiModelDatum(name='sonnet', provider='anthropic', size='large')"
+
+
+def test_ai_model_datum_equality_and_hash(model_dir):
+    path = str(model_dir)
+    first = b00t_py.load_ai_model("sonnet", path)
+
+    assert first == b00t_py.load_ai_model("sonnet", path)
+    assert first != b00t_py.load_ai_model("haiku", path)
+    assert len({first, b00t_py.load_ai_model("sonnet", path)}) == 1
+
+
+def test_model_enums_values():
+    assert ModelCapability.JsonMode.value == "json_mode"
+    assert ModelProvider.OpenRouter.value == "openrouter"
+    assert str(ModelSize.Large) == "large"
+    assert b00t_py.list_sizes() == [ModelSize.Small, ModelSize.Medium, ModelSize.Large]
+    assert ModelCapability.Code in b00t_py.list_capabilities()
+    assert ModelProvider.Anthropic in b00t_py.list_providers()
+
+
+def test_load_all_ai_models(model_dir):
+    models = b00t_py.load_all_ai_models(str(model_dir))
+
+    assert list(models) == ["haiku", "sonnet"]
+    assert isinstance(models["sonnet"], b00t_py.AiModelConfig)
+    assert models["haiku"].size == "small"
+
+
+def test_load_all_ai_models_empty_dir(tmp_path):
+    assert b00t_py.load_all_ai_models(str(tmp_path)) == {}
@@ -0,0 +1,52 @@
+"""Async variants return awaitables that match their sync counterparts."""
+
+import asyncio
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_check_all_providers_async_matches_sync(datum_dir, monkeypatch):
+    monkeypatch.setenv("B00T_TEST_PROVIDER_KEY", "secret")
+    path = str(datum_dir)
+
+    async def main():
+        return await b00t_py.check_all_providers_async(path)
+
+    result = asyncio.run(main())
+    assert result == b00t_py.check_all_providers(path)
+    assert result["testprov"]["available"] is True
+    assert result["otherprov"]["available"] is False
+
+
+def test_list_ai_models_async(datum_dir):
+    path = str(datum_dir)
+
+    async def main():
+        return await asyncio.gather(
+            b00t_py.list_ai_models_async(path),
+            b00t_py.list_ai_models_async(path, "testprov"),
+        )
+
+    every_model, testprov_models = asyncio.run(main())
+    assert sorted(every_model) == ["big-model", "small-model"]
+    assert testprov_models == ["small-model"]
+
+
+def test_list_ai_providers_async(datum_dir):
+    async def main():
+        return await b00t_py.list_ai_providers_async(str(datum_dir))
+
+    assert asyncio.run(main()) == ["otherprov", "testprov"]
+
+
+def test_list_ai_models_async_under_trio(datum_dir):
+    trio = pytest.importorskip("trio")
+
+    async def main():
+        return await b00t_py.list_ai_models_async(str(datum_dir), "otherprov")
+
+    assert trio.run(main) == ["big-model"]
@@ -0,0 +1,46 @@
+"""MCP and CLI datum listings, reached through the b00t_py package."""
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+@pytest.fixture
+def tool_dir(tmp_path):
+    for name in ["zeta", "alpha"]:
+        (tmp_path / f"{name}.mcp.toml").write_text(
+            f'[b00t]\nname = "{name}"\ntype = "mcp"\nhint = "test server"\ncommand = "npx"\n'
+        )
+    (tmp_path / "argo.cli.toml").write_text(
+        '[b00t]\nname = "argo"\ntype = "cli"\nhint = "Argo CLI"\ndesires = "3.5.0"\n'
+        'install = "echo install"\nversion = "argo version --short"\n'
+        "version_regex = 'argo: v(\\d+\\.\\d+\\.\\d+)'\n"
+    )
+    return tmp_path
+
+
+def test_list_mcp_servers_sorted(tool_dir):
+    assert b00t_py.list_mcp_servers(str(tool_dir)) == ["alpha", "zeta"]
+
+
+def test_list_cli_tools(tool_dir):
+    assert b00t_py.list_cli_tools(str(tool_dir)) == ["argo"]
+
+
+def test_load_cli_datum(tool_dir):
+    datum = b00t_py.load_cli_datum("argo", str(tool_dir))
+
+    assert datum["name"] == "argo"
+    # No explicit `command`, so the binary is named after the datum
+    assert datum["command"] == "argo"
+    assert datum["description"] == "Argo CLI"
+    assert datum["desires"] == "3.5.0"
+    assert datum["version_regex"] == r"argo: v(\d+\.\d+\.\d+)"
+    assert datum["update"] is None
+
+
+def test_load_cli_datum_missing(tool_dir):
+    with pytest.raises(b00t_py.B00tNotFoundError):
+        b00t_py.load_cli_datum("nope", str(tool_dir))
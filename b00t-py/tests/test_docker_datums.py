@@ -0,0 +1,24 @@
+"""list_docker_datums status contract."""
+
+import shutil
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+@pytest.mark.skipif(
+    not (shutil.which("docker") or shutil.which("podman")),
+    reason="needs docker or podman",
+)
+def test_list_docker_datums_reports_not_created(tmp_path):
+    name = "b00t-py-test-never-created"
+    (tmp_path / f"{name}.docker.toml").write_text(
+        f'[b00t]\nname = "{name}"\ntype = "docker"\nhint = "test"\nimage = "busybox"\n'
+    )
+
+    assert b00t_py.list_docker_datums(str(tmp_path)) == [
+        {"name": name, "status": "not_created", "image": "busybox"}
+    ]
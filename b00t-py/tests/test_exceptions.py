@@ -0,0 +1,30 @@
+"""The native exception classes, as exported from the b00t_py package."""
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_exception_hierarchy():
+    for subclass in (
+        b00t_py.B00tNotFoundError,
+        b00t_py.B00tParseError,
+        b00t_py.B00tEnvError,
+        b00t_py.B00tIOError,
+    ):
+        assert issubclass(subclass, b00t_py.B00tError)
+    assert issubclass(b00t_py.B00tError, Exception)
+
+
+def test_missing_datum_raises_not_found(tmp_path):
+    with pytest.raises(b00t_py.B00tNotFoundError, match="missing"):
+        b00t_py.load_ai_model_datum("missing", str(tmp_path))
+
+
+def test_invalid_toml_raises_parse_error(tmp_path):
+    (tmp_path / "broken.ai_model.toml").write_text("[ai_model\n")
+
+    with pytest.raises(b00t_py.B00tParseError):
+        b00t_py.load_ai_model_datum("broken", str(tmp_path))
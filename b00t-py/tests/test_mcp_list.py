@@ -0,0 +1,23 @@
+"""mcp_list server-name filtering."""
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_mcp_list_filters_by_server_name(mcp_dir):
+    servers = b00t_py.mcp_list(str(mcp_dir), servers=["b00t-test-beta"])
+    assert [server["name"] for server in servers] == ["b00t-test-beta"]
+    assert servers[0]["command"] == "npx"
+
+
+def test_mcp_query_list_uses_selected_servers(mcp_dir):
+    servers = b00t_py.mcp(str(mcp_dir)).servers(["b00t-test-alpha"]).list()
+    assert [server["name"] for server in servers] == ["b00t-test-alpha"]
+
+
+def test_mcp_list_unknown_server(mcp_dir):
+    with pytest.raises(b00t_py.B00tNotFoundError):
+        b00t_py.mcp_list(str(mcp_dir), servers=["b00t-test-missing"])
@@ -0,0 +1,55 @@
+"""mcp_output formats and the json_format deprecation."""
+
+import json
+import os
+import subprocess
+import sys
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_mcp_output_returns_config(mcp_dir):
+    output = json.loads(b00t_py.mcp_output("b00t-test-alpha,b00t-test-beta", str(mcp_dir)))
+    assert sorted(output["mcpServers"]) == ["b00t-test-alpha", "b00t-test-beta"]
+    assert output["mcpServers"]["b00t-test-alpha"]["args"] == ["-y", "server"]
+
+
+def test_mcp_output_cursor_with_prefix(mcp_dir):
+    output = json.loads(
+        b00t_py.mcp_output("b00t-test-alpha", str(mcp_dir), format="cursor", prefix="work")
+    )
+    assert list(output["mcpServers"]) == ["work_b00t-test-alpha"]
+    assert output["mcpServers"]["work_b00t-test-alpha"]["type"] == "stdio"
+
+
+def test_mcp_query_output_json(mcp_dir):
+    output = json.loads(b00t_py.mcp(str(mcp_dir)).servers(["b00t-test-beta"]).json().output())
+    assert list(output) == ["b00t-test-beta"]
+
+
+def test_mcp_output_json_format_warns(mcp_dir):
+    with pytest.warns(FutureWarning, match="json_format"):
+        output = b00t_py.mcp_output("b00t-test-alpha", str(mcp_dir), json_format=True)
+    assert list(json.loads(output)) == ["b00t-test-alpha"]
+
+
+def test_mcp_output_json_format_warning_is_visible_by_default(mcp_dir):
+    # A fresh interpreter with the default warning filters, called from __main__
+    script = (
+        "import sys, b00t_py; "
+        "b00t_py.mcp_output('b00t-test-alpha', sys.argv[1], json_format=True)"
+    )
+    env = dict(os.environ, PYTHONPATH=os.pathsep.join(sys.path))
+    env.pop("PYTHONWARNINGS", None)
+    result = subprocess.run(
+        [sys.executable, "-c", script, str(mcp_dir)],
+        capture_output=True,
+        text=True,
+        env=env,
+        check=True,
+    )
+    assert "FutureWarning: mcp_output(json_format=...) is deprecated" in result.stderr
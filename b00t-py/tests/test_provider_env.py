@@ -0,0 +1,64 @@
+"""ProviderEnv exports a provider's [env] table and restores it on exit."""
+
+import os
+
+import pytest
+
+pytest.importorskip("b00t_py.b00t_py", reason="native module not built (maturin develop)")
+
+import b00t_py
+
+
+def test_provider_env_round_trip(datum_dir, monkeypatch):
+    monkeypatch.setenv("B00T_TEST_SOURCE_KEY", "secret")
+    monkeypatch.setenv("B00T_TEST_PROVIDER_BASE", "https://previous.invalid")
+    monkeypatch.delenv("B00T_TEST_PROVIDER_KEY", raising=False)
+
+    with b00t_py.provider_env("testprov", str(datum_dir)) as status:
+        assert os.environ["B00T_TEST_PROVIDER_KEY"] == "secret"
+        assert os.environ["B00T_TEST_PROVIDER_BASE"] == "https://example.invalid/v1"
+        # The Rust side reads the process environment, which os.environ keeps in sync
+        assert status["available"] is True
+        assert b00t_py.check_provider_env("testprov", str(datum_dir))["missing_env_vars"] == []
+
+    assert "B00T_TEST_PROVIDER_KEY" not in os.environ
+    assert os.environ["B00T_TEST_PROVIDER_BASE"] == "https://previous.invalid"
+    assert b00t_py.check_provider_env("testprov", str(datum_dir))["missing_env_vars"] == [
+        "B00T_TEST_PROVIDER_KEY"
+    ]
+
+
+def test_provider_env_restores_after_exception(datum_dir, monkeypatch):
+    monkeypatch.setenv("B00T_TEST_SOURCE_KEY", "secret")
+    monkeypatch.delenv("B00T_TEST_PROVIDER_KEY", raising=False)
+
+    with pytest.raises(RuntimeError):
+        with b00t_py.provider_env("testprov", str(datum_dir)):
+            raise RuntimeError("boom")
+
+    assert "B00T_TEST_PROVIDER_KEY" not in os.environ
+
+
+def test_provider_env_unknown_provider(datum_dir):
+    with pytest.raises(b00t_py.B00tNotFoundError):
+        with b00t_py.provider_env("nope", str(datum_dir)):
+            pass
+
+
+def test_check_provider_env_resolve_vars_ignores_literals(datum_dir, monkeypatch):
+    # testprov has a literal base URL and a key that references B00T_TEST_SOURCE_KEY
+    monkeypatch.setenv("B00T_TEST_PROVIDER_BASE", "https://example.invalid/v1")
+    monkeypatch.delenv("B00T_TEST_SOURCE_KEY", raising=False)
+
+    status = b00t_py.check_provider_env("testprov", str(datum_dir), resolve_vars=True)
+    assert status["available"] is False
+    assert status["missing_env_vars"] == ["B00T_TEST_PROVIDER_KEY"]
+    assert status["resolved_values"] == {
+        "B00T_TEST_PROVIDER_BASE": "https://example.invalid/v1",
+        "B00T_TEST_PROVIDER_KEY": None,
+    }
+
+    monkeypatch.setenv("B00T_TEST_SOURCE_KEY", "secret")
+    status = b00t_py.check_provider_env("testprov", str(datum_dir), resolve_vars=True)
+    assert status["available"] is True
+    assert status["resolved_values"]["B00T_TEST_PROVIDER_KEY"] == "secret"